@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use clap::{Arg, Command};
+use clap::{Arg, ArgAction, ArgGroup, Command};
+use ets_lib::{GroupBy, MAX_OWNER_STATE_TIMEOUT_MS, Severity};
+use std::time::Duration;
 
 pub fn clap_parser() -> Command {
     let tables_group = Command::new("tables")
@@ -20,11 +22,36 @@ pub fn clap_parser() -> Command {
         .subcommand_required(true)
         .subcommands(tables_subcommands());
 
+    let persistent_terms_group = Command::new("persistent-terms")
+        .about("persistent_term operations")
+        .subcommand_required(true)
+        .subcommands(persistent_terms_subcommands());
+
+    let dets_group = Command::new("dets")
+        .about("DETS table operations")
+        .subcommand_required(true)
+        .subcommands(dets_subcommands());
+
+    let mnesia_group = Command::new("mnesia")
+        .about("Mnesia table operations")
+        .subcommand_required(true)
+        .subcommands(mnesia_subcommands());
+
+    let node_group = Command::new("node")
+        .about("Node-wide ETS information")
+        .subcommand_required(true)
+        .subcommands(node_subcommands());
+
     Command::new("ets-cli")
         .version(env!("CARGO_PKG_VERSION"))
         .about("ETS CLI - Inspect ETS tables on running Erlang nodes")
         .subcommand_required(true)
         .subcommand(tables_group)
+        .subcommand(dets_group)
+        .subcommand(mnesia_group)
+        .subcommand(persistent_terms_group)
+        .subcommand(node_group)
+        .subcommand(diff_command())
 }
 
 fn node_arg() -> Arg {
@@ -36,6 +63,24 @@ fn node_arg() -> Arg {
         .help("Target Erlang node name (e.g., rabbit@localhost)")
 }
 
+fn nodes_arg() -> Arg {
+    Arg::new("node")
+        .long("node")
+        .short('n')
+        .required(true)
+        .action(ArgAction::Append)
+        .value_name("NODE")
+        .help("Target Erlang node name, can be repeated (e.g., --node a@h1 --node b@h2)")
+}
+
+fn table_name_arg(help: &'static str) -> Arg {
+    Arg::new("name")
+        .long("name")
+        .required(true)
+        .value_name("TABLE")
+        .help(help)
+}
+
 fn cookie_arg() -> Arg {
     Arg::new("erlang_cookie")
         .long("erlang-cookie")
@@ -53,30 +98,536 @@ fn pattern_arg() -> Arg {
         .help("Filter table names by regex pattern")
 }
 
+fn format_arg() -> Arg {
+    Arg::new("format")
+        .long("format")
+        .short('f')
+        .value_name("FORMAT")
+        .value_parser(["table", "json"])
+        .default_value("table")
+        .help("Output format")
+}
+
+fn snapshot_source_arg() -> Arg {
+    Arg::new("snapshot")
+        .long("snapshot")
+        .value_name("FILE")
+        .conflicts_with("node")
+        .help("Read tables from a file written by 'tables snapshot' instead of a live node")
+}
+
+fn contents_arg(help: &'static str) -> Arg {
+    Arg::new("contents")
+        .long("contents")
+        .action(ArgAction::SetTrue)
+        .help(help)
+}
+
+/// Parses durations such as `500ms`, `10s`, `2m` or `1h`; a bare number means seconds.
+pub fn parse_interval(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+    let amount: u64 = amount
+        .parse()
+        .map_err(|_| format!("'{}' is not a duration like 10s or 2m", value))?;
+
+    let seconds = |multiplier: u64| {
+        amount
+            .checked_mul(multiplier)
+            .map(Duration::from_secs)
+            .ok_or_else(|| format!("'{}' is too long a duration", value))
+    };
+
+    match unit {
+        "ms" => Ok(Duration::from_millis(amount)),
+        "" | "s" => seconds(1),
+        "m" => seconds(60),
+        "h" => seconds(3600),
+        other => Err(format!(
+            "unknown duration unit '{}', expected one of: ms, s, m, h",
+            other
+        )),
+    }
+}
+
+fn diff_command() -> Command {
+    Command::new("diff")
+        .about("Compare two node snapshots taken with 'tables snapshot'")
+        .arg(
+            Arg::new("old")
+                .required(true)
+                .value_name("OLD_SNAPSHOT")
+                .help("Path to the older snapshot file"),
+        )
+        .arg(
+            Arg::new("new")
+                .required(true)
+                .value_name("NEW_SNAPSHOT")
+                .help("Path to the newer snapshot file"),
+        )
+        .arg(contents_arg(
+            "Also report added, removed and changed objects per table",
+        ))
+        .arg(format_arg())
+}
+
+fn dets_subcommands() -> Vec<Command> {
+    let list_cmd = Command::new("list")
+        .about("List DETS tables opened on the node")
+        .arg(node_arg())
+        .arg(cookie_arg())
+        .arg(pattern_arg())
+        .arg(format_arg());
+
+    let dump_cmd = Command::new("dump")
+        .about("Dump contents of a DETS table")
+        .arg(node_arg())
+        .arg(cookie_arg())
+        .arg(table_name_arg("Name of the DETS table to dump"))
+        .arg(
+            Arg::new("chunk_size")
+                .long("chunk-size")
+                .value_name("OBJECTS")
+                .value_parser(clap::value_parser!(usize))
+                .help("Approximate number of objects fetched per round trip (default: 500)"),
+        );
+
+    let lookup_cmd = Command::new("lookup")
+        .about("Look up the objects stored under a key in a DETS table")
+        .arg(node_arg())
+        .arg(cookie_arg())
+        .arg(table_name_arg("Name of the DETS table"))
+        .arg(
+            Arg::new("key")
+                .long("key")
+                .short('k')
+                .required(true)
+                .value_name("TERM")
+                .help("Key in Erlang term syntax, e.g. '<<\"guest\">>' or '{user, 42}'"),
+        );
+
+    vec![list_cmd, dump_cmd, lookup_cmd]
+}
+
+fn mnesia_subcommands() -> Vec<Command> {
+    let list_cmd = Command::new("list")
+        .about("List Mnesia tables with their storage type, replicas and attributes")
+        .arg(node_arg())
+        .arg(cookie_arg())
+        .arg(pattern_arg())
+        .arg(format_arg());
+
+    let dump_cmd = Command::new("dump")
+        .about("Dump a Mnesia table with records rendered using their attribute names")
+        .arg(node_arg())
+        .arg(cookie_arg())
+        .arg(table_name_arg("Name of the Mnesia table to dump"));
+
+    vec![list_cmd, dump_cmd]
+}
+
+fn node_subcommands() -> Vec<Command> {
+    let ets_alloc_cmd = Command::new("ets-alloc")
+        .about("Report ets_alloc carriers, blocks and fragmentation against table memory")
+        .arg(node_arg())
+        .arg(cookie_arg())
+        .arg(format_arg());
+
+    vec![ets_alloc_cmd]
+}
+
+fn persistent_terms_subcommands() -> Vec<Command> {
+    let list_cmd = Command::new("list")
+        .about("List persistent_term keys sorted by value size (descending)")
+        .arg(node_arg())
+        .arg(cookie_arg())
+        .arg(
+            Arg::new("pattern")
+                .long("pattern")
+                .short('p')
+                .value_name("REGEX")
+                .help("Filter keys (rendered in Erlang term syntax) by regex pattern"),
+        )
+        .arg(format_arg());
+
+    let get_cmd = Command::new("get")
+        .about("Print the value stored under a persistent_term key")
+        .arg(node_arg())
+        .arg(cookie_arg())
+        .arg(
+            Arg::new("key")
+                .long("key")
+                .short('k')
+                .required(true)
+                .value_name("TERM")
+                .help("Key in Erlang term syntax, e.g. '{my_app, config}'"),
+        )
+        .arg(format_arg());
+
+    vec![list_cmd, get_cmd]
+}
+
 fn tables_subcommands() -> Vec<Command> {
     let list_cmd = Command::new("list")
         .about("List all ETS tables on the node")
-        .arg(node_arg())
+        .arg(
+            nodes_arg()
+                .required(false)
+                .required_unless_present("snapshot"),
+        )
+        .arg(snapshot_source_arg().conflicts_with_all(["compare", "cluster"]))
         .arg(cookie_arg())
-        .arg(pattern_arg());
+        .arg(pattern_arg())
+        .arg(
+            Arg::new("compare")
+                .long("compare")
+                .action(ArgAction::SetTrue)
+                .help("Compare tables across all --node values side by side"),
+        )
+        .arg(
+            Arg::new("cluster")
+                .long("cluster")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("compare")
+                .help("List tables on every cluster member, using --node as the gateway"),
+        )
+        .arg(format_arg());
 
     let memory_breakdown_cmd = Command::new("memory_breakdown")
         .about("List ETS tables sorted by memory usage (descending)")
+        .arg(
+            node_arg()
+                .required(false)
+                .required_unless_present("snapshot"),
+        )
+        .arg(snapshot_source_arg().conflicts_with("estimate_compression"))
+        .arg(cookie_arg())
+        .arg(pattern_arg())
+        .arg(
+            Arg::new("group_by")
+                .long("group-by")
+                .value_name("GROUPING")
+                .value_parser(GroupBy::NAMES)
+                .help("Aggregate tables into groups, e.g. by the OTP application of their owner"),
+        )
+        .arg(
+            Arg::new("group_regex")
+                .long("group-regex")
+                .value_name("REGEX")
+                .requires("group_by")
+                .help(
+                    "Regex for --group-by regex-capture, its first capture group names the group",
+                ),
+        )
+        .arg(
+            Arg::new("top")
+                .long("top")
+                .value_name("N")
+                .value_parser(clap::value_parser!(usize))
+                .requires("group_by")
+                .help("Show the N largest groups and fold the rest into an 'other' row"),
+        )
+        .arg(
+            Arg::new("estimate_compression")
+                .long("estimate-compression")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("group_by")
+                .help(
+                    "Sample objects of each table and project the savings of the compressed option",
+                ),
+        )
+        .arg(
+            Arg::new("off_heap")
+                .long("off-heap")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("group_by")
+                .help("Add an estimate of the refc binaries referenced by each table's objects"),
+        )
+        .group(ArgGroup::new("sampling").args(["estimate_compression", "off_heap"]))
+        .arg(
+            Arg::new("sample_size")
+                .long("sample-size")
+                .value_name("OBJECTS")
+                .value_parser(clap::value_parser!(u64).range(1..))
+                .requires("sampling")
+                .help(
+                    "Objects to sample per table with --estimate-compression or --off-heap (default: 1000)",
+                ),
+        );
+
+    let info_cmd = Command::new("info")
+        .about("Show information about an ETS table")
+        .arg(
+            node_arg()
+                .required(false)
+                .required_unless_present("snapshot"),
+        )
+        .arg(snapshot_source_arg())
+        .arg(cookie_arg())
+        .arg(table_name_arg("Name of the ETS table"))
+        .arg(
+            Arg::new("stats")
+                .long("stats")
+                .action(ArgAction::SetTrue)
+                .help("Include bucket and chain length statistics of hash-based tables"),
+        )
+        .arg(format_arg());
+
+    let schema_cmd = Command::new("schema")
+        .about("Infer the shape of a table's objects from a sample of them")
         .arg(node_arg())
         .arg(cookie_arg())
-        .arg(pattern_arg());
+        .arg(table_name_arg("Name of the ETS table"))
+        .arg(
+            Arg::new("sample_size")
+                .long("sample-size")
+                .value_name("OBJECTS")
+                .value_parser(clap::value_parser!(u64).range(1..))
+                .help("Objects to sample (default: 1000)"),
+        )
+        .arg(
+            Arg::new("all")
+                .long("all")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("sample_size")
+                .help("Scan every object instead of a sample"),
+        )
+        .arg(
+            Arg::new("emit")
+                .long("emit")
+                .value_name("KIND")
+                .value_parser(["json-schema", "rust"])
+                .conflicts_with("format")
+                .help("Print a JSON Schema or Rust types for erltf_serde::from_term instead of a summary"),
+        )
+        .arg(format_arg());
 
     let dump_cmd = Command::new("dump")
         .about("Dump contents of an ETS table")
+        .arg(
+            node_arg()
+                .required(false)
+                .required_unless_present("snapshot"),
+        )
+        .arg(snapshot_source_arg())
+        .arg(cookie_arg())
+        .arg(table_name_arg("Name of the ETS table to dump"))
+        .arg(
+            Arg::new("page_size")
+                .long("page-size")
+                .value_name("KEYS")
+                .value_parser(clap::value_parser!(u64).range(1..))
+                .help("Fetch the table in pages of this many keys instead of in one round trip"),
+        );
+
+    let snapshot_cmd = Command::new("snapshot")
+        .about("Save table metadata (and optionally contents) to a snapshot file")
         .arg(node_arg())
         .arg(cookie_arg())
         .arg(
-            Arg::new("name")
-                .long("name")
+            Arg::new("output")
+                .long("output")
+                .short('o')
                 .required(true)
-                .value_name("TABLE")
-                .help("Name of the ETS table to dump"),
-        );
+                .value_name("FILE")
+                .help("Path of the snapshot file to write"),
+        )
+        .arg(contents_arg(
+            "Also capture the contents of named, non-private tables",
+        ));
+
+    let compare_cmd = Command::new("compare")
+        .about("Compare the contents of a table on two nodes")
+        .arg(nodes_arg())
+        .arg(cookie_arg())
+        .arg(table_name_arg("Name of the ETS table to compare"))
+        .arg(
+            Arg::new("method")
+                .long("method")
+                .value_name("METHOD")
+                .value_parser(["auto", "contents", "hashes"])
+                .default_value("auto")
+                .help("Transfer full contents or compare per-key hashes computed on each node"),
+        )
+        .arg(
+            Arg::new("hash_threshold")
+                .long("hash-threshold")
+                .value_name("OBJECTS")
+                .value_parser(clap::value_parser!(u64))
+                .help(
+                    "With --method auto, use per-key hashes for tables larger than this (default: 10000)",
+                ),
+        )
+        .arg(format_arg());
+
+    let checksum_cmd = Command::new("checksum")
+        .about("Compute an order-independent checksum of a table's objects on one or more nodes")
+        .arg(nodes_arg())
+        .arg(cookie_arg())
+        .arg(table_name_arg("Name of the ETS table"))
+        .arg(
+            Arg::new("chunk_size")
+                .long("chunk-size")
+                .value_name("OBJECTS")
+                .value_parser(clap::value_parser!(u64).range(1..))
+                .help("Objects hashed per round trip to the table (default: 1000)"),
+        )
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .value_name("MILLISECONDS")
+                .value_parser(clap::value_parser!(u64).range(1..))
+                .help(
+                    "How long each node gets to checksum the whole table; slower nodes are reported as failures (default: 60000)",
+                ),
+        )
+        .arg(format_arg());
+
+    let find_key_cmd = Command::new("find-key")
+        .about("Find tables that contain a given key")
+        .arg(node_arg())
+        .arg(cookie_arg())
+        .arg(
+            Arg::new("key")
+                .long("key")
+                .short('k')
+                .required(true)
+                .value_name("TERM")
+                .help("Key in Erlang term syntax, e.g. '<<\"guest\">>' or '{user, 42}'"),
+        )
+        .arg(pattern_arg())
+        .arg(format_arg());
+
+    let grep_cmd = Command::new("grep")
+        .about("Search the rendered entries of tables for a regex")
+        .arg(node_arg())
+        .arg(cookie_arg())
+        .arg(
+            Arg::new("regex")
+                .long("regex")
+                .short('r')
+                .required(true)
+                .value_name("REGEX")
+                .help("Regex to match against entries rendered in Erlang term syntax"),
+        )
+        .arg(pattern_arg())
+        .arg(
+            Arg::new("max_matches")
+                .long("max-matches")
+                .value_name("COUNT")
+                .value_parser(clap::value_parser!(usize))
+                .help("Stop searching after this many matching entries"),
+        )
+        .arg(format_arg());
+
+    let owner_state_cmd = Command::new("owner-state")
+        .about("Print the state of the process that owns a table (messages the process)")
+        .arg(node_arg())
+        .arg(cookie_arg())
+        .arg(table_name_arg(
+            "Name of the ETS table whose owner to inspect",
+        ))
+        .arg(
+            Arg::new("status")
+                .long("status")
+                .action(ArgAction::SetTrue)
+                .help("Fetch the full sys:get_status/2 output instead of sys:get_state/2"),
+        )
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .value_name("MILLISECONDS")
+                .value_parser(clap::value_parser!(u64).range(1..=MAX_OWNER_STATE_TIMEOUT_MS))
+                .help("How long to wait for the owner to reply, at most 60000 (default: 5000)"),
+        )
+        .arg(format_arg());
+
+    let lint_cmd = Command::new("lint")
+        .about("Flag common ETS configuration mistakes on the node")
+        .arg(node_arg())
+        .arg(cookie_arg())
+        .arg(
+            Arg::new("fail_on")
+                .long("fail-on")
+                .value_name("SEVERITY")
+                .value_parser(Severity::NAMES)
+                .help("Exit with a non-zero code if any finding is at least this severe"),
+        )
+        .arg(
+            Arg::new("large_table_size")
+                .long("large-table-size")
+                .value_name("OBJECTS")
+                .value_parser(clap::value_parser!(u64))
+                .help("Object count from which a table is considered large (default: 100000)"),
+        )
+        .arg(
+            Arg::new("message_queue_threshold")
+                .long("message-queue-threshold")
+                .value_name("MESSAGES")
+                .value_parser(clap::value_parser!(u64))
+                .help("Owner message queue length that is flagged (default: 10000)"),
+        )
+        .arg(format_arg());
+
+    let leaks_cmd = Command::new("leaks")
+        .about("Sample the table list repeatedly and flag owners whose tables keep growing")
+        .arg(node_arg())
+        .arg(cookie_arg())
+        .arg(
+            Arg::new("interval")
+                .long("interval")
+                .value_name("DURATION")
+                .value_parser(parse_interval)
+                .default_value("10s")
+                .help("Time between samples, e.g. 500ms, 10s or 2m"),
+        )
+        .arg(
+            Arg::new("samples")
+                .long("samples")
+                .value_name("COUNT")
+                .value_parser(clap::value_parser!(u64).range(2..))
+                .default_value("6")
+                .help("Number of table listings to take"),
+        )
+        .arg(
+            Arg::new("top")
+                .long("top")
+                .value_name("N")
+                .value_parser(clap::value_parser!(usize))
+                .default_value("20")
+                .help("Rows to show per owner and per name table (table format only)"),
+        )
+        .arg(format_arg());
+
+    let tree_cmd = Command::new("tree")
+        .about("Show ETS tables placed in the supervision trees of running applications")
+        .arg(node_arg())
+        .arg(cookie_arg())
+        .arg(
+            Arg::new("all")
+                .long("all")
+                .action(ArgAction::SetTrue)
+                .help("Also show processes whose subtrees own no tables"),
+        )
+        .arg(format_arg());
 
-    vec![list_cmd, memory_breakdown_cmd, dump_cmd]
+    vec![
+        list_cmd,
+        memory_breakdown_cmd,
+        info_cmd,
+        schema_cmd,
+        dump_cmd,
+        snapshot_cmd,
+        compare_cmd,
+        checksum_cmd,
+        find_key_cmd,
+        grep_cmd,
+        tree_cmd,
+        owner_state_cmd,
+        lint_cmd,
+        leaks_cmd,
+    ]
 }
@@ -16,6 +16,13 @@ pub mod cli;
 pub mod output;
 
 pub use ets_lib::{
-    Error, EtsTableInfo, Protection, Result, TableType, dump_table, filter_tables,
-    get_erlang_cookie, list_tables,
+    ComparisonMethod, Error, EtsSource, EtsTableInfo, GroupBy, LiveNode, Protection, Result,
+    Snapshot, TableComparison, TableType, checksum_table, compare_inventories, compare_table,
+    diff_snapshots, dump_dets_table, dump_mnesia_table, dump_table, estimate_compression,
+    ets_alloc_report, filter_cluster_tables, filter_dets_tables, filter_mnesia_tables,
+    filter_tables, find_key, get_erlang_cookie, get_owner_state, get_persistent_term,
+    get_table_details, grep_tables, group_tables, infer_table_schema, lint_node,
+    list_cluster_tables, list_dets_tables, list_mnesia_tables, list_persistent_terms, list_tables,
+    list_tables_with_applications, list_tables_with_off_heap, lookup_dets_table, sample_tables,
+    supervision_tree, take_snapshot, top_groups,
 };
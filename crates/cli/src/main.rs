@@ -13,12 +13,32 @@
 // limitations under the License.
 
 use clap::ArgMatches;
+use erltf::OwnedTerm;
 use ets_cli::cli::clap_parser;
-use ets_cli::output;
-use ets_cli::{Error, Result, dump_table, filter_tables, get_erlang_cookie, list_tables};
+use ets_cli::output::{self, OutputFormat};
+use ets_cli::{
+    ComparisonMethod, Error, EtsSource, EtsTableInfo, GroupBy, LiveNode, Result, Snapshot,
+    checksum_table, compare_inventories, compare_table, diff_snapshots, dump_dets_table,
+    dump_mnesia_table, estimate_compression, ets_alloc_report, filter_cluster_tables,
+    filter_dets_tables, filter_mnesia_tables, filter_tables, find_key, get_erlang_cookie,
+    get_owner_state, get_persistent_term, grep_tables, group_tables, infer_table_schema, lint_node,
+    list_cluster_tables, list_dets_tables, list_mnesia_tables, list_persistent_terms,
+    lookup_dets_table, sample_tables, supervision_tree, take_snapshot, top_groups,
+};
+use ets_lib::checksum::{DEFAULT_CHECKSUM_CHUNK_SIZE, DEFAULT_CHECKSUM_TIMEOUT_MS};
+use ets_lib::compare::DEFAULT_HASH_THRESHOLD;
+use ets_lib::compression::DEFAULT_COMPRESSION_SAMPLE_SIZE;
+use ets_lib::dets::DEFAULT_CHUNK_SIZE;
+use ets_lib::ets::DEFAULT_OFF_HEAP_SAMPLE_SIZE;
+use ets_lib::lint::{LintThresholds, Severity};
+use ets_lib::owner::{DEFAULT_OWNER_STATE_TIMEOUT_MS, OwnerStateKind};
+use ets_lib::schema::DEFAULT_SCHEMA_SAMPLE_SIZE;
 use regex::Regex;
+use std::cmp::Reverse;
 use std::io::stderr;
+use std::path::Path;
 use std::process::exit;
+use std::time::Duration;
 use sysexits::ExitCode;
 
 fn init_logging() {
@@ -48,6 +68,13 @@ async fn main() {
 
     let exit_code = match matches.subcommand() {
         Some(("tables", sub_matches)) => handle_tables_command(sub_matches).await,
+        Some(("dets", sub_matches)) => handle_dets_command(sub_matches).await,
+        Some(("mnesia", sub_matches)) => handle_mnesia_command(sub_matches).await,
+        Some(("persistent-terms", sub_matches)) => {
+            handle_persistent_terms_command(sub_matches).await
+        }
+        Some(("node", sub_matches)) => handle_node_command(sub_matches).await,
+        Some(("diff", sub_matches)) => handle_diff_command(sub_matches),
         _ => {
             eprintln!("Unknown command. Use --help for usage information.");
             ExitCode::Usage
@@ -63,7 +90,22 @@ async fn handle_tables_command(args: &ArgMatches) -> ExitCode {
         Some(("memory_breakdown", sub_matches)) => {
             handle_memory_breakdown_command(sub_matches).await
         }
+        Some(("info", sub_matches)) => {
+            handle_result(do_table_info(sub_matches).await, "get table info")
+        }
+        Some(("schema", sub_matches)) => {
+            handle_result(do_table_schema(sub_matches).await, "infer table schema")
+        }
         Some(("dump", sub_matches)) => handle_dump_command(sub_matches).await,
+        Some(("snapshot", sub_matches)) => handle_snapshot_command(sub_matches).await,
+        Some(("compare", sub_matches)) => handle_compare_command(sub_matches).await,
+        Some(("checksum", sub_matches)) => handle_checksum_command(sub_matches).await,
+        Some(("find-key", sub_matches)) => handle_find_key_command(sub_matches).await,
+        Some(("grep", sub_matches)) => handle_grep_command(sub_matches).await,
+        Some(("tree", sub_matches)) => handle_tree_command(sub_matches).await,
+        Some(("owner-state", sub_matches)) => handle_owner_state_command(sub_matches).await,
+        Some(("lint", sub_matches)) => handle_lint_command(sub_matches).await,
+        Some(("leaks", sub_matches)) => handle_leaks_command(sub_matches).await,
         _ => {
             eprintln!("Unknown tables subcommand. Use --help for usage information.");
             ExitCode::Usage
@@ -71,6 +113,70 @@ async fn handle_tables_command(args: &ArgMatches) -> ExitCode {
     }
 }
 
+async fn handle_dets_command(args: &ArgMatches) -> ExitCode {
+    match args.subcommand() {
+        Some(("list", sub_matches)) => {
+            handle_result(do_list_dets_tables(sub_matches).await, "list DETS tables")
+        }
+        Some(("dump", sub_matches)) => {
+            handle_result(do_dump_dets_table(sub_matches).await, "dump DETS table")
+        }
+        Some(("lookup", sub_matches)) => {
+            handle_result(do_lookup_dets_table(sub_matches).await, "look up DETS key")
+        }
+        _ => {
+            eprintln!("Unknown dets subcommand. Use --help for usage information.");
+            ExitCode::Usage
+        }
+    }
+}
+
+async fn handle_mnesia_command(args: &ArgMatches) -> ExitCode {
+    match args.subcommand() {
+        Some(("list", sub_matches)) => handle_result(
+            do_list_mnesia_tables(sub_matches).await,
+            "list Mnesia tables",
+        ),
+        Some(("dump", sub_matches)) => {
+            handle_result(do_dump_mnesia_table(sub_matches).await, "dump Mnesia table")
+        }
+        _ => {
+            eprintln!("Unknown mnesia subcommand. Use --help for usage information.");
+            ExitCode::Usage
+        }
+    }
+}
+
+async fn handle_node_command(args: &ArgMatches) -> ExitCode {
+    match args.subcommand() {
+        Some(("ets-alloc", sub_matches)) => handle_result(
+            do_ets_alloc_report(sub_matches).await,
+            "report on ets_alloc",
+        ),
+        _ => {
+            eprintln!("Unknown node subcommand. Use --help for usage information.");
+            ExitCode::Usage
+        }
+    }
+}
+
+async fn handle_persistent_terms_command(args: &ArgMatches) -> ExitCode {
+    match args.subcommand() {
+        Some(("list", sub_matches)) => handle_result(
+            do_list_persistent_terms(sub_matches).await,
+            "list persistent terms",
+        ),
+        Some(("get", sub_matches)) => handle_result(
+            do_get_persistent_term(sub_matches).await,
+            "get persistent term",
+        ),
+        _ => {
+            eprintln!("Unknown persistent-terms subcommand. Use --help for usage information.");
+            ExitCode::Usage
+        }
+    }
+}
+
 fn handle_result(result: Result<()>, operation: &str) -> ExitCode {
     match result {
         Ok(()) => ExitCode::Ok,
@@ -94,6 +200,62 @@ async fn handle_dump_command(args: &ArgMatches) -> ExitCode {
     handle_result(do_dump_table(args).await, "dump table")
 }
 
+async fn handle_snapshot_command(args: &ArgMatches) -> ExitCode {
+    handle_result(do_take_snapshot(args).await, "take snapshot")
+}
+
+async fn handle_compare_command(args: &ArgMatches) -> ExitCode {
+    handle_result(do_compare_table(args).await, "compare table")
+}
+
+async fn handle_checksum_command(args: &ArgMatches) -> ExitCode {
+    match do_checksum_table(args).await {
+        Ok(true) => ExitCode::Ok,
+        Ok(false) => ExitCode::DataErr,
+        Err(e) => {
+            log::error!("Failed to checksum table: {}", e);
+            eprintln!("Error: {}", e);
+            ExitCode::Software
+        }
+    }
+}
+
+async fn handle_find_key_command(args: &ArgMatches) -> ExitCode {
+    handle_result(do_find_key(args).await, "find key")
+}
+
+async fn handle_grep_command(args: &ArgMatches) -> ExitCode {
+    handle_result(do_grep_tables(args).await, "search tables")
+}
+
+async fn handle_tree_command(args: &ArgMatches) -> ExitCode {
+    handle_result(do_supervision_tree(args).await, "build supervision tree")
+}
+
+async fn handle_owner_state_command(args: &ArgMatches) -> ExitCode {
+    handle_result(do_get_owner_state(args).await, "get owner state")
+}
+
+async fn handle_leaks_command(args: &ArgMatches) -> ExitCode {
+    handle_result(do_detect_leaks(args).await, "sample tables")
+}
+
+async fn handle_lint_command(args: &ArgMatches) -> ExitCode {
+    match do_lint(args).await {
+        Ok(true) => ExitCode::DataErr,
+        Ok(false) => ExitCode::Ok,
+        Err(e) => {
+            log::error!("Failed to lint tables: {}", e);
+            eprintln!("Error: {}", e);
+            ExitCode::Software
+        }
+    }
+}
+
+fn handle_diff_command(args: &ArgMatches) -> ExitCode {
+    handle_result(do_diff_snapshots(args), "diff snapshots")
+}
+
 fn resolve_erlang_cookie(args: &ArgMatches) -> Result<String> {
     let explicit = args.get_one::<String>("erlang_cookie").map(|s| s.as_str());
     get_erlang_cookie(explicit, None)
@@ -106,35 +268,351 @@ fn get_pattern_filter(args: &ArgMatches) -> Result<Option<Regex>> {
 }
 
 async fn do_list_tables(args: &ArgMatches) -> Result<()> {
+    let pattern = get_pattern_filter(args)?;
+
+    if args.contains_id("snapshot") {
+        let tables = ets_source(args)?.list_tables().await?;
+        print_tables(filter_tables(tables, pattern.as_ref()), args);
+        return Ok(());
+    }
+
+    let nodes = get_nodes(args);
+    let cookie = resolve_erlang_cookie(args)?;
+
+    if args.get_flag("cluster") {
+        let [gateway] = nodes.as_slice() else {
+            return Err(Error::InvalidArgument(
+                "--cluster requires exactly one gateway --node".to_string(),
+            ));
+        };
+        let mut cluster = list_cluster_tables(gateway, &cookie).await?;
+        cluster.tables = filter_cluster_tables(cluster.tables, pattern.as_ref());
+        output::print_cluster_table_list(cluster, get_output_format(args));
+        return Ok(());
+    }
+
+    if args.get_flag("compare") {
+        let inventory = compare_inventories(&nodes, &cookie, pattern.as_ref()).await?;
+        output::print_inventory_comparison(&inventory, get_output_format(args));
+        return Ok(());
+    }
+
+    let [node] = nodes.as_slice() else {
+        return Err(Error::InvalidArgument(
+            "listing tables on multiple nodes requires --compare".to_string(),
+        ));
+    };
+
+    let tables = LiveNode::new(node, &cookie).list_tables().await?;
+    print_tables(filter_tables(tables, pattern.as_ref()), args);
+
+    Ok(())
+}
+
+fn print_tables(tables: Vec<EtsTableInfo>, args: &ArgMatches) {
+    match get_output_format(args) {
+        OutputFormat::Json => output::print_json(&tables),
+        OutputFormat::Table => output::print_table_list(tables),
+    }
+}
+
+/// The snapshot file given with `--snapshot`, or the live `--node`.
+fn ets_source(args: &ArgMatches) -> Result<Box<dyn EtsSource>> {
+    if let Some(path) = args.get_one::<String>("snapshot") {
+        return Ok(Box::new(Snapshot::read_from(Path::new(path))?));
+    }
+
+    let node = args
+        .get_one::<String>("node")
+        .expect("node is required by clap unless --snapshot is given");
+    let cookie = resolve_erlang_cookie(args)?;
+    Ok(Box::new(LiveNode::new(node, &cookie)))
+}
+
+async fn memory_breakdown(args: &ArgMatches) -> Result<()> {
+    let pattern = get_pattern_filter(args)?;
+
+    if args.get_flag("estimate_compression") {
+        let node = args
+            .get_one::<String>("node")
+            .expect("node is required by clap without --snapshot");
+        let cookie = resolve_erlang_cookie(args)?;
+        let sample_size = args
+            .get_one::<u64>("sample_size")
+            .map(|n| *n as usize)
+            .unwrap_or(DEFAULT_COMPRESSION_SAMPLE_SIZE);
+        let estimate = estimate_compression(node, &cookie, pattern.as_ref(), sample_size).await?;
+        output::print_compression_estimate(&estimate);
+        return Ok(());
+    }
+
+    let group_regex = args.get_one::<String>("group_regex").map(|s| s.as_str());
+    let group_by = args
+        .get_one::<String>("group_by")
+        .map(|name| GroupBy::from_name(name, group_regex))
+        .transpose()?;
+
+    let source = ets_source(args)?;
+    let tables = if args.get_flag("off_heap") {
+        let sample_size = args
+            .get_one::<u64>("sample_size")
+            .map(|n| *n as usize)
+            .unwrap_or(DEFAULT_OFF_HEAP_SAMPLE_SIZE);
+        source.list_tables_with_off_heap(sample_size).await?
+    } else if group_by.as_ref().is_some_and(GroupBy::needs_applications) {
+        source.list_tables_with_applications().await?
+    } else {
+        source.list_tables().await?
+    };
+    let mut tables = filter_tables(tables, pattern.as_ref());
+
+    if let Some(group_by) = group_by {
+        let mut groups = group_tables(&tables, &group_by);
+        if let Some(top) = args.get_one::<usize>("top") {
+            groups = top_groups(groups, *top);
+        }
+        output::print_memory_groups(&groups);
+        return Ok(());
+    }
+
+    tables.sort_by_key(|t| Reverse(t.memory_bytes));
+    output::print_memory_breakdown(tables);
+
+    Ok(())
+}
+
+async fn do_table_info(args: &ArgMatches) -> Result<()> {
+    let table_name = args
+        .get_one::<String>("name")
+        .expect("name is required by clap");
+    let with_stats = args.get_flag("stats");
+
+    let details = ets_source(args)?
+        .table_details(table_name, with_stats)
+        .await?;
+    output::print_table_details(&details, with_stats, get_output_format(args));
+
+    Ok(())
+}
+
+async fn do_table_schema(args: &ArgMatches) -> Result<()> {
     let node = args
         .get_one::<String>("node")
         .expect("node is required by clap");
     let cookie = resolve_erlang_cookie(args)?;
+    let table_name = args
+        .get_one::<String>("name")
+        .expect("name is required by clap");
+    let sample_size = if args.get_flag("all") {
+        None
+    } else {
+        Some(
+            args.get_one::<u64>("sample_size")
+                .map(|n| *n as usize)
+                .unwrap_or(DEFAULT_SCHEMA_SAMPLE_SIZE),
+        )
+    };
+
+    let schema = infer_table_schema(node, &cookie, table_name, sample_size).await?;
+    match args.get_one::<String>("emit").map(|s| s.as_str()) {
+        Some("json-schema") => output::print_json(&schema.to_json_schema()),
+        Some("rust") => print!("{}", schema.to_rust()),
+        _ => output::print_table_schema(&schema, get_output_format(args)),
+    }
+
+    Ok(())
+}
+
+async fn do_dump_table(args: &ArgMatches) -> Result<()> {
+    let table_name = args
+        .get_one::<String>("name")
+        .expect("name is required by clap");
+
+    let source = ets_source(args)?;
+    let entries = match args.get_one::<u64>("page_size") {
+        Some(&page_size) => dump_in_pages(source.as_ref(), table_name, page_size as usize).await?,
+        None => source.dump_table(table_name).await?,
+    };
+    output::print_table_dump(table_name, entries);
+
+    Ok(())
+}
+
+async fn dump_in_pages(
+    source: &dyn EtsSource,
+    table_name: &str,
+    page_size: usize,
+) -> Result<Vec<OwnedTerm>> {
+    let mut entries = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = source.page_table(table_name, cursor, page_size).await?;
+        entries.extend(page.entries);
+        match page.next {
+            Some(next) => cursor = Some(next),
+            None => return Ok(entries),
+        }
+    }
+}
+
+fn get_output_format(args: &ArgMatches) -> OutputFormat {
+    OutputFormat::from_arg(args.get_one::<String>("format").map(|s| s.as_str()))
+}
+
+async fn do_take_snapshot(args: &ArgMatches) -> Result<()> {
+    let node = args
+        .get_one::<String>("node")
+        .expect("node is required by clap");
+    let cookie = resolve_erlang_cookie(args)?;
+    let output_path = args
+        .get_one::<String>("output")
+        .expect("output is required by clap");
+    let include_contents = args.get_flag("contents");
+
+    let snapshot = take_snapshot(node, &cookie, include_contents).await?;
+    snapshot.write_to(Path::new(output_path))?;
+
+    println!(
+        "Snapshot of {} tables ({} with contents) written to {}",
+        snapshot.tables.len(),
+        snapshot.contents.len(),
+        output_path
+    );
+
+    Ok(())
+}
+
+fn do_diff_snapshots(args: &ArgMatches) -> Result<()> {
+    let old_path = args
+        .get_one::<String>("old")
+        .expect("old is required by clap");
+    let new_path = args
+        .get_one::<String>("new")
+        .expect("new is required by clap");
+    let include_contents = args.get_flag("contents");
+    let format = get_output_format(args);
+
+    let old = Snapshot::read_from(Path::new(old_path))?;
+    let new = Snapshot::read_from(Path::new(new_path))?;
+
+    if include_contents && !(old.has_contents() && new.has_contents()) {
+        eprintln!(
+            "Note: one or both snapshots were taken without --contents, object-level changes are not available"
+        );
+    }
+
+    let diff = diff_snapshots(&old, &new);
+    output::print_snapshot_diff(&diff, &new.tables, include_contents, format);
+
+    Ok(())
+}
+
+fn get_nodes(args: &ArgMatches) -> Vec<&str> {
+    args.get_many::<String>("node")
+        .expect("node is required by clap")
+        .map(|s| s.as_str())
+        .collect()
+}
+
+async fn do_compare_table(args: &ArgMatches) -> Result<()> {
+    let nodes = get_nodes(args);
+    let [left_node, right_node] = nodes.as_slice() else {
+        return Err(Error::InvalidArgument(
+            "compare requires exactly two --node values".to_string(),
+        ));
+    };
+    let cookie = resolve_erlang_cookie(args)?;
+    let table_name = args
+        .get_one::<String>("name")
+        .expect("name is required by clap");
+    let hash_threshold = args
+        .get_one::<u64>("hash_threshold")
+        .copied()
+        .unwrap_or(DEFAULT_HASH_THRESHOLD);
+    let method = match args.get_one::<String>("method").map(|s| s.as_str()) {
+        Some("contents") => ComparisonMethod::Contents,
+        Some("hashes") => ComparisonMethod::KeyHashes,
+        _ => ComparisonMethod::Auto { hash_threshold },
+    };
+
+    let comparison = compare_table(left_node, right_node, &cookie, table_name, method).await?;
+    output::print_table_comparison(&comparison, get_output_format(args));
+
+    Ok(())
+}
+
+/// Returns whether every node produced the same checksum.
+async fn do_checksum_table(args: &ArgMatches) -> Result<bool> {
+    let nodes = get_nodes(args);
+    let cookie = resolve_erlang_cookie(args)?;
+    let table_name = args
+        .get_one::<String>("name")
+        .expect("name is required by clap");
+    let chunk_size = args
+        .get_one::<u64>("chunk_size")
+        .map(|n| *n as usize)
+        .unwrap_or(DEFAULT_CHECKSUM_CHUNK_SIZE);
+    let timeout_ms = args
+        .get_one::<u64>("timeout")
+        .copied()
+        .unwrap_or(DEFAULT_CHECKSUM_TIMEOUT_MS);
+
+    let checksums = checksum_table(&nodes, &cookie, table_name, chunk_size, timeout_ms).await?;
+    output::print_table_checksums(&checksums, get_output_format(args));
+
+    Ok(checksums.all_match())
+}
+
+async fn do_find_key(args: &ArgMatches) -> Result<()> {
+    let node = args
+        .get_one::<String>("node")
+        .expect("node is required by clap");
+    let cookie = resolve_erlang_cookie(args)?;
+    let key = args
+        .get_one::<String>("key")
+        .expect("key is required by clap");
     let pattern = get_pattern_filter(args)?;
 
-    let tables = list_tables(node, &cookie).await?;
-    let tables = filter_tables(tables, pattern.as_ref());
-    output::print_table_list(tables);
+    let result = find_key(node, &cookie, key, pattern.as_ref()).await?;
+    output::print_key_search(&result, get_output_format(args));
 
     Ok(())
 }
 
-async fn memory_breakdown(args: &ArgMatches) -> Result<()> {
+async fn do_grep_tables(args: &ArgMatches) -> Result<()> {
     let node = args
         .get_one::<String>("node")
         .expect("node is required by clap");
     let cookie = resolve_erlang_cookie(args)?;
+    let regex = args
+        .get_one::<String>("regex")
+        .expect("regex is required by clap");
+    let regex = Regex::new(regex).map_err(|e| Error::InvalidPattern(e.to_string()))?;
     let pattern = get_pattern_filter(args)?;
+    let max_matches = args.get_one::<usize>("max_matches").copied();
 
-    let tables = list_tables(node, &cookie).await?;
-    let mut tables = filter_tables(tables, pattern.as_ref());
-    tables.sort_by(|a, b| b.memory_bytes.cmp(&a.memory_bytes));
-    output::print_memory_breakdown(tables);
+    let result = grep_tables(node, &cookie, &regex, pattern.as_ref(), max_matches).await?;
+    output::print_grep_result(&result, get_output_format(args));
 
     Ok(())
 }
 
-async fn do_dump_table(args: &ArgMatches) -> Result<()> {
+async fn do_supervision_tree(args: &ArgMatches) -> Result<()> {
+    let node = args
+        .get_one::<String>("node")
+        .expect("node is required by clap");
+    let cookie = resolve_erlang_cookie(args)?;
+
+    let mut tree = supervision_tree(node, &cookie).await?;
+    if !args.get_flag("all") {
+        tree.prune_empty();
+    }
+    output::print_supervision_tree(&tree, get_output_format(args));
+
+    Ok(())
+}
+
+async fn do_get_owner_state(args: &ArgMatches) -> Result<()> {
     let node = args
         .get_one::<String>("node")
         .expect("node is required by clap");
@@ -142,9 +620,190 @@ async fn do_dump_table(args: &ArgMatches) -> Result<()> {
     let table_name = args
         .get_one::<String>("name")
         .expect("name is required by clap");
+    let kind = if args.get_flag("status") {
+        OwnerStateKind::Status
+    } else {
+        OwnerStateKind::State
+    };
+    let timeout_ms = args
+        .get_one::<u64>("timeout")
+        .copied()
+        .unwrap_or(DEFAULT_OWNER_STATE_TIMEOUT_MS);
+
+    let state = get_owner_state(node, &cookie, table_name, kind, timeout_ms).await?;
+    output::print_owner_state(&state, get_output_format(args));
+
+    Ok(())
+}
+
+async fn do_detect_leaks(args: &ArgMatches) -> Result<()> {
+    let node = args
+        .get_one::<String>("node")
+        .expect("node is required by clap");
+    let cookie = resolve_erlang_cookie(args)?;
+    let interval = *args
+        .get_one::<Duration>("interval")
+        .expect("interval has a default value");
+    let samples = *args
+        .get_one::<u64>("samples")
+        .expect("samples has a default value");
+    let top = *args
+        .get_one::<usize>("top")
+        .expect("top has a default value");
+
+    let report = sample_tables(node, &cookie, interval, samples as usize).await?;
+    output::print_leak_report(&report, top, get_output_format(args));
+
+    Ok(())
+}
+
+/// Returns `true` when a finding reaches the `--fail-on` severity.
+async fn do_lint(args: &ArgMatches) -> Result<bool> {
+    let node = args
+        .get_one::<String>("node")
+        .expect("node is required by clap");
+    let cookie = resolve_erlang_cookie(args)?;
+    let fail_on = args
+        .get_one::<String>("fail_on")
+        .map(|name| Severity::from_name(name))
+        .transpose()?;
+
+    let mut thresholds = LintThresholds::default();
+    if let Some(size) = args.get_one::<u64>("large_table_size") {
+        thresholds.large_table_size = *size;
+    }
+    if let Some(len) = args.get_one::<u64>("message_queue_threshold") {
+        thresholds.message_queue_len = *len;
+    }
+
+    let report = lint_node(node, &cookie, &thresholds).await?;
+    output::print_lint_report(&report, get_output_format(args));
+
+    Ok(fail_on.is_some_and(|min| report.max_severity().is_some_and(|max| max >= min)))
+}
+
+async fn do_ets_alloc_report(args: &ArgMatches) -> Result<()> {
+    let node = args
+        .get_one::<String>("node")
+        .expect("node is required by clap");
+    let cookie = resolve_erlang_cookie(args)?;
+
+    let report = ets_alloc_report(node, &cookie).await?;
+    output::print_ets_alloc_report(&report, get_output_format(args));
 
-    let entries = dump_table(node, &cookie, table_name).await?;
+    Ok(())
+}
+
+async fn do_list_persistent_terms(args: &ArgMatches) -> Result<()> {
+    let node = args
+        .get_one::<String>("node")
+        .expect("node is required by clap");
+    let cookie = resolve_erlang_cookie(args)?;
+    let pattern = get_pattern_filter(args)?;
+
+    let terms = list_persistent_terms(node, &cookie, pattern.as_ref()).await?;
+    output::print_persistent_terms(&terms, get_output_format(args));
+
+    Ok(())
+}
+
+async fn do_get_persistent_term(args: &ArgMatches) -> Result<()> {
+    let node = args
+        .get_one::<String>("node")
+        .expect("node is required by clap");
+    let cookie = resolve_erlang_cookie(args)?;
+    let key = args
+        .get_one::<String>("key")
+        .expect("key is required by clap");
+
+    let value = get_persistent_term(node, &cookie, key).await?;
+    output::print_persistent_term_value(key, &value, get_output_format(args));
+
+    Ok(())
+}
+
+async fn do_list_dets_tables(args: &ArgMatches) -> Result<()> {
+    let node = args
+        .get_one::<String>("node")
+        .expect("node is required by clap");
+    let cookie = resolve_erlang_cookie(args)?;
+    let pattern = get_pattern_filter(args)?;
+
+    let tables = list_dets_tables(node, &cookie).await?;
+    let tables = filter_dets_tables(tables, pattern.as_ref());
+    match get_output_format(args) {
+        OutputFormat::Json => output::print_json(&tables),
+        OutputFormat::Table => output::print_dets_table_list(tables),
+    }
+
+    Ok(())
+}
+
+async fn do_dump_dets_table(args: &ArgMatches) -> Result<()> {
+    let node = args
+        .get_one::<String>("node")
+        .expect("node is required by clap");
+    let cookie = resolve_erlang_cookie(args)?;
+    let table_name = args
+        .get_one::<String>("name")
+        .expect("name is required by clap");
+    let chunk_size = args
+        .get_one::<usize>("chunk_size")
+        .copied()
+        .unwrap_or(DEFAULT_CHUNK_SIZE);
+
+    let entries = dump_dets_table(node, &cookie, table_name, chunk_size).await?;
     output::print_table_dump(table_name, entries);
 
     Ok(())
 }
+
+async fn do_lookup_dets_table(args: &ArgMatches) -> Result<()> {
+    let node = args
+        .get_one::<String>("node")
+        .expect("node is required by clap");
+    let cookie = resolve_erlang_cookie(args)?;
+    let table_name = args
+        .get_one::<String>("name")
+        .expect("name is required by clap");
+    let key = args
+        .get_one::<String>("key")
+        .expect("key is required by clap");
+
+    let objects = lookup_dets_table(node, &cookie, table_name, key).await?;
+    output::print_key_lookup(table_name, key, objects);
+
+    Ok(())
+}
+
+async fn do_list_mnesia_tables(args: &ArgMatches) -> Result<()> {
+    let node = args
+        .get_one::<String>("node")
+        .expect("node is required by clap");
+    let cookie = resolve_erlang_cookie(args)?;
+    let pattern = get_pattern_filter(args)?;
+
+    let tables = list_mnesia_tables(node, &cookie).await?;
+    let tables = filter_mnesia_tables(tables, pattern.as_ref());
+    match get_output_format(args) {
+        OutputFormat::Json => output::print_json(&tables),
+        OutputFormat::Table => output::print_mnesia_table_list(tables),
+    }
+
+    Ok(())
+}
+
+async fn do_dump_mnesia_table(args: &ArgMatches) -> Result<()> {
+    let node = args
+        .get_one::<String>("node")
+        .expect("node is required by clap");
+    let cookie = resolve_erlang_cookie(args)?;
+    let table_name = args
+        .get_one::<String>("name")
+        .expect("name is required by clap");
+
+    let dump = dump_mnesia_table(node, &cookie, table_name).await?;
+    output::print_rendered_dump(table_name, dump.rendered_records());
+
+    Ok(())
+}
@@ -13,14 +13,29 @@
 // limitations under the License.
 
 use erltf::OwnedTerm;
-use ets_lib::EtsTableInfo;
+use ets_lib::diff::object_key;
+use ets_lib::owner::OwnerStateKind;
+use ets_lib::search::render_entry;
+use ets_lib::{
+    ClusterTables, CompressionEstimate, CompressionStatus, ContentsDiff, DetsTableInfo,
+    EtsAllocReport, EtsTableInfo, GrepMatch, GrepResult, GrowthSeries, InventoryComparison,
+    KeySearchResult, LeakReport, LintReport, MnesiaTableInfo, OwnerState, PersistentTerms,
+    ProcessKind, ProcessNode, SnapshotDiff, SupervisionTree, TableChecksums, TableComparison,
+    TableDetails, TableGroup, TableSchema, TermKind, TermShape,
+};
 use humansize::{BINARY, format_size};
+use serde::Serialize;
+use tabled::builder::Builder;
 use tabled::settings::object::Rows;
 use tabled::settings::{Format, Modify, Style};
 use tabled::{Table, Tabled};
 
 fn apply_table_style<T: Tabled>(rows: Vec<T>) -> String {
-    Table::new(rows)
+    style_table(Table::new(rows))
+}
+
+fn style_table(mut table: Table) -> String {
+    table
         .with(Style::modern())
         .with(Modify::new(Rows::first()).with(Format::content(|s| format!("\x1b[1m{}\x1b[0m", s))))
         .to_string()
@@ -42,6 +57,34 @@ struct TableRow {
     protection: String,
 }
 
+#[derive(Tabled)]
+struct ClusterTableRow {
+    #[tabled(rename = "Node")]
+    node: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Type")]
+    table_type: String,
+    #[tabled(rename = "Objects")]
+    size: u64,
+    #[tabled(rename = "Memory")]
+    memory: String,
+    #[tabled(rename = "Owner")]
+    owner: String,
+    #[tabled(rename = "Protection")]
+    protection: String,
+}
+
+#[derive(Tabled)]
+struct ChecksumRow {
+    #[tabled(rename = "Node")]
+    node: String,
+    #[tabled(rename = "Objects")]
+    objects: u64,
+    #[tabled(rename = "Checksum")]
+    checksum: String,
+}
+
 #[derive(Tabled)]
 struct MemoryBreakdownRow {
     #[tabled(rename = "Name")]
@@ -54,10 +97,55 @@ struct MemoryBreakdownRow {
     table_type: String,
 }
 
+#[derive(Tabled)]
+struct MemoryBreakdownOffHeapRow {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Memory")]
+    memory: String,
+    #[tabled(rename = "Off-heap")]
+    off_heap: String,
+    #[tabled(rename = "Objects")]
+    size: u64,
+    #[tabled(rename = "Type")]
+    table_type: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Table,
+    Json,
+}
+
+impl OutputFormat {
+    pub fn from_arg(value: Option<&str>) -> Self {
+        match value {
+            Some("json") => OutputFormat::Json,
+            _ => OutputFormat::Table,
+        }
+    }
+}
+
+pub fn print_json<T: Serialize>(value: &T) {
+    match serde_json::to_string_pretty(value) {
+        Ok(json) => println!("{}", json),
+        Err(e) => eprintln!("Error: failed to serialize output: {}", e),
+    }
+}
+
 pub fn format_memory(bytes: u64) -> String {
     format_size(bytes, BINARY)
 }
 
+pub fn format_memory_delta(delta: i64) -> String {
+    let sign = if delta < 0 { "-" } else { "+" };
+    format!("{}{}", sign, format_memory(delta.unsigned_abs()))
+}
+
+pub fn format_count_delta(delta: i64) -> String {
+    format!("{:+}", delta)
+}
+
 pub fn print_table_list(tables: Vec<EtsTableInfo>) {
     if tables.is_empty() {
         println!("No ETS tables found.");
@@ -81,6 +169,54 @@ pub fn print_table_list(tables: Vec<EtsTableInfo>) {
     println!("\nTotal: {} tables", count);
 }
 
+pub fn print_cluster_table_list(cluster: ClusterTables, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        print_json(&cluster);
+        return;
+    }
+
+    let count = cluster.tables.len();
+    let node_count = cluster
+        .tables
+        .iter()
+        .map(|t| t.node.as_str())
+        .collect::<std::collections::BTreeSet<_>>()
+        .len();
+
+    if count == 0 {
+        println!("No ETS tables found.");
+    } else {
+        let rows: Vec<ClusterTableRow> = cluster
+            .tables
+            .into_iter()
+            .map(|t| ClusterTableRow {
+                node: t.node,
+                name: t.info.name,
+                table_type: t.info.table_type.to_string(),
+                size: t.info.size,
+                memory: format_memory(t.info.memory_bytes),
+                owner: t.info.owner,
+                protection: t.info.protection.to_string(),
+            })
+            .collect();
+        println!("{}", apply_table_style(rows));
+    }
+
+    for failure in &cluster.failures {
+        eprintln!(
+            "Warning: node {} did not respond: {}",
+            failure.node, failure.reason
+        );
+    }
+
+    println!(
+        "\nTotal: {} tables on {} nodes, {} nodes failed to respond",
+        count,
+        node_count,
+        cluster.failures.len()
+    );
+}
+
 pub fn print_memory_breakdown(tables: Vec<EtsTableInfo>) {
     if tables.is_empty() {
         println!("No ETS tables found.");
@@ -90,6 +226,32 @@ pub fn print_memory_breakdown(tables: Vec<EtsTableInfo>) {
     let count = tables.len();
     let total_memory: u64 = tables.iter().map(|t| t.memory_bytes).sum();
 
+    if tables.iter().any(|t| t.off_heap_bytes.is_some()) {
+        let total_off_heap: u64 = tables.iter().filter_map(|t| t.off_heap_bytes).sum();
+        let rows: Vec<MemoryBreakdownOffHeapRow> = tables
+            .into_iter()
+            .map(|t| MemoryBreakdownOffHeapRow {
+                name: t.name,
+                memory: format_memory(t.memory_bytes),
+                off_heap: t
+                    .off_heap_bytes
+                    .map(format_memory)
+                    .unwrap_or_else(|| "-".to_string()),
+                size: t.size,
+                table_type: t.table_type.to_string(),
+            })
+            .collect();
+
+        println!("{}", apply_table_style(rows));
+        println!(
+            "\nTotal: {} tables, {} memory, an estimated {} in off-heap binaries",
+            count,
+            format_memory(total_memory),
+            format_memory(total_off_heap)
+        );
+        return;
+    }
+
     let rows: Vec<MemoryBreakdownRow> = tables
         .into_iter()
         .map(|t| MemoryBreakdownRow {
@@ -108,6 +270,110 @@ pub fn print_memory_breakdown(tables: Vec<EtsTableInfo>) {
     );
 }
 
+#[derive(Tabled)]
+struct CompressionEstimateRow {
+    #[tabled(rename = "Table Name")]
+    name: String,
+    #[tabled(rename = "Memory")]
+    memory: String,
+    #[tabled(rename = "Objects")]
+    size: u64,
+    #[tabled(rename = "Sampled")]
+    sampled: u64,
+    #[tabled(rename = "Ratio")]
+    ratio: String,
+    #[tabled(rename = "Est. Savings")]
+    savings: String,
+    #[tabled(rename = "Projected Memory")]
+    projected: String,
+}
+
+pub fn print_compression_estimate(estimate: &CompressionEstimate) {
+    if estimate.tables.is_empty() {
+        println!("No ETS tables found.");
+        return;
+    }
+
+    let rows: Vec<CompressionEstimateRow> = estimate
+        .tables
+        .iter()
+        .map(|t| CompressionEstimateRow {
+            name: t.name.clone(),
+            memory: format_memory(t.memory_bytes),
+            size: t.size,
+            sampled: t.sampled_objects,
+            ratio: match (t.status, t.ratio) {
+                (CompressionStatus::Estimated, Some(ratio)) => format!("{:.2}", ratio),
+                (CompressionStatus::AlreadyCompressed, _) => "compressed".to_string(),
+                (CompressionStatus::Unreadable, _) => "private".to_string(),
+                _ => "-".to_string(),
+            },
+            savings: format_memory(t.estimated_savings_bytes),
+            projected: format_memory(t.projected_memory_bytes),
+        })
+        .collect();
+
+    println!("{}", apply_table_style(rows));
+    println!(
+        "\nTotal: {} tables, {} memory, an estimated {} ({}) could be saved with the compressed option",
+        estimate.tables.len(),
+        format_memory(estimate.total_memory_bytes),
+        format_memory(estimate.estimated_savings_bytes),
+        format_percentage(if estimate.total_memory_bytes > 0 {
+            estimate.estimated_savings_bytes as f64 / estimate.total_memory_bytes as f64 * 100.0
+        } else {
+            0.0
+        })
+    );
+}
+
+#[derive(Tabled)]
+struct MemoryGroupRow {
+    #[tabled(rename = "Group")]
+    name: String,
+    #[tabled(rename = "Tables")]
+    table_count: usize,
+    #[tabled(rename = "Objects")]
+    object_count: u64,
+    #[tabled(rename = "Memory")]
+    memory: String,
+    #[tabled(rename = "% of Total")]
+    percentage: String,
+}
+
+pub fn format_percentage(percentage: f64) -> String {
+    format!("{:.1}%", percentage)
+}
+
+pub fn print_memory_groups(groups: &[TableGroup]) {
+    if groups.is_empty() {
+        println!("No ETS tables found.");
+        return;
+    }
+
+    let table_count: usize = groups.iter().map(|g| g.table_count).sum();
+    let total_memory: u64 = groups.iter().map(|g| g.memory_bytes).sum();
+
+    let rows: Vec<MemoryGroupRow> = groups
+        .iter()
+        .map(|g| MemoryGroupRow {
+            name: g.name.clone(),
+            table_count: g.table_count,
+            object_count: g.object_count,
+            memory: format_memory(g.memory_bytes),
+            percentage: format_percentage(g.memory_percentage),
+        })
+        .collect();
+
+    println!("{}", apply_table_style(rows));
+    println!(
+        "\nTotal: {} groups, {} tables, {} memory",
+        groups.len(),
+        table_count,
+        format_memory(total_memory)
+    );
+}
+
 #[derive(Tabled)]
 struct DumpRow {
     #[tabled(rename = "#")]
@@ -116,23 +382,1176 @@ struct DumpRow {
     entry: String,
 }
 
+fn dump_rows(entries: Vec<String>) -> Vec<DumpRow> {
+    entries
+        .into_iter()
+        .enumerate()
+        .map(|(i, entry)| DumpRow {
+            index: i + 1,
+            entry,
+        })
+        .collect()
+}
+
 pub fn print_table_dump(table_name: &str, entries: Vec<OwnedTerm>) {
+    print_rendered_dump(table_name, render_terms(&entries));
+}
+
+/// Prints entries that have already been rendered, e.g. Mnesia records
+/// rendered with their attribute names.
+pub fn print_rendered_dump(table_name: &str, entries: Vec<String>) {
     if entries.is_empty() {
         println!("Table '{}' is empty.", table_name);
         return;
     }
 
     let count = entries.len();
-    let rows: Vec<DumpRow> = entries
+    println!("Table: {}", table_name);
+    println!("{}", apply_table_style(dump_rows(entries)));
+    println!("\nTotal: {} entries", count);
+}
+
+pub fn print_key_lookup(table_name: &str, key: &str, entries: Vec<OwnedTerm>) {
+    if entries.is_empty() {
+        println!("Table '{}' has no objects with key {}.", table_name, key);
+        return;
+    }
+
+    let count = entries.len();
+    println!("Table: {}, key: {}", table_name, key);
+    println!("{}", apply_table_style(dump_rows(render_terms(&entries))));
+    println!("\nTotal: {} entries", count);
+}
+
+#[derive(Tabled)]
+struct DetsTableRow {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Type")]
+    table_type: String,
+    #[tabled(rename = "Objects")]
+    size: u64,
+    #[tabled(rename = "File Size")]
+    file_size: String,
+    #[tabled(rename = "File")]
+    file: String,
+}
+
+#[derive(Tabled)]
+struct MnesiaTableRow {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Storage")]
+    storage_type: String,
+    #[tabled(rename = "Record")]
+    record: String,
+    #[tabled(rename = "Objects")]
+    size: u64,
+    #[tabled(rename = "Memory")]
+    memory: String,
+    #[tabled(rename = "Replicas")]
+    replicas: String,
+}
+
+fn replica_summary(table: &MnesiaTableInfo) -> String {
+    [
+        ("ram", &table.ram_copies),
+        ("disc", &table.disc_copies),
+        ("disc_only", &table.disc_only_copies),
+    ]
+    .into_iter()
+    .filter(|(_, nodes)| !nodes.is_empty())
+    .map(|(kind, nodes)| format!("{}: {}", kind, nodes.join(", ")))
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+pub fn print_mnesia_table_list(tables: Vec<MnesiaTableInfo>) {
+    if tables.is_empty() {
+        println!("No Mnesia tables found.");
+        return;
+    }
+
+    let count = tables.len();
+    let rows: Vec<MnesiaTableRow> = tables
+        .iter()
+        .map(|t| MnesiaTableRow {
+            name: t.name.clone(),
+            storage_type: t.storage_type.clone(),
+            record: format!("#{}{{{}}}", t.record_name, t.attributes.join(", ")),
+            size: t.size,
+            memory: format_memory(t.memory_bytes),
+            replicas: replica_summary(t),
+        })
+        .collect();
+
+    println!("{}", apply_table_style(rows));
+    println!("\nTotal: {} tables", count);
+}
+
+pub fn print_dets_table_list(tables: Vec<DetsTableInfo>) {
+    if tables.is_empty() {
+        println!("No DETS tables found.");
+        return;
+    }
+
+    let count = tables.len();
+    let rows: Vec<DetsTableRow> = tables
         .into_iter()
-        .enumerate()
-        .map(|(i, entry)| DumpRow {
-            index: i + 1,
-            entry: entry.to_string(),
+        .map(|t| DetsTableRow {
+            name: t.name,
+            table_type: t.table_type.to_string(),
+            size: t.size,
+            file_size: format_memory(t.file_size),
+            file: t.file,
         })
         .collect();
 
-    println!("Table: {}", table_name);
     println!("{}", apply_table_style(rows));
-    println!("\nTotal: {} entries", count);
+    println!("\nTotal: {} tables", count);
+}
+
+#[derive(Tabled)]
+struct SnapshotTableRow {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Type")]
+    table_type: String,
+    #[tabled(rename = "Objects")]
+    size: u64,
+    #[tabled(rename = "Memory")]
+    memory: String,
+}
+
+#[derive(Tabled)]
+struct TableDeltaRow {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Tables")]
+    count: String,
+    #[tabled(rename = "Objects")]
+    size: String,
+    #[tabled(rename = "Objects Delta")]
+    size_delta: String,
+    #[tabled(rename = "Memory")]
+    memory: String,
+    #[tabled(rename = "Memory Delta")]
+    memory_delta: String,
+}
+
+#[derive(Tabled)]
+struct ObjectChangeRow {
+    #[tabled(rename = "Change")]
+    change: &'static str,
+    #[tabled(rename = "Key")]
+    key: String,
+    #[tabled(rename = "Entry")]
+    entry: String,
+}
+
+#[derive(Serialize)]
+struct ChangedObjectsJson {
+    key: String,
+    old: Vec<String>,
+    new: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct ContentsDiffJson {
+    table_name: String,
+    added: Vec<String>,
+    removed: Vec<String>,
+    changed: Vec<ChangedObjectsJson>,
+}
+
+#[derive(Serialize)]
+struct SnapshotDiffJson<'a> {
+    added: &'a [EtsTableInfo],
+    removed: &'a [EtsTableInfo],
+    changed: &'a [ets_lib::TableDelta],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contents: Option<Vec<ContentsDiffJson>>,
+}
+
+fn render_terms(terms: &[OwnedTerm]) -> Vec<String> {
+    terms.iter().map(|t| t.to_string()).collect()
+}
+
+fn snapshot_table_rows(tables: &[EtsTableInfo]) -> Vec<SnapshotTableRow> {
+    tables
+        .iter()
+        .map(|t| SnapshotTableRow {
+            name: t.name.clone(),
+            table_type: t.table_type.to_string(),
+            size: t.size,
+            memory: format_memory(t.memory_bytes),
+        })
+        .collect()
+}
+
+fn contents_diff_rows(diff: &ContentsDiff, keypos: u64) -> Vec<ObjectChangeRow> {
+    let key_of = |object: &OwnedTerm| {
+        object_key(object, keypos)
+            .map(|k| k.to_string())
+            .unwrap_or_default()
+    };
+
+    let mut rows = Vec::new();
+    for object in &diff.added {
+        rows.push(ObjectChangeRow {
+            change: "added",
+            key: key_of(object),
+            entry: object.to_string(),
+        });
+    }
+    for object in &diff.removed {
+        rows.push(ObjectChangeRow {
+            change: "removed",
+            key: key_of(object),
+            entry: object.to_string(),
+        });
+    }
+    for change in &diff.changed {
+        for object in &change.old {
+            rows.push(ObjectChangeRow {
+                change: "changed (old)",
+                key: change.key.to_string(),
+                entry: object.to_string(),
+            });
+        }
+        for object in &change.new {
+            rows.push(ObjectChangeRow {
+                change: "changed (new)",
+                key: change.key.to_string(),
+                entry: object.to_string(),
+            });
+        }
+    }
+    rows
+}
+
+pub fn print_snapshot_diff(
+    diff: &SnapshotDiff,
+    new_tables: &[EtsTableInfo],
+    include_contents: bool,
+    format: OutputFormat,
+) {
+    if format == OutputFormat::Json {
+        let contents = include_contents.then(|| {
+            diff.contents
+                .iter()
+                .map(|c| ContentsDiffJson {
+                    table_name: c.table_name.clone(),
+                    added: render_terms(&c.added),
+                    removed: render_terms(&c.removed),
+                    changed: c
+                        .changed
+                        .iter()
+                        .map(|ch| ChangedObjectsJson {
+                            key: ch.key.to_string(),
+                            old: render_terms(&ch.old),
+                            new: render_terms(&ch.new),
+                        })
+                        .collect(),
+                })
+                .collect()
+        });
+        print_json(&SnapshotDiffJson {
+            added: &diff.added,
+            removed: &diff.removed,
+            changed: &diff.changed,
+            contents,
+        });
+        return;
+    }
+
+    if diff.added.is_empty() && diff.removed.is_empty() && diff.changed.is_empty() {
+        println!("No table-level differences.");
+    }
+
+    if !diff.added.is_empty() {
+        println!("Tables added:");
+        println!("{}", apply_table_style(snapshot_table_rows(&diff.added)));
+    }
+
+    if !diff.removed.is_empty() {
+        println!("Tables removed:");
+        println!("{}", apply_table_style(snapshot_table_rows(&diff.removed)));
+    }
+
+    if !diff.changed.is_empty() {
+        let rows: Vec<TableDeltaRow> = diff
+            .changed
+            .iter()
+            .map(|d| TableDeltaRow {
+                name: d.name.clone(),
+                count: format!("{} -> {}", d.old_count, d.new_count),
+                size: format!("{} -> {}", d.old_size, d.new_size),
+                size_delta: format_count_delta(d.size_delta),
+                memory: format!(
+                    "{} -> {}",
+                    format_memory(d.old_memory_bytes),
+                    format_memory(d.new_memory_bytes)
+                ),
+                memory_delta: format_memory_delta(d.memory_delta),
+            })
+            .collect();
+        println!("Tables changed:");
+        println!("{}", apply_table_style(rows));
+    }
+
+    if include_contents {
+        for contents in &diff.contents {
+            let keypos = new_tables
+                .iter()
+                .find(|t| t.name == contents.table_name)
+                .map(|t| t.keypos)
+                .unwrap_or(1);
+            println!("\nTable: {}", contents.table_name);
+            println!(
+                "{}",
+                apply_table_style(contents_diff_rows(contents, keypos))
+            );
+        }
+    }
+
+    println!(
+        "\nTotal: {} added, {} removed, {} changed tables",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.changed.len()
+    );
+}
+
+#[derive(Tabled)]
+struct KeyDifferenceRow {
+    #[tabled(rename = "Difference")]
+    difference: String,
+    #[tabled(rename = "Key")]
+    key: String,
+}
+
+#[derive(Serialize)]
+struct TableComparisonJson<'a> {
+    table_name: &'a str,
+    left_node: &'a str,
+    right_node: &'a str,
+    left_size: u64,
+    right_size: u64,
+    method: String,
+    only_left: Vec<String>,
+    only_right: Vec<String>,
+    differing: Vec<String>,
+}
+
+pub fn print_table_comparison(comparison: &TableComparison, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        print_json(&TableComparisonJson {
+            table_name: &comparison.table_name,
+            left_node: &comparison.left_node,
+            right_node: &comparison.right_node,
+            left_size: comparison.left_size,
+            right_size: comparison.right_size,
+            method: comparison.method.to_string(),
+            only_left: render_terms(&comparison.only_left),
+            only_right: render_terms(&comparison.only_right),
+            differing: render_terms(&comparison.differing),
+        });
+        return;
+    }
+
+    println!(
+        "Table: {} ({}: {} objects, {}: {} objects, compared by {})",
+        comparison.table_name,
+        comparison.left_node,
+        comparison.left_size,
+        comparison.right_node,
+        comparison.right_size,
+        comparison.method
+    );
+
+    if comparison.is_identical() {
+        println!("\nContents are identical.");
+        return;
+    }
+
+    let only_left = format!("only on {}", comparison.left_node);
+    let only_right = format!("only on {}", comparison.right_node);
+    let rows: Vec<KeyDifferenceRow> = comparison
+        .only_left
+        .iter()
+        .map(|k| (only_left.clone(), k))
+        .chain(
+            comparison
+                .only_right
+                .iter()
+                .map(|k| (only_right.clone(), k)),
+        )
+        .chain(
+            comparison
+                .differing
+                .iter()
+                .map(|k| ("values differ".to_string(), k)),
+        )
+        .map(|(difference, key)| KeyDifferenceRow {
+            difference,
+            key: key.to_string(),
+        })
+        .collect();
+
+    println!("{}", apply_table_style(rows));
+    println!(
+        "\nTotal: {} only on {}, {} only on {}, {} differing",
+        comparison.only_left.len(),
+        comparison.left_node,
+        comparison.only_right.len(),
+        comparison.right_node,
+        comparison.differing.len()
+    );
+}
+
+fn highlight(text: String, color_code: &str) -> String {
+    format!("\x1b[{}m{}\x1b[0m", color_code, text)
+}
+
+pub fn print_inventory_comparison(inventory: &InventoryComparison, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        print_json(inventory);
+        return;
+    }
+
+    if inventory.rows.is_empty() {
+        println!("No ETS tables found.");
+        return;
+    }
+
+    let mut builder = Builder::default();
+    let mut header = vec!["Name".to_string()];
+    header.extend(inventory.nodes.iter().cloned());
+    builder.push_record(header);
+
+    for row in &inventory.rows {
+        let name = if row.is_missing_on_some() {
+            highlight(row.name.clone(), "31")
+        } else {
+            row.name.clone()
+        };
+        let mut record = vec![name];
+        for (i, stats) in row.per_node.iter().enumerate() {
+            let cell = match stats {
+                None => highlight("missing".to_string(), "31"),
+                Some(stats) => {
+                    let text = format!(
+                        "{} objects, {}",
+                        stats.size,
+                        format_memory(stats.memory_bytes)
+                    );
+                    if row.is_outlier(i) {
+                        highlight(text, "33")
+                    } else {
+                        text
+                    }
+                }
+            };
+            record.push(cell);
+        }
+        builder.push_record(record);
+    }
+
+    let missing = inventory
+        .rows
+        .iter()
+        .filter(|r| r.is_missing_on_some())
+        .count();
+    let outliers = inventory
+        .rows
+        .iter()
+        .filter(|r| !r.size_outliers.is_empty())
+        .count();
+
+    println!("{}", style_table(builder.build()));
+    println!(
+        "\nTotal: {} table names across {} nodes, {} missing on some nodes, {} with outlier sizes",
+        inventory.rows.len(),
+        inventory.nodes.len(),
+        missing,
+        outliers
+    );
+}
+
+#[derive(Tabled)]
+struct KeyHitRow {
+    #[tabled(rename = "Table")]
+    table: String,
+    #[tabled(rename = "Type")]
+    table_type: String,
+    #[tabled(rename = "Entry")]
+    entry: String,
+}
+
+#[derive(Serialize)]
+struct KeyHitJson<'a> {
+    table: &'a EtsTableInfo,
+    objects: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct KeySearchJson<'a> {
+    key: String,
+    hits: Vec<KeyHitJson<'a>>,
+    skipped_private: &'a [String],
+}
+
+pub fn print_key_search(result: &KeySearchResult, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        print_json(&KeySearchJson {
+            key: result.key.to_string(),
+            hits: result
+                .hits
+                .iter()
+                .map(|h| KeyHitJson {
+                    table: &h.table,
+                    objects: render_terms(&h.objects),
+                })
+                .collect(),
+            skipped_private: &result.skipped_private,
+        });
+        return;
+    }
+
+    if result.hits.is_empty() {
+        println!("No tables contain key {}.", result.key);
+    } else {
+        let rows: Vec<KeyHitRow> = result
+            .hits
+            .iter()
+            .flat_map(|hit| {
+                hit.objects.iter().map(|object| KeyHitRow {
+                    table: hit.table.name.clone(),
+                    table_type: hit.table.table_type.to_string(),
+                    entry: object.to_string(),
+                })
+            })
+            .collect();
+        println!("Key: {}", result.key);
+        println!("{}", apply_table_style(rows));
+    }
+
+    if !result.skipped_private.is_empty() {
+        println!(
+            "\nNote: skipped {} private tables: {}",
+            result.skipped_private.len(),
+            result.skipped_private.join(", ")
+        );
+    }
+
+    println!("\nTotal: {} tables contain the key", result.hits.len());
+}
+
+#[derive(Tabled)]
+struct GrepMatchRow {
+    #[tabled(rename = "Table")]
+    table: String,
+    #[tabled(rename = "#")]
+    index: usize,
+    #[tabled(rename = "Entry")]
+    entry: String,
+}
+
+pub fn highlight_matches(m: &GrepMatch) -> String {
+    m.segments()
+        .into_iter()
+        .map(|(text, matched)| {
+            if matched {
+                highlight(text.to_string(), "1;31")
+            } else {
+                text.to_string()
+            }
+        })
+        .collect()
+}
+
+pub fn print_grep_result(result: &GrepResult, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        print_json(result);
+        return;
+    }
+
+    if result.matches.is_empty() {
+        println!("No matching entries found.");
+    } else {
+        let rows: Vec<GrepMatchRow> = result
+            .matches
+            .iter()
+            .map(|m| GrepMatchRow {
+                table: m.table_name.clone(),
+                index: m.index,
+                entry: highlight_matches(m),
+            })
+            .collect();
+        println!("{}", apply_table_style(rows));
+    }
+
+    if !result.skipped_private.is_empty() {
+        println!(
+            "\nNote: skipped {} private tables: {}",
+            result.skipped_private.len(),
+            result.skipped_private.join(", ")
+        );
+    }
+
+    if !result.stopped_early.is_empty() {
+        let tables: Vec<String> = result
+            .stopped_early
+            .iter()
+            .map(|t| match &t.error {
+                None => format!(
+                    "{} (deleted after {} entries)",
+                    t.table_name, t.entries_searched
+                ),
+                Some(error) => format!(
+                    "{} (failed after {} entries: {})",
+                    t.table_name, t.entries_searched, error
+                ),
+            })
+            .collect();
+        println!(
+            "\nNote: {} tables were only partially searched: {}",
+            tables.len(),
+            tables.join(", ")
+        );
+    }
+
+    if result.truncated {
+        println!("\nNote: stopped after {} matches", result.matches.len());
+    }
+
+    println!(
+        "\nTotal: {} matching entries in {} searched tables",
+        result.matches.len(),
+        result.tables_searched
+    );
+}
+
+#[derive(Tabled)]
+struct PersistentTermRow {
+    #[tabled(rename = "Key")]
+    key: String,
+    #[tabled(rename = "Memory")]
+    memory: String,
+}
+
+pub fn print_persistent_terms(terms: &PersistentTerms, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        print_json(terms);
+        return;
+    }
+
+    if terms.terms.is_empty() {
+        println!("No persistent terms found.");
+    } else {
+        let rows: Vec<PersistentTermRow> = terms
+            .terms
+            .iter()
+            .map(|t| PersistentTermRow {
+                key: t.key.clone(),
+                memory: format_memory(t.memory_bytes),
+            })
+            .collect();
+        println!("{}", apply_table_style(rows));
+    }
+
+    println!(
+        "\nTotal: {} of {} persistent terms shown, {} memory in use",
+        terms.terms.len(),
+        terms.count,
+        format_memory(terms.total_memory_bytes)
+    );
+}
+
+#[derive(Serialize)]
+struct PersistentTermJson<'a> {
+    key: &'a str,
+    value: String,
+}
+
+pub fn print_persistent_term_value(key: &str, value: &OwnedTerm, format: OutputFormat) {
+    let value = render_entry(value);
+    if format == OutputFormat::Json {
+        print_json(&PersistentTermJson { key, value });
+        return;
+    }
+
+    println!("Key: {}", key);
+    println!("Value: {}", value);
+}
+
+fn print_process_node(node: &ProcessNode, indent: &str, last: bool, root: bool) {
+    let (branch, child_indent) = match (root, last) {
+        (true, _) => ("", String::new()),
+        (false, true) => ("└─ ", format!("{}   ", indent)),
+        (false, false) => ("├─ ", format!("{}│  ", indent)),
+    };
+    let kind = match node.kind {
+        ProcessKind::Supervisor => "supervisor",
+        ProcessKind::Worker => "worker",
+    };
+    println!(
+        "{}{}{} ({} {}) [{} tables, {}]",
+        indent,
+        branch,
+        node.name,
+        kind,
+        node.pid,
+        node.subtree_table_count,
+        format_memory(node.subtree_memory_bytes)
+    );
+
+    let count = node.tables.len() + node.children.len();
+    for (i, table) in node.tables.iter().enumerate() {
+        let connector = if i + 1 == count { "└─" } else { "├─" };
+        println!(
+            "{}{}• {} ({} objects, {})",
+            child_indent,
+            connector,
+            table.name,
+            table.size,
+            format_memory(table.memory_bytes)
+        );
+    }
+    for (i, child) in node.children.iter().enumerate() {
+        let last = node.tables.len() + i + 1 == count;
+        print_process_node(child, &child_indent, last, false);
+    }
+}
+
+pub fn print_supervision_tree(tree: &SupervisionTree, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        print_json(tree);
+        return;
+    }
+
+    if tree.applications.is_empty() {
+        println!("No supervision trees own ETS tables.");
+    }
+    for application in &tree.applications {
+        print_process_node(application, "", true, true);
+        println!();
+    }
+
+    if !tree.unattributed.is_empty() {
+        let memory: u64 = tree.unattributed.iter().map(|t| t.memory_bytes).sum();
+        println!(
+            "Outside supervision trees: {} tables, {}",
+            tree.unattributed.len(),
+            format_memory(memory)
+        );
+        for table in &tree.unattributed {
+            println!(
+                "  • {} (owner {}, {} objects, {})",
+                table.name,
+                table.owner,
+                table.size,
+                format_memory(table.memory_bytes)
+            );
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct OwnerStateJson<'a> {
+    table: &'a str,
+    owner: &'a str,
+    kind: &'a str,
+    value: String,
+}
+
+pub fn print_owner_state(state: &OwnerState, format: OutputFormat) {
+    let value = render_entry(&state.value);
+    if format == OutputFormat::Json {
+        print_json(&OwnerStateJson {
+            table: &state.table_name,
+            owner: &state.owner,
+            kind: state.kind.name(),
+            value,
+        });
+        return;
+    }
+
+    println!("Table: {}", state.table_name);
+    println!("Owner: {}", state.owner);
+    let label = match state.kind {
+        OwnerStateKind::State => "State",
+        OwnerStateKind::Status => "Status",
+    };
+    println!("{}: {}", label, value);
+}
+
+#[derive(Tabled)]
+struct LintFindingRow {
+    #[tabled(rename = "Severity")]
+    severity: String,
+    #[tabled(rename = "Rule")]
+    rule: String,
+    #[tabled(rename = "Table")]
+    table: String,
+    #[tabled(rename = "Explanation")]
+    explanation: String,
+}
+
+pub fn print_lint_report(report: &LintReport, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        print_json(report);
+        return;
+    }
+
+    if report.findings.is_empty() {
+        println!("No findings.");
+    } else {
+        let rows: Vec<LintFindingRow> = report
+            .findings
+            .iter()
+            .map(|f| LintFindingRow {
+                severity: f.severity.to_string(),
+                rule: f.rule.to_string(),
+                table: f.table.clone().unwrap_or_else(|| "-".to_string()),
+                explanation: f.explanation.clone(),
+            })
+            .collect();
+        println!("{}", apply_table_style(rows));
+    }
+
+    println!(
+        "\nTotal: {} findings across {} tables ({} of {} allowed tables in use)",
+        report.findings.len(),
+        report.tables_checked,
+        report.ets_count,
+        report.ets_limit
+    );
+}
+
+pub fn format_eta(seconds: f64) -> String {
+    let seconds = seconds.round() as u64;
+    match seconds {
+        0..60 => format!("{}s", seconds),
+        60..3600 => format!("{}m {}s", seconds / 60, seconds % 60),
+        3600..86400 => format!("{}h {}m", seconds / 3600, (seconds % 3600) / 60),
+        _ => format!("{}d {}h", seconds / 86400, (seconds % 86400) / 3600),
+    }
+}
+
+fn format_series<T: ToString>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(" → ")
+}
+
+#[derive(Tabled)]
+struct GrowthRow {
+    #[tabled(rename = "Key")]
+    key: String,
+    #[tabled(rename = "Tables")]
+    tables: String,
+    #[tabled(rename = "Objects")]
+    objects: String,
+    #[tabled(rename = "Tables/min")]
+    tables_per_minute: String,
+    #[tabled(rename = "Objects/min")]
+    objects_per_minute: String,
+    #[tabled(rename = "Time to ets_limit")]
+    eta: String,
+    #[tabled(rename = "Growing")]
+    growing: String,
+}
+
+fn growth_rows(series: &[GrowthSeries], top: usize) -> Vec<GrowthRow> {
+    series
+        .iter()
+        .take(top)
+        .map(|s| {
+            let growing = match (s.count_growing, s.size_growing) {
+                (true, true) => highlight("tables, objects".to_string(), "1;31"),
+                (true, false) => highlight("tables".to_string(), "1;31"),
+                (false, true) => highlight("objects".to_string(), "1;31"),
+                (false, false) => String::new(),
+            };
+            GrowthRow {
+                key: s.key.clone(),
+                tables: format_series(&s.table_counts),
+                objects: format!(
+                    "{} → {}",
+                    s.object_counts.first().copied().unwrap_or(0),
+                    s.object_counts.last().copied().unwrap_or(0)
+                ),
+                tables_per_minute: format!("{:+.1}", s.tables_per_minute),
+                objects_per_minute: format!("{:+.1}", s.objects_per_minute),
+                eta: s
+                    .seconds_to_ets_limit
+                    .map(format_eta)
+                    .unwrap_or_else(|| "-".to_string()),
+                growing,
+            }
+        })
+        .collect()
+}
+
+pub fn print_leak_report(report: &LeakReport, top: usize, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        print_json(report);
+        return;
+    }
+
+    println!(
+        "Sampled {} times over {}: {} tables (ets_limit: {})\n",
+        report.table_counts.len(),
+        format_eta(report.elapsed_secs),
+        format_series(&report.table_counts),
+        report.ets_limit
+    );
+
+    println!("By owner:");
+    println!("{}", apply_table_style(growth_rows(&report.owners, top)));
+    println!("\nBy table name:");
+    println!("{}", apply_table_style(growth_rows(&report.names, top)));
+
+    let growing: Vec<&GrowthSeries> = report.growing_owners().collect();
+    if growing.is_empty() {
+        println!("\nNo owner's tables grew monotonically across the samples.");
+    } else {
+        println!(
+            "\n{} owners have table counts or sizes that grew monotonically across the samples:",
+            growing.len()
+        );
+        for owner in growing {
+            let eta = owner
+                .seconds_to_ets_limit
+                .map(|s| format!(", ets_limit in about {}", format_eta(s)))
+                .unwrap_or_default();
+            println!(
+                "  {}: {:+.1} tables/min, {:+.1} objects/min{}",
+                owner.key, owner.tables_per_minute, owner.objects_per_minute, eta
+            );
+        }
+    }
+}
+
+#[derive(Tabled)]
+struct AllocatorInstanceRow {
+    #[tabled(rename = "Instance")]
+    instance: u64,
+    #[tabled(rename = "MBC Carriers")]
+    mbcs_carriers: u64,
+    #[tabled(rename = "SBC Carriers")]
+    sbcs_carriers: u64,
+    #[tabled(rename = "Blocks")]
+    blocks: String,
+    #[tabled(rename = "Carriers Size")]
+    carriers: String,
+    #[tabled(rename = "Fragmentation")]
+    fragmentation: String,
+}
+
+pub fn print_ets_alloc_report(report: &EtsAllocReport, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        print_json(report);
+        return;
+    }
+
+    if report.enabled {
+        let rows: Vec<AllocatorInstanceRow> = report
+            .instances
+            .iter()
+            .map(|i| AllocatorInstanceRow {
+                instance: i.instance,
+                mbcs_carriers: i.mbcs_carriers,
+                sbcs_carriers: i.sbcs_carriers,
+                blocks: format_memory(i.blocks_bytes),
+                carriers: format_memory(i.carriers_bytes),
+                fragmentation: format_percentage(i.fragmentation * 100.0),
+            })
+            .collect();
+        println!("{}\n", apply_table_style(rows));
+    } else {
+        println!("ets_alloc is disabled on this node, ETS memory comes from sys_alloc.\n");
+    }
+
+    println!(
+        "Allocator: {} in blocks, {} in carriers, {} fragmentation",
+        format_memory(report.blocks_bytes),
+        format_memory(report.carriers_bytes),
+        format_percentage(report.fragmentation * 100.0)
+    );
+    println!(
+        "erlang:memory(ets): {}",
+        format_memory(report.erlang_memory_ets_bytes)
+    );
+    println!(
+        "Tables: {} reported by {} tables",
+        format_memory(report.tables_memory_bytes),
+        report.table_count
+    );
+    println!(
+        "\nNot attributed to any table: {} (erlang:memory(ets) minus table memory)",
+        format_memory_delta(report.unattributed_bytes)
+    );
+    if report.enabled {
+        println!(
+            "Held by the allocator beyond table memory: {} (carriers minus table memory)",
+            format_memory_delta(report.allocator_overhead_bytes)
+        );
+    }
+}
+
+pub fn print_table_details(details: &TableDetails, with_stats: bool, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        print_json(details);
+        return;
+    }
+
+    let info = &details.info;
+    println!("Table: {}", info.name);
+    println!("Type: {}", info.table_type);
+    println!("Protection: {}", info.protection);
+    println!("Owner: {}", info.owner);
+    println!("Key position: {}", info.keypos);
+    println!("Objects: {}", info.size);
+    println!("Memory: {}", format_memory(info.memory_bytes));
+
+    if !with_stats {
+        return;
+    }
+
+    let Some(stats) = &details.hash_stats else {
+        println!("\nBucket statistics are only available for hash-based tables.");
+        return;
+    };
+
+    println!("\nBuckets: {}", stats.buckets);
+    println!(
+        "Chain length: min {}, max {}, average {:.2}",
+        stats.min_chain_len, stats.max_chain_len, stats.avg_chain_len
+    );
+    println!(
+        "Standard deviation: {:.2} (expected {:.2})",
+        stats.std_dev, stats.expected_std_dev
+    );
+
+    if details.skewed {
+        let ratio = stats.deviation_ratio().unwrap_or_default();
+        println!(
+            "\n{}",
+            highlight(
+                format!(
+                    "Warning: chain lengths deviate {:.1} times more than expected, many keys hash to the same buckets",
+                    ratio
+                ),
+                "1;33"
+            )
+        );
+    }
+}
+
+pub fn print_table_schema(schema: &TableSchema, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        print_json(schema);
+        return;
+    }
+
+    println!(
+        "Table: {} ({} of {} objects sampled)",
+        schema.table, schema.sampled_objects, schema.total_objects
+    );
+    let Some(arity) = schema.arity() else {
+        println!("No objects to infer a schema from");
+        return;
+    };
+    if schema.object.tuple_arities.len() > 1 {
+        let arities: Vec<String> = schema
+            .object
+            .tuple_arities
+            .iter()
+            .map(|(arity, count)| format!("{} ({} objects)", arity, count))
+            .collect();
+        println!("Arity: {}", arities.join(", "));
+    } else {
+        println!("Arity: {}", arity);
+    }
+    println!();
+
+    for (i, shape) in schema.object.tuple_elements.iter().enumerate() {
+        print_shape(&format!("#{}", i + 1), shape, schema.sampled_objects, 0);
+    }
+}
+
+fn print_shape(label: &str, shape: &TermShape, parent_count: u64, depth: usize) {
+    let indent = "  ".repeat(depth);
+    let presence = if shape.observed < parent_count {
+        format!(
+            " (present in {:.0}%)",
+            shape.observed as f64 * 100.0 / parent_count as f64
+        )
+    } else {
+        String::new()
+    };
+    println!("{}{}: {}{}", indent, label, shape.summary(), presence);
+
+    let tuples = shape.count(TermKind::Tuple);
+    for (i, element) in shape.tuple_elements.iter().enumerate() {
+        print_shape(&format!("{}.{}", label, i + 1), element, tuples, depth + 1);
+    }
+    if let Some(elements) = shape.list_elements.as_deref()
+        && elements.observed > 0
+    {
+        print_shape(
+            &format!("{}[]", label),
+            elements,
+            elements.observed,
+            depth + 1,
+        );
+    }
+    let maps = shape.count(TermKind::Map);
+    for (key, value) in &shape.map_keys {
+        print_shape(key, value, maps, depth + 1);
+    }
+    if shape.map_keys_truncated {
+        println!("{}  (more keys not shown)", indent);
+    }
+}
+
+pub fn print_table_checksums(checksums: &TableChecksums, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        print_json(checksums);
+        return;
+    }
+
+    println!("Table: {}", checksums.table);
+    if !checksums.checksums.is_empty() {
+        let rows: Vec<ChecksumRow> = checksums
+            .checksums
+            .iter()
+            .map(|c| ChecksumRow {
+                node: c.node.clone(),
+                objects: c.objects,
+                checksum: c.checksum.clone(),
+            })
+            .collect();
+        println!("{}", apply_table_style(rows));
+    }
+
+    for failure in &checksums.failures {
+        eprintln!(
+            "Warning: no checksum from node {}: {}",
+            failure.node, failure.reason
+        );
+    }
+
+    if checksums.checksums.len() + checksums.failures.len() > 1 {
+        if checksums.all_match() {
+            println!("\nAll checksums match");
+        } else {
+            println!("\n{}", highlight("Checksums differ".to_string(), "1;31"));
+        }
+    }
 }
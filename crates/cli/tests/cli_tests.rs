@@ -14,7 +14,13 @@
 
 mod test_helpers;
 
+use erltf::{erl_atom, erl_int, erl_tuple};
+use ets_cli::cli::parse_interval;
+use ets_lib::{EtsTableInfo, Protection, Snapshot, TableType};
 use predicates::prelude::PredicateBooleanExt;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
 use test_helpers::{output_includes, run_fails, run_succeeds};
 
 #[test]
@@ -85,3 +91,753 @@ fn test_invalid_regex_pattern_fails() {
             .or(output_includes("pattern")),
     );
 }
+
+#[test]
+fn test_diff_requires_two_snapshots() {
+    run_fails(["diff", "old.snapshot"])
+        .stderr(output_includes("NEW_SNAPSHOT").or(output_includes("required")));
+}
+
+#[test]
+fn test_diff_missing_snapshot_file_fails() {
+    run_fails([
+        "diff",
+        "/nonexistent/old.snapshot",
+        "/nonexistent/new.snapshot",
+    ])
+    .stderr(output_includes("Error"));
+}
+
+#[test]
+fn test_tables_snapshot_requires_output() {
+    run_fails([
+        "tables",
+        "snapshot",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+    ])
+    .stderr(output_includes("--output").or(output_includes("required")));
+}
+
+#[test]
+fn test_tables_compare_requires_name() {
+    run_fails([
+        "tables",
+        "compare",
+        "--node",
+        "a@localhost",
+        "--node",
+        "b@localhost",
+        "--erlang-cookie",
+        "test",
+    ])
+    .stderr(output_includes("--name").or(output_includes("required")));
+}
+
+#[test]
+fn test_tables_compare_requires_two_nodes() {
+    run_fails([
+        "tables",
+        "compare",
+        "--node",
+        "a@localhost",
+        "--erlang-cookie",
+        "test",
+        "--name",
+        "ac_tab",
+    ])
+    .stderr(output_includes("exactly two"));
+}
+
+#[test]
+fn test_tables_checksum_help_shows_chunk_size_option() {
+    run_succeeds(["tables", "checksum", "--help"]).stdout(output_includes("--chunk-size"));
+}
+
+#[test]
+fn test_tables_checksum_requires_name() {
+    run_fails([
+        "tables",
+        "checksum",
+        "--node",
+        "a@localhost",
+        "--node",
+        "b@localhost",
+        "--erlang-cookie",
+        "test",
+    ])
+    .stderr(output_includes("--name").or(output_includes("required")));
+}
+
+#[test]
+fn test_tables_checksum_rejects_zero_chunk_size() {
+    run_fails([
+        "tables",
+        "checksum",
+        "--node",
+        "a@localhost",
+        "--erlang-cookie",
+        "test",
+        "--name",
+        "ac_tab",
+        "--chunk-size",
+        "0",
+    ])
+    .stderr(output_includes("--chunk-size"));
+}
+
+#[test]
+fn test_tables_checksum_help_shows_timeout_option() {
+    run_succeeds(["tables", "checksum", "--help"]).stdout(output_includes("--timeout"));
+}
+
+#[test]
+fn test_tables_checksum_rejects_zero_timeout() {
+    run_fails([
+        "tables",
+        "checksum",
+        "--node",
+        "a@localhost",
+        "--erlang-cookie",
+        "test",
+        "--name",
+        "ac_tab",
+        "--timeout",
+        "0",
+    ])
+    .stderr(output_includes("--timeout"));
+}
+
+#[test]
+fn test_tables_list_help_shows_compare_option() {
+    run_succeeds(["tables", "list", "--help"]).stdout(output_includes("--compare"));
+}
+
+#[test]
+fn test_tables_list_multiple_nodes_require_compare() {
+    run_fails([
+        "tables",
+        "list",
+        "--node",
+        "a@localhost",
+        "--node",
+        "b@localhost",
+        "--erlang-cookie",
+        "test",
+    ])
+    .stderr(output_includes("--compare"));
+}
+
+#[test]
+fn test_tables_list_cluster_conflicts_with_compare() {
+    run_fails([
+        "tables",
+        "list",
+        "--node",
+        "a@localhost",
+        "--erlang-cookie",
+        "test",
+        "--cluster",
+        "--compare",
+    ])
+    .stderr(output_includes("cannot be used with"));
+}
+
+#[test]
+fn test_tables_find_key_requires_key() {
+    run_fails([
+        "tables",
+        "find-key",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+    ])
+    .stderr(output_includes("--key").or(output_includes("required")));
+}
+
+#[test]
+fn test_tables_grep_requires_regex() {
+    run_fails([
+        "tables",
+        "grep",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+    ])
+    .stderr(output_includes("--regex").or(output_includes("required")));
+}
+
+#[test]
+fn test_tables_grep_rejects_invalid_regex() {
+    run_fails([
+        "tables",
+        "grep",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+        "--regex",
+        "(unclosed",
+    ])
+    .stderr(output_includes("Invalid regex pattern"));
+}
+
+#[test]
+fn test_tables_tree_help_shows_all_option() {
+    run_succeeds(["tables", "tree", "--help"])
+        .stdout(output_includes("--all"))
+        .stdout(output_includes("supervision trees"));
+}
+
+#[test]
+fn test_tables_tree_requires_node() {
+    run_fails(["tables", "tree", "--erlang-cookie", "test"])
+        .stderr(output_includes("--node").or(output_includes("required")));
+}
+
+#[test]
+fn test_tables_info_help_shows_stats_option() {
+    run_succeeds(["tables", "info", "--help"])
+        .stdout(output_includes("--stats"))
+        .stdout(output_includes("--name"));
+}
+
+#[test]
+fn test_tables_schema_help_shows_options() {
+    run_succeeds(["tables", "schema", "--help"])
+        .stdout(output_includes("--sample-size"))
+        .stdout(output_includes("--emit"));
+}
+
+#[test]
+fn test_tables_schema_rejects_all_with_sample_size() {
+    run_fails([
+        "tables",
+        "schema",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+        "--name",
+        "my_table",
+        "--all",
+        "--sample-size",
+        "10",
+    ])
+    .stderr(output_includes("cannot be used with"));
+}
+
+#[test]
+fn test_tables_schema_rejects_zero_sample_size() {
+    run_fails([
+        "tables",
+        "schema",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+        "--name",
+        "my_table",
+        "--sample-size",
+        "0",
+    ])
+    .stderr(output_includes("--sample-size"));
+}
+
+#[test]
+fn test_tables_owner_state_help_shows_options() {
+    run_succeeds(["tables", "owner-state", "--help"])
+        .stdout(output_includes("--timeout"))
+        .stdout(output_includes("--status"));
+}
+
+#[test]
+fn test_tables_owner_state_requires_name() {
+    run_fails([
+        "tables",
+        "owner-state",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+    ])
+    .stderr(output_includes("--name").or(output_includes("required")));
+}
+
+#[test]
+fn test_tables_owner_state_rejects_zero_timeout() {
+    run_fails([
+        "tables",
+        "owner-state",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+        "--name",
+        "my_table",
+        "--timeout",
+        "0",
+    ])
+    .stderr(output_includes("--timeout"));
+}
+
+#[test]
+fn test_tables_lint_help_shows_fail_on_option() {
+    run_succeeds(["tables", "lint", "--help"])
+        .stdout(output_includes("--fail-on"))
+        .stdout(output_includes("--large-table-size"));
+}
+
+#[test]
+fn test_tables_lint_rejects_unknown_severity() {
+    run_fails([
+        "tables",
+        "lint",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+        "--fail-on",
+        "fatal",
+    ])
+    .stderr(output_includes("invalid value"));
+}
+
+#[test]
+fn test_tables_leaks_help_shows_sampling_options() {
+    run_succeeds(["tables", "leaks", "--help"])
+        .stdout(output_includes("--interval"))
+        .stdout(output_includes("--samples"));
+}
+
+#[test]
+fn test_tables_leaks_requires_two_samples() {
+    run_fails([
+        "tables",
+        "leaks",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+        "--samples",
+        "1",
+    ])
+    .stderr(output_includes("--samples"));
+}
+
+#[test]
+fn test_tables_leaks_rejects_unknown_interval_unit() {
+    run_fails([
+        "tables",
+        "leaks",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+        "--interval",
+        "10w",
+    ])
+    .stderr(output_includes("unknown duration unit"));
+}
+
+#[test]
+fn test_parse_interval_units() {
+    assert_eq!(parse_interval("500ms"), Ok(Duration::from_millis(500)));
+    assert_eq!(parse_interval("10s"), Ok(Duration::from_secs(10)));
+    assert_eq!(parse_interval("10"), Ok(Duration::from_secs(10)));
+    assert_eq!(parse_interval("2m"), Ok(Duration::from_secs(120)));
+    assert_eq!(parse_interval("1h"), Ok(Duration::from_secs(3600)));
+    assert!(parse_interval("s").is_err());
+}
+
+#[test]
+fn test_parse_interval_rejects_overflowing_durations() {
+    assert!(parse_interval(&format!("{}m", u64::MAX / 60 + 1)).is_err());
+    assert!(parse_interval(&format!("{}h", u64::MAX / 3600 + 1)).is_err());
+    assert_eq!(
+        parse_interval(&format!("{}h", u64::MAX / 3600)),
+        Ok(Duration::from_secs(u64::MAX / 3600 * 3600))
+    );
+}
+
+#[test]
+fn test_memory_breakdown_estimate_compression_conflicts_with_group_by() {
+    run_fails([
+        "tables",
+        "memory_breakdown",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+        "--estimate-compression",
+        "--group-by",
+        "owner",
+    ])
+    .stderr(output_includes("cannot be used with"));
+}
+
+#[test]
+fn test_memory_breakdown_sample_size_requires_a_sampling_option() {
+    run_fails([
+        "tables",
+        "memory_breakdown",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+        "--sample-size",
+        "100",
+    ])
+    .stderr(output_includes("--estimate-compression"))
+    .stderr(output_includes("--off-heap"));
+}
+
+#[test]
+fn test_memory_breakdown_off_heap_conflicts_with_estimate_compression() {
+    run_fails([
+        "tables",
+        "memory_breakdown",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+        "--off-heap",
+        "--estimate-compression",
+    ])
+    .stderr(output_includes("cannot be used with"));
+}
+
+#[test]
+fn test_node_help_shows_ets_alloc() {
+    run_succeeds(["node", "--help"]).stdout(output_includes("ets-alloc"));
+}
+
+#[test]
+fn test_node_ets_alloc_requires_node() {
+    run_fails(["node", "ets-alloc", "--erlang-cookie", "test"])
+        .stderr(output_includes("--node").or(output_includes("required")));
+}
+
+#[test]
+fn test_persistent_terms_help_shows_subcommands() {
+    run_succeeds(["persistent-terms", "--help"])
+        .stdout(output_includes("list"))
+        .stdout(output_includes("get"));
+}
+
+#[test]
+fn test_persistent_terms_get_requires_key() {
+    run_fails([
+        "persistent-terms",
+        "get",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+    ])
+    .stderr(output_includes("--key").or(output_includes("required")));
+}
+
+#[test]
+fn test_dets_help_shows_subcommands() {
+    run_succeeds(["dets", "--help"])
+        .stdout(output_includes("list"))
+        .stdout(output_includes("dump"))
+        .stdout(output_includes("lookup"));
+}
+
+#[test]
+fn test_dets_lookup_requires_key() {
+    run_fails([
+        "dets",
+        "lookup",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+        "--name",
+        "my_table",
+    ])
+    .stderr(output_includes("--key").or(output_includes("required")));
+}
+
+#[test]
+fn test_mnesia_help_shows_subcommands() {
+    run_succeeds(["mnesia", "--help"])
+        .stdout(output_includes("list"))
+        .stdout(output_includes("dump"));
+}
+
+#[test]
+fn test_mnesia_dump_requires_name() {
+    run_fails([
+        "mnesia",
+        "dump",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+    ])
+    .stderr(output_includes("--name").or(output_includes("required")));
+}
+
+#[test]
+fn test_tables_memory_breakdown_rejects_unknown_grouping() {
+    run_fails([
+        "tables",
+        "memory_breakdown",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+        "--group-by",
+        "color",
+    ])
+    .stderr(output_includes("invalid value"));
+}
+
+#[test]
+fn test_tables_memory_breakdown_rejects_zero_sample_size() {
+    for flag in ["--off-heap", "--estimate-compression"] {
+        run_fails([
+            "tables",
+            "memory_breakdown",
+            "--node",
+            "test@localhost",
+            "--erlang-cookie",
+            "test",
+            flag,
+            "--sample-size",
+            "0",
+        ])
+        .stderr(output_includes("--sample-size"));
+    }
+}
+
+#[test]
+fn test_tables_memory_breakdown_top_requires_group_by() {
+    run_fails([
+        "tables",
+        "memory_breakdown",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+        "--top",
+        "5",
+    ])
+    .stderr(output_includes("--group-by"));
+}
+
+#[test]
+fn test_tables_memory_breakdown_regex_capture_requires_group_regex() {
+    run_fails([
+        "tables",
+        "memory_breakdown",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+        "--group-by",
+        "regex-capture",
+    ])
+    .stderr(output_includes("requires a group regex"));
+}
+
+#[test]
+fn test_tables_memory_breakdown_group_regex_requires_regex_capture() {
+    run_fails([
+        "tables",
+        "memory_breakdown",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+        "--group-by",
+        "owner",
+        "--group-regex",
+        "^(ra_[a-z]+)",
+    ])
+    .stderr(output_includes("only applies to regex-capture"));
+
+    run_fails([
+        "tables",
+        "memory_breakdown",
+        "--node",
+        "test@localhost",
+        "--erlang-cookie",
+        "test",
+        "--group-regex",
+        "^(ra_[a-z]+)",
+    ])
+    .stderr(output_includes("--group-by"));
+}
+
+fn write_test_snapshot(file_name: &str) -> PathBuf {
+    let table = EtsTableInfo {
+        name: "sessions".to_string(),
+        table_type: TableType::Set,
+        size: 1,
+        memory_bytes: 1024,
+        owner: "<0.0.0>".to_string(),
+        protection: Protection::Public,
+        keypos: 1,
+        application: None,
+        off_heap_bytes: None,
+    };
+    let snapshot = Snapshot {
+        node: "rabbit@localhost".to_string(),
+        taken_at: 1_700_000_000,
+        tables: vec![table],
+        contents: BTreeMap::from([(
+            "sessions".to_string(),
+            vec![erl_tuple!(erl_int!(1), erl_atom!("alice"))],
+        )]),
+    };
+
+    let path = std::env::temp_dir().join(format!("{}-{}", std::process::id(), file_name));
+    snapshot.write_to(&path).unwrap();
+    path
+}
+
+#[test]
+fn test_tables_list_reads_snapshot() {
+    let path = write_test_snapshot("list.snapshot");
+
+    run_succeeds([
+        "tables",
+        "list",
+        "--snapshot",
+        path.to_str().unwrap(),
+        "--format",
+        "json",
+    ])
+    .stdout(output_includes("\"sessions\""));
+
+    std::fs::remove_file(path).ok();
+}
+
+#[test]
+fn test_tables_dump_reads_snapshot() {
+    let path = write_test_snapshot("dump.snapshot");
+
+    run_succeeds([
+        "tables",
+        "dump",
+        "--snapshot",
+        path.to_str().unwrap(),
+        "--name",
+        "sessions",
+    ])
+    .stdout(output_includes("alice"))
+    .stdout(output_includes("Total: 1 entries"));
+
+    std::fs::remove_file(path).ok();
+}
+
+#[test]
+fn test_tables_info_reads_snapshot() {
+    let path = write_test_snapshot("info.snapshot");
+
+    run_succeeds([
+        "tables",
+        "info",
+        "--snapshot",
+        path.to_str().unwrap(),
+        "--name",
+        "sessions",
+        "--format",
+        "json",
+    ])
+    .stdout(output_includes("\"sessions\""));
+
+    run_fails([
+        "tables",
+        "info",
+        "--snapshot",
+        path.to_str().unwrap(),
+        "--name",
+        "sessions",
+        "--stats",
+    ])
+    .stderr(output_includes("require a running node"));
+
+    std::fs::remove_file(path).ok();
+}
+
+#[test]
+fn test_tables_memory_breakdown_reads_snapshot() {
+    let path = write_test_snapshot("memory.snapshot");
+
+    run_succeeds([
+        "tables",
+        "memory_breakdown",
+        "--snapshot",
+        path.to_str().unwrap(),
+        "--group-by",
+        "type",
+    ])
+    .stdout(output_includes("set"));
+
+    run_fails([
+        "tables",
+        "memory_breakdown",
+        "--snapshot",
+        path.to_str().unwrap(),
+        "--off-heap",
+    ])
+    .stderr(output_includes("require a running node"));
+
+    std::fs::remove_file(path).ok();
+}
+
+#[test]
+fn test_tables_dump_pages_through_snapshot() {
+    let path = write_test_snapshot("paged-dump.snapshot");
+
+    run_succeeds([
+        "tables",
+        "dump",
+        "--snapshot",
+        path.to_str().unwrap(),
+        "--name",
+        "sessions",
+        "--page-size",
+        "1",
+    ])
+    .stdout(output_includes("alice"))
+    .stdout(output_includes("Total: 1 entries"));
+
+    std::fs::remove_file(path).ok();
+}
+
+#[test]
+fn test_tables_dump_rejects_zero_page_size() {
+    run_fails([
+        "tables",
+        "dump",
+        "--snapshot",
+        "missing.snapshot",
+        "--name",
+        "sessions",
+        "--page-size",
+        "0",
+    ]);
+}
+
+#[test]
+fn test_tables_list_snapshot_conflicts_with_node() {
+    run_fails([
+        "tables",
+        "list",
+        "--snapshot",
+        "tables.snapshot",
+        "--node",
+        "test@localhost",
+    ])
+    .stderr(output_includes("cannot be used with"));
+}
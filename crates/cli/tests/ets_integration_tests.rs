@@ -14,68 +14,316 @@
 
 mod test_helpers;
 
+use erltf::{erl_atom, erl_int, erl_tuple};
+use ets_fake_node::{FakeNode, Fixture, FixtureTable};
+use ets_lib::TableType;
 use predicates::prelude::PredicateBooleanExt;
 use test_helpers::{get_test_cookie, get_test_node, output_includes, run_fails, run_succeeds};
 
+// Commands that only need plain RPCs run against a fake node. Commands
+// that evaluate code through erl_eval need a live node (TEST_NODE) and
+// are ignored by default.
+
+fn start_fake_node() -> FakeNode {
+    let registry = FixtureTable::new("rabbit_registry", TableType::Set).with_objects(vec![
+        erl_tuple!(
+            erl_atom!("exchange"),
+            erl_atom!("rabbit_exchange_type_direct")
+        ),
+        erl_tuple!(
+            erl_atom!("exchange"),
+            erl_atom!("rabbit_exchange_type_fanout")
+        ),
+    ]);
+    let ra_log = FixtureTable::new("ra_log_ets", TableType::OrderedSet)
+        .with_objects(vec![erl_tuple!(erl_int!(1), erl_atom!("entry"))]);
+
+    FakeNode::start(Fixture::new().with_table(registry).with_table(ra_log)).unwrap()
+}
+
+#[test]
+fn test_list_tables() {
+    let node = start_fake_node();
+
+    run_succeeds([
+        "tables",
+        "list",
+        "--node",
+        node.name(),
+        "--erlang-cookie",
+        node.cookie(),
+    ])
+    .stdout(output_includes("rabbit_registry"))
+    .stdout(output_includes("ra_log_ets"))
+    .stdout(output_includes("Total:"));
+}
+
+#[test]
+fn test_list_tables_with_pattern_filter() {
+    let node = start_fake_node();
+
+    run_succeeds([
+        "tables",
+        "list",
+        "--node",
+        node.name(),
+        "--erlang-cookie",
+        node.cookie(),
+        "--pattern",
+        "^ra_",
+    ])
+    .stdout(output_includes("ra_log_ets"))
+    .stdout(output_includes("rabbit_registry").not());
+}
+
+#[test]
+fn test_list_tables_with_wrong_cookie() {
+    let node = start_fake_node();
+
+    run_fails([
+        "tables",
+        "list",
+        "--node",
+        node.name(),
+        "--erlang-cookie",
+        "not-the-cookie",
+    ]);
+}
+
+#[test]
+fn test_table_info() {
+    let node = start_fake_node();
+
+    run_succeeds([
+        "tables",
+        "info",
+        "--node",
+        node.name(),
+        "--erlang-cookie",
+        node.cookie(),
+        "--name",
+        "ra_log_ets",
+    ])
+    .stdout(output_includes("ra_log_ets"))
+    .stdout(output_includes("ordered_set"));
+}
+
+#[test]
+fn test_dump_table() {
+    let node = start_fake_node();
+
+    run_succeeds([
+        "tables",
+        "dump",
+        "--node",
+        node.name(),
+        "--erlang-cookie",
+        node.cookie(),
+        "--name",
+        "rabbit_registry",
+    ])
+    .stdout(output_includes("Table: rabbit_registry"))
+    .stdout(output_includes("rabbit_exchange_type_fanout"));
+}
+
+#[test]
+fn test_dump_nonexistent_table() {
+    let node = start_fake_node();
+
+    run_fails([
+        "tables",
+        "dump",
+        "--node",
+        node.name(),
+        "--erlang-cookie",
+        node.cookie(),
+        "--name",
+        "nonexistent_table_12345",
+    ])
+    .stderr(output_includes("not found"));
+}
+
+#[test]
+fn test_memory_breakdown() {
+    let node = start_fake_node();
+
+    run_succeeds([
+        "tables",
+        "memory_breakdown",
+        "--node",
+        node.name(),
+        "--erlang-cookie",
+        node.cookie(),
+    ])
+    .stdout(output_includes("Total:"))
+    .stdout(output_includes("rabbit_registry"))
+    .stdout(output_includes("B"));
+}
+
+#[test]
+fn test_memory_breakdown_with_pattern_filter() {
+    let node = start_fake_node();
+
+    run_succeeds([
+        "tables",
+        "memory_breakdown",
+        "--node",
+        node.name(),
+        "--erlang-cookie",
+        node.cookie(),
+        "--pattern",
+        "rabbit_",
+    ])
+    .stdout(output_includes("rabbit_registry"))
+    .stdout(output_includes("ra_log_ets").not());
+}
+
+#[test]
+fn test_memory_breakdown_grouped_by_type() {
+    let node = start_fake_node();
+
+    run_succeeds([
+        "tables",
+        "memory_breakdown",
+        "--node",
+        node.name(),
+        "--erlang-cookie",
+        node.cookie(),
+        "--group-by",
+        "type",
+    ])
+    .stdout(output_includes("ordered_set"))
+    .stdout(output_includes("Total:"));
+}
+
 #[test]
 #[ignore]
-fn test_list_tables_on_live_node() {
+fn test_find_key_on_live_node() {
     let node = get_test_node();
     let cookie = get_test_cookie();
 
     run_succeeds([
         "tables",
-        "list",
+        "find-key",
         "--node",
         &node,
         "--erlang-cookie",
         &cookie,
+        "--key",
+        "{application_master, kernel}",
     ])
-    .stdout(output_includes("Total:"))
-    .stdout(output_includes("tables"));
+    .stdout(output_includes("Total:"));
 }
 
 #[test]
 #[ignore]
-fn test_dump_ac_table_on_live_node() {
+fn test_grep_on_live_node() {
     let node = get_test_node();
     let cookie = get_test_cookie();
 
     run_succeeds([
         "tables",
+        "grep",
+        "--node",
+        &node,
+        "--erlang-cookie",
+        &cookie,
+        "--regex",
+        "kernel",
+        "--pattern",
+        "^ac_tab$",
+    ])
+    .stdout(output_includes("ac_tab"))
+    .stdout(output_includes("Total:"));
+}
+
+#[test]
+#[ignore]
+fn test_list_persistent_terms_on_live_node() {
+    let node = get_test_node();
+    let cookie = get_test_cookie();
+
+    run_succeeds([
+        "persistent-terms",
+        "list",
+        "--node",
+        &node,
+        "--erlang-cookie",
+        &cookie,
+    ])
+    .stdout(output_includes("Total:"));
+}
+
+#[test]
+#[ignore]
+fn test_get_missing_persistent_term_on_live_node() {
+    let node = get_test_node();
+    let cookie = get_test_cookie();
+
+    run_fails([
+        "persistent-terms",
+        "get",
+        "--node",
+        &node,
+        "--erlang-cookie",
+        &cookie,
+        "--key",
+        "{ets_browser, no_such_key}",
+    ])
+    .stderr(output_includes("Persistent term not found"));
+}
+
+#[test]
+#[ignore]
+fn test_list_dets_tables_on_live_node() {
+    let node = get_test_node();
+    let cookie = get_test_cookie();
+
+    run_succeeds(["dets", "list", "--node", &node, "--erlang-cookie", &cookie])
+        .stdout(output_includes("DETS tables").or(output_includes("Total:")));
+}
+
+#[test]
+#[ignore]
+fn test_dump_nonexistent_dets_table() {
+    let node = get_test_node();
+    let cookie = get_test_cookie();
+
+    run_fails([
+        "dets",
         "dump",
         "--node",
         &node,
         "--erlang-cookie",
         &cookie,
         "--name",
-        "ac_tab",
+        "nonexistent_dets_table_xyz",
     ])
-    .stdout(output_includes("Table: ac_tab"));
+    .stderr(output_includes("Table not found"));
 }
 
 #[test]
 #[ignore]
-fn test_dump_nonexistent_table() {
+fn test_dump_mnesia_schema_on_live_node() {
     let node = get_test_node();
     let cookie = get_test_cookie();
 
-    run_fails([
-        "tables",
+    run_succeeds([
+        "mnesia",
         "dump",
         "--node",
         &node,
         "--erlang-cookie",
         &cookie,
         "--name",
-        "nonexistent_table_12345",
+        "schema",
     ])
-    .stderr(output_includes("not found").or(output_includes("Table not found")));
+    .stdout(output_includes("Table: schema"));
 }
 
 #[test]
 #[ignore]
-fn test_memory_breakdown_on_live_node() {
+fn test_memory_breakdown_grouped_by_application_on_live_node() {
     let node = get_test_node();
     let cookie = get_test_cookie();
 
@@ -86,38 +334,156 @@ fn test_memory_breakdown_on_live_node() {
         &node,
         "--erlang-cookie",
         &cookie,
+        "--group-by",
+        "application",
+    ])
+    .stdout(output_includes("kernel"))
+    .stdout(output_includes("groups"));
+}
+
+#[test]
+#[ignore]
+fn test_supervision_tree_on_live_node() {
+    let node = get_test_node();
+    let cookie = get_test_cookie();
+
+    run_succeeds([
+        "tables",
+        "tree",
+        "--node",
+        &node,
+        "--erlang-cookie",
+        &cookie,
+    ])
+    .stdout(output_includes("kernel"))
+    .stdout(output_includes("supervisor"));
+}
+
+#[test]
+#[ignore]
+fn test_table_info_with_stats_on_live_node() {
+    let node = get_test_node();
+    let cookie = get_test_cookie();
+
+    run_succeeds([
+        "tables",
+        "info",
+        "--node",
+        &node,
+        "--erlang-cookie",
+        &cookie,
+        "--name",
+        "global_names",
+        "--stats",
+    ])
+    .stdout(output_includes("Buckets:"))
+    .stdout(output_includes("Standard deviation:"));
+}
+
+#[test]
+#[ignore]
+fn test_table_schema_on_live_node() {
+    let node = get_test_node();
+    let cookie = get_test_cookie();
+
+    run_succeeds([
+        "tables",
+        "schema",
+        "--node",
+        &node,
+        "--erlang-cookie",
+        &cookie,
+        "--name",
+        "global_names",
+        "--emit",
+        "rust",
+    ])
+    .stdout(output_includes("pub struct GlobalNames("));
+}
+
+#[test]
+#[ignore]
+fn test_table_checksum_on_live_node() {
+    let node = get_test_node();
+    let cookie = get_test_cookie();
+
+    run_succeeds([
+        "tables",
+        "checksum",
+        "--node",
+        &node,
+        "--erlang-cookie",
+        &cookie,
+        "--name",
+        "ac_tab",
+    ])
+    .stdout(output_includes("Checksum"))
+    .stdout(output_includes(&node));
+}
+
+#[test]
+#[ignore]
+fn test_owner_state_on_live_node() {
+    let node = get_test_node();
+    let cookie = get_test_cookie();
+
+    run_succeeds([
+        "tables",
+        "owner-state",
+        "--node",
+        &node,
+        "--erlang-cookie",
+        &cookie,
+        "--name",
+        "global_names",
+    ])
+    .stdout(output_includes("Owner:"))
+    .stdout(output_includes("State:"));
+}
+
+#[test]
+#[ignore]
+fn test_lint_on_live_node() {
+    let node = get_test_node();
+    let cookie = get_test_cookie();
+
+    run_succeeds([
+        "tables",
+        "lint",
+        "--node",
+        &node,
+        "--erlang-cookie",
+        &cookie,
     ])
     .stdout(output_includes("Total:"))
-    .stdout(output_includes("memory"))
-    .stdout(
-        output_includes("KiB")
-            .or(output_includes("MiB"))
-            .or(output_includes("B")),
-    );
+    .stdout(output_includes("allowed tables in use"));
 }
 
 #[test]
 #[ignore]
-fn test_list_tables_with_pattern_filter() {
+fn test_leaks_on_live_node() {
     let node = get_test_node();
     let cookie = get_test_cookie();
 
     run_succeeds([
         "tables",
-        "list",
+        "leaks",
         "--node",
         &node,
         "--erlang-cookie",
         &cookie,
-        "--pattern",
-        "^ra_",
+        "--interval",
+        "200ms",
+        "--samples",
+        "3",
     ])
-    .stdout(output_includes("Total:"));
+    .stdout(output_includes("By owner:"))
+    .stdout(output_includes("By table name:"));
 }
 
 #[test]
 #[ignore]
-fn test_memory_breakdown_with_pattern_filter() {
+fn test_estimate_compression_on_live_node() {
     let node = get_test_node();
     let cookie = get_test_cookie();
 
@@ -128,9 +494,45 @@ fn test_memory_breakdown_with_pattern_filter() {
         &node,
         "--erlang-cookie",
         &cookie,
-        "--pattern",
-        "rabbit_",
+        "--estimate-compression",
     ])
-    .stdout(output_includes("Total:"))
-    .stdout(output_includes("memory"));
+    .stdout(output_includes("Est. Savings"))
+    .stdout(output_includes("compressed option"));
+}
+
+#[test]
+#[ignore]
+fn test_memory_breakdown_with_off_heap_on_live_node() {
+    let node = get_test_node();
+    let cookie = get_test_cookie();
+
+    run_succeeds([
+        "tables",
+        "memory_breakdown",
+        "--node",
+        &node,
+        "--erlang-cookie",
+        &cookie,
+        "--off-heap",
+    ])
+    .stdout(output_includes("Off-heap"))
+    .stdout(output_includes("off-heap binaries"));
+}
+
+#[test]
+#[ignore]
+fn test_ets_alloc_report_on_live_node() {
+    let node = get_test_node();
+    let cookie = get_test_cookie();
+
+    run_succeeds([
+        "node",
+        "ets-alloc",
+        "--node",
+        &node,
+        "--erlang-cookie",
+        &cookie,
+    ])
+    .stdout(output_includes("erlang:memory(ets)"))
+    .stdout(output_includes("Not attributed to any table"));
 }
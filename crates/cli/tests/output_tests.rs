@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use ets_cli::output::format_memory;
+use ets_cli::output::{format_count_delta, format_eta, format_memory, format_memory_delta};
 
 #[test]
 fn test_format_memory_zero() {
@@ -39,3 +39,24 @@ fn test_format_memory_mebibytes() {
 fn test_format_memory_gibibytes() {
     assert_eq!(format_memory(1024 * 1024 * 1024), "1 GiB");
 }
+
+#[test]
+fn test_format_memory_delta_signs() {
+    assert_eq!(format_memory_delta(2048), "+2 KiB");
+    assert_eq!(format_memory_delta(-1024), "-1 KiB");
+    assert_eq!(format_memory_delta(0), "+0 B");
+}
+
+#[test]
+fn test_format_count_delta_signs() {
+    assert_eq!(format_count_delta(5), "+5");
+    assert_eq!(format_count_delta(-3), "-3");
+}
+
+#[test]
+fn test_format_eta_picks_units() {
+    assert_eq!(format_eta(42.4), "42s");
+    assert_eq!(format_eta(125.0), "2m 5s");
+    assert_eq!(format_eta(7260.0), "2h 1m");
+    assert_eq!(format_eta(90000.0), "1d 1h");
+}
@@ -0,0 +1,166 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Fixture;
+use crate::rex;
+use edp_client::control::ControlMessage;
+use edp_client::digest;
+use edp_client::flags::DistributionFlags;
+use edp_client::handshake::{Challenge, ChallengeAck, ChallengeReply, SendName};
+use erltf::OwnedTerm;
+use std::io;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+const PASS_THROUGH: u8 = 112;
+
+pub(crate) struct LocalNode {
+    pub name: String,
+    pub creation: u32,
+    pub fixture: Fixture,
+}
+
+pub(crate) async fn accept_loop(listener: std::net::TcpListener, node: Arc<LocalNode>) {
+    let listener = match TcpListener::from_std(listener) {
+        Ok(listener) => listener,
+        Err(_) => return,
+    };
+
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(serve_connection(stream, node.clone()));
+    }
+}
+
+async fn serve_connection(mut stream: TcpStream, node: Arc<LocalNode>) -> io::Result<()> {
+    accept_handshake(&mut stream, &node).await?;
+
+    loop {
+        let len = stream.read_u32().await? as usize;
+        if len == 0 {
+            // a tick
+            continue;
+        }
+
+        let mut frame = vec![0u8; len];
+        stream.read_exact(&mut frame).await?;
+
+        let (control, payload) = decode_frame(&frame)?;
+        if let ControlMessage::RegSend { to_name, .. } = control
+            && to_name.is_atom_with_name("rex")
+            && let Some((reply_to, result)) = payload.and_then(|p| rex::handle_call(&node, &p))
+        {
+            let control = ControlMessage::Send {
+                cookie: OwnedTerm::atom(""),
+                to_pid: reply_to,
+            };
+            let reply = OwnedTerm::tuple(vec![OwnedTerm::atom("rex"), result]);
+            send(&mut stream, control, reply).await?;
+        }
+    }
+}
+
+async fn accept_handshake(stream: &mut TcpStream, node: &LocalNode) -> io::Result<()> {
+    let send_name = read_handshake_message(stream).await?;
+    let new_format = match send_name.first() {
+        Some(b'N') => {
+            SendName::decode(&send_name).map_err(protocol_error)?;
+            true
+        }
+        Some(b'n') => false,
+        _ => return Err(protocol_error("expected a send_name message")),
+    };
+
+    write_handshake_message(stream, b"sok").await?;
+
+    if !new_format {
+        // the complement carries the high flag bits and the peer's creation
+        let complement = read_handshake_message(stream).await?;
+        if complement.first() != Some(&b'c') {
+            return Err(protocol_error("expected a complement message"));
+        }
+    }
+
+    // Without the atom cache and fragments, peers send every message in
+    // the pass-through format.
+    let flags = DistributionFlags::DEFAULT
+        .difference(DistributionFlags::DIST_HDR_ATOM_CACHE | DistributionFlags::FRAGMENTS);
+    let challenge = digest::generate_challenge();
+    let message = Challenge::new(flags, challenge, node.creation, &node.name)
+        .encode()
+        .map_err(protocol_error)?;
+    stream.write_all(&message).await?;
+
+    let reply = read_handshake_message(stream).await?;
+    let reply = ChallengeReply::decode(&reply).map_err(protocol_error)?;
+    if !reply.verify(challenge, &node.fixture.cookie) {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "peer used a different cookie",
+        ));
+    }
+
+    let ack = ChallengeAck::new(reply.challenge, &node.fixture.cookie).encode();
+    stream.write_all(&ack).await
+}
+
+async fn read_handshake_message(stream: &mut TcpStream) -> io::Result<Vec<u8>> {
+    let len = stream.read_u16().await? as usize;
+    let mut message = vec![0u8; len];
+    stream.read_exact(&mut message).await?;
+    Ok(message)
+}
+
+async fn write_handshake_message(stream: &mut TcpStream, message: &[u8]) -> io::Result<()> {
+    stream.write_u16(message.len() as u16).await?;
+    stream.write_all(message).await
+}
+
+fn decode_frame(frame: &[u8]) -> io::Result<(ControlMessage, Option<OwnedTerm>)> {
+    let Some((&PASS_THROUGH, rest)) = frame.split_first() else {
+        return Err(protocol_error("expected a pass-through message"));
+    };
+
+    let (control, rest) = erltf::decoder::decode_with_trailing(rest).map_err(protocol_error)?;
+    let payload = if rest.is_empty() {
+        None
+    } else {
+        Some(erltf::decode(rest).map_err(protocol_error)?)
+    };
+
+    Ok((
+        ControlMessage::from_term(&control).map_err(protocol_error)?,
+        payload,
+    ))
+}
+
+async fn send(
+    stream: &mut TcpStream,
+    control: ControlMessage,
+    message: OwnedTerm,
+) -> io::Result<()> {
+    let control = erltf::encode(&control.to_term()).map_err(protocol_error)?;
+    let message = erltf::encode(&message).map_err(protocol_error)?;
+
+    let mut frame = Vec::with_capacity(5 + control.len() + message.len());
+    frame.extend_from_slice(&((1 + control.len() + message.len()) as u32).to_be_bytes());
+    frame.push(PASS_THROUGH);
+    frame.extend_from_slice(&control);
+    frame.extend_from_slice(&message);
+    stream.write_all(&frame).await
+}
+
+fn protocol_error(e: impl ToString) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+}
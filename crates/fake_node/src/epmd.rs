@@ -0,0 +1,312 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::runtime;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+pub use edp_client::epmd_client::EPMD_PORT;
+
+const ALIVE2_REQ: u8 = 120;
+const ALIVE2_X_RESP: u8 = 118;
+const PORT2_REQ: u8 = 122;
+const PORT2_RESP: u8 = 119;
+const NAMES_REQ: u8 = 110;
+
+const NODE_TYPE_NORMAL: u8 = 77;
+const PROTOCOL_TCP: u8 = 0;
+const HIGHEST_VERSION: u16 = 6;
+const LOWEST_VERSION: u16 = 5;
+
+const REGISTRATION_TIMEOUT: Duration = Duration::from_secs(5);
+
+// The stand-in registers itself under this name, with its process id as
+// the extra data, so that other processes can tell it from a real EPMD.
+const STAND_IN_NAME: &str = "ets_fake_epmd";
+
+#[derive(Debug, Clone)]
+struct Registration {
+    port: u16,
+    node_type: u8,
+    protocol: u8,
+    highest_version: u16,
+    lowest_version: u16,
+    extra: Vec<u8>,
+}
+
+type Registry = Arc<Mutex<HashMap<String, Registration>>>;
+
+/// Starts the stand-in unless a real EPMD already listens on the EPMD port.
+///
+/// The port is fixed because `edp_client` always looks EPMD up on 4369,
+/// so the stand-in stops with the process that bound it and cannot be
+/// shared: this fails if another process owns it, and under `cargo nextest`,
+/// which runs every test in a process of its own, a real EPMD is required.
+pub(crate) fn ensure_running() -> io::Result<()> {
+    static STARTED: Mutex<bool> = Mutex::new(false);
+
+    let mut started = STARTED.lock().unwrap_or_else(|e| e.into_inner());
+    if *started {
+        return Ok(());
+    }
+
+    if std::env::var_os("NEXTEST").is_some()
+        && TcpStream::connect(("localhost", EPMD_PORT)).is_err()
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "cargo nextest runs every test in a process of its own, so fake nodes \
+             need a real EPMD: start one with `epmd -daemon`",
+        ));
+    }
+
+    match std::net::TcpListener::bind(("127.0.0.1", EPMD_PORT)) {
+        Ok(listener) => {
+            listener.set_nonblocking(true)?;
+            runtime().spawn(serve(listener));
+        }
+        Err(e) if e.kind() == io::ErrorKind::AddrInUse => {
+            if let Some(owner) = stand_in_owner()? {
+                return Err(io::Error::new(
+                    io::ErrorKind::AddrInUse,
+                    format!(
+                        "the EPMD on port {} is the fake node stand-in of process {}, \
+                         which stops when that process exits; start a real EPMD with \
+                         `epmd -daemon` to run fake nodes from several processes",
+                        EPMD_PORT, owner
+                    ),
+                ));
+            }
+        }
+        Err(e) => return Err(e),
+    }
+
+    *started = true;
+    Ok(())
+}
+
+/// Registers `alive_name` with the EPMD on this host and returns the
+/// registration connection, which must stay open for as long as the node
+/// runs, along with the node's creation.
+pub(crate) fn register(alive_name: &str, port: u16) -> io::Result<(TcpStream, u32)> {
+    let mut stream = TcpStream::connect(("localhost", EPMD_PORT))?;
+    stream.set_read_timeout(Some(REGISTRATION_TIMEOUT))?;
+
+    let name = alive_name.as_bytes();
+    let mut request = Vec::with_capacity(15 + name.len());
+    request.extend_from_slice(&((13 + name.len()) as u16).to_be_bytes());
+    request.push(ALIVE2_REQ);
+    request.extend_from_slice(&port.to_be_bytes());
+    request.push(NODE_TYPE_NORMAL);
+    request.push(PROTOCOL_TCP);
+    request.extend_from_slice(&HIGHEST_VERSION.to_be_bytes());
+    request.extend_from_slice(&LOWEST_VERSION.to_be_bytes());
+    request.extend_from_slice(&(name.len() as u16).to_be_bytes());
+    request.extend_from_slice(name);
+    request.extend_from_slice(&0u16.to_be_bytes());
+    stream.write_all(&request)?;
+
+    let mut header = [0u8; 2];
+    stream.read_exact(&mut header)?;
+    let creation = match header {
+        [ALIVE2_X_RESP, 0] => {
+            let mut creation = [0u8; 4];
+            stream.read_exact(&mut creation)?;
+            u32::from_be_bytes(creation)
+        }
+        // ALIVE2_RESP, sent by EPMDs that predate 32-bit creations
+        [121, 0] => {
+            let mut creation = [0u8; 2];
+            stream.read_exact(&mut creation)?;
+            u16::from_be_bytes(creation) as u32
+        }
+        [_, result] => {
+            return Err(io::Error::other(format!(
+                "EPMD refused to register {} (result {})",
+                alive_name, result
+            )));
+        }
+    };
+
+    stream.set_read_timeout(None)?;
+    Ok((stream, creation))
+}
+
+/// Returns the process id of the stand-in that listens on the EPMD port,
+/// or `None` when it is a real EPMD.
+fn stand_in_owner() -> io::Result<Option<u32>> {
+    let mut stream = TcpStream::connect(("localhost", EPMD_PORT))?;
+    stream.set_read_timeout(Some(REGISTRATION_TIMEOUT))?;
+
+    let name = STAND_IN_NAME.as_bytes();
+    let mut request = ((1 + name.len()) as u16).to_be_bytes().to_vec();
+    request.push(PORT2_REQ);
+    request.extend_from_slice(name);
+    stream.write_all(&request)?;
+
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response)?;
+
+    // PORT2_RESP, result, and the node's details ending with the extra data
+    if !matches!(response.as_slice(), [PORT2_RESP, 0, ..]) {
+        return Ok(None);
+    }
+    let owner = response
+        .len()
+        .checked_sub(4)
+        .and_then(|at| response.get(at..))
+        .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]));
+    Ok(owner)
+}
+
+async fn serve(listener: std::net::TcpListener) {
+    let listener = match TcpListener::from_std(listener) {
+        Ok(listener) => listener,
+        Err(_) => return,
+    };
+    let registry = Registry::default();
+    registry.lock().unwrap_or_else(|e| e.into_inner()).insert(
+        STAND_IN_NAME.to_string(),
+        Registration {
+            port: EPMD_PORT,
+            node_type: NODE_TYPE_NORMAL,
+            protocol: PROTOCOL_TCP,
+            highest_version: HIGHEST_VERSION,
+            lowest_version: LOWEST_VERSION,
+            extra: std::process::id().to_be_bytes().to_vec(),
+        },
+    );
+    let creations = Arc::new(AtomicU32::new(1));
+
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(handle_request(stream, registry.clone(), creations.clone()));
+    }
+}
+
+async fn handle_request(
+    mut stream: tokio::net::TcpStream,
+    registry: Registry,
+    creations: Arc<AtomicU32>,
+) -> io::Result<()> {
+    let len = stream.read_u16().await? as usize;
+    let mut request = vec![0u8; len];
+    stream.read_exact(&mut request).await?;
+
+    match request.split_first() {
+        Some((&ALIVE2_REQ, body)) => {
+            let (name, registration) = parse_alive2(body)?;
+            let accepted = match registry
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .entry(name.clone())
+            {
+                Entry::Vacant(entry) => {
+                    entry.insert(registration);
+                    true
+                }
+                Entry::Occupied(_) => false,
+            };
+
+            let creation = creations.fetch_add(1, Ordering::Relaxed);
+            let mut response = vec![ALIVE2_X_RESP, if accepted { 0 } else { 1 }];
+            response.extend_from_slice(&creation.to_be_bytes());
+            stream.write_all(&response).await?;
+
+            if accepted {
+                // the node stays registered until it closes the connection
+                let mut buf = [0u8; 64];
+                while matches!(stream.read(&mut buf).await, Ok(n) if n > 0) {}
+                registry
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .remove(&name);
+            }
+        }
+        Some((&PORT2_REQ, name)) => {
+            let name = String::from_utf8_lossy(name).into_owned();
+            let found = registry
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .get(&name)
+                .cloned();
+
+            let response = match found {
+                Some(r) => {
+                    let mut response = vec![PORT2_RESP, 0];
+                    response.extend_from_slice(&r.port.to_be_bytes());
+                    response.push(r.node_type);
+                    response.push(r.protocol);
+                    response.extend_from_slice(&r.highest_version.to_be_bytes());
+                    response.extend_from_slice(&r.lowest_version.to_be_bytes());
+                    response.extend_from_slice(&(name.len() as u16).to_be_bytes());
+                    response.extend_from_slice(name.as_bytes());
+                    response.extend_from_slice(&(r.extra.len() as u16).to_be_bytes());
+                    response.extend_from_slice(&r.extra);
+                    response
+                }
+                None => vec![PORT2_RESP, 1],
+            };
+            stream.write_all(&response).await?;
+        }
+        Some((&NAMES_REQ, _)) => {
+            let mut response = (EPMD_PORT as u32).to_be_bytes().to_vec();
+            let registry = registry.lock().unwrap_or_else(|e| e.into_inner()).clone();
+            for (name, r) in registry {
+                response
+                    .extend_from_slice(format!("name {} at port {}\n", name, r.port).as_bytes());
+            }
+            stream.write_all(&response).await?;
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn parse_alive2(body: &[u8]) -> io::Result<(String, Registration)> {
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "malformed ALIVE2_REQ");
+
+    let u16_at = |at: usize| -> io::Result<u16> {
+        body.get(at..at + 2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]))
+            .ok_or_else(invalid)
+    };
+
+    let name_len = u16_at(8)? as usize;
+    let name = body.get(10..10 + name_len).ok_or_else(invalid)?;
+    let extra_len = u16_at(10 + name_len)? as usize;
+    let extra_start = 12 + name_len;
+    let extra = body
+        .get(extra_start..extra_start + extra_len)
+        .ok_or_else(invalid)?;
+
+    Ok((
+        String::from_utf8_lossy(name).into_owned(),
+        Registration {
+            port: u16_at(0)?,
+            node_type: body[2],
+            protocol: body[3],
+            highest_version: u16_at(4)?,
+            lowest_version: u16_at(6)?,
+            extra: extra.to_vec(),
+        },
+    ))
+}
@@ -0,0 +1,208 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod distribution;
+mod epmd;
+mod rex;
+
+use erltf::OwnedTerm;
+use ets_lib::{Protection, TableType};
+use std::io;
+use std::net::TcpStream;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, OnceLock};
+use tokio::runtime::Runtime;
+use tokio::task::JoinHandle;
+
+pub use epmd::EPMD_PORT;
+
+pub const DEFAULT_COOKIE: &str = "ets-fake-node";
+
+const WORD_SIZE: u64 = 8;
+
+static NODE_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+#[derive(Debug, Clone)]
+pub struct FixtureTable {
+    pub name: String,
+    pub table_type: TableType,
+    pub protection: Protection,
+    pub keypos: u64,
+    pub objects: Vec<OwnedTerm>,
+}
+
+impl FixtureTable {
+    pub fn new(name: &str, table_type: TableType) -> Self {
+        Self {
+            name: name.to_string(),
+            table_type,
+            protection: Protection::Protected,
+            keypos: 1,
+            objects: Vec::new(),
+        }
+    }
+
+    pub fn with_protection(mut self, protection: Protection) -> Self {
+        self.protection = protection;
+        self
+    }
+
+    pub fn with_keypos(mut self, keypos: u64) -> Self {
+        self.keypos = keypos;
+        self
+    }
+
+    pub fn with_objects(mut self, objects: Vec<OwnedTerm>) -> Self {
+        self.objects = objects;
+        self
+    }
+
+    /// The key of `object`, or `None` if it is not a tuple long enough
+    /// to have one at `keypos`.
+    pub(crate) fn key_of<'a>(&self, object: &'a OwnedTerm) -> Option<&'a OwnedTerm> {
+        object.as_tuple()?.get(self.keypos.checked_sub(1)? as usize)
+    }
+
+    /// Memory in words, roughly what `ets:info/2` reports for a table
+    /// holding the same objects.
+    pub(crate) fn memory_words(&self) -> u64 {
+        const EMPTY_TABLE_WORDS: u64 = 305;
+
+        let object_words: u64 = self
+            .objects
+            .iter()
+            .map(|object| {
+                let bytes = erltf::encode(object).map(|b| b.len()).unwrap_or(0) as u64;
+                bytes.div_ceil(WORD_SIZE)
+            })
+            .sum();
+        EMPTY_TABLE_WORDS + object_words
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Fixture {
+    pub cookie: String,
+    pub tables: Vec<FixtureTable>,
+}
+
+impl Default for Fixture {
+    fn default() -> Self {
+        Self {
+            cookie: DEFAULT_COOKIE.to_string(),
+            tables: Vec::new(),
+        }
+    }
+}
+
+impl Fixture {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_cookie(mut self, cookie: &str) -> Self {
+        self.cookie = cookie.to_string();
+        self
+    }
+
+    pub fn with_table(mut self, table: FixtureTable) -> Self {
+        self.tables.push(table);
+        self
+    }
+
+    pub(crate) fn table(&self, name: &str) -> Option<&FixtureTable> {
+        self.tables.iter().find(|t| t.name == name)
+    }
+}
+
+/// A running fake node. It unregisters from EPMD and stops accepting
+/// connections when dropped.
+pub struct FakeNode {
+    name: String,
+    cookie: String,
+    port: u16,
+    accept_task: JoinHandle<()>,
+    // EPMD forgets a node once its registration connection closes
+    _registration: TcpStream,
+}
+
+impl FakeNode {
+    /// Starts a node named `ets_fake_<pid>_<n>@localhost` serving `fixture`.
+    /// Works from both synchronous tests and async ones, since the node runs
+    /// on a runtime of its own.
+    pub fn start(fixture: Fixture) -> io::Result<Self> {
+        epmd::ensure_running()?;
+
+        let alive_name = format!(
+            "ets_fake_{}_{}",
+            std::process::id(),
+            NODE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        );
+        let name = format!("{}@localhost", alive_name);
+
+        let listener = std::net::TcpListener::bind(("127.0.0.1", 0))?;
+        listener.set_nonblocking(true)?;
+        let port = listener.local_addr()?.port();
+
+        let (registration, creation) = epmd::register(&alive_name, port)?;
+
+        let cookie = fixture.cookie.clone();
+        let node = Arc::new(distribution::LocalNode {
+            name: name.clone(),
+            creation,
+            fixture,
+        });
+        let accept_task = runtime().spawn(distribution::accept_loop(listener, node));
+
+        Ok(Self {
+            name,
+            cookie,
+            port,
+            accept_task,
+            _registration: registration,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn cookie(&self) -> &str {
+        &self.cookie
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+impl Drop for FakeNode {
+    fn drop(&mut self) {
+        self.accept_task.abort();
+    }
+}
+
+/// Fake nodes and the EPMD stand-in share one runtime that lives as long
+/// as the test process.
+pub(crate) fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .thread_name("ets-fake-node")
+            .enable_all()
+            .build()
+            .expect("failed to build the fake node runtime")
+    })
+}
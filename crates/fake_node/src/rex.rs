@@ -0,0 +1,157 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::distribution::LocalNode;
+use crate::{FixtureTable, WORD_SIZE};
+use erltf::OwnedTerm;
+use erltf::types::{Atom, ExternalPid};
+use ets_lib::Protection;
+
+/// Pids of table owners start here, after the pids of the processes
+/// every node starts with.
+const FIRST_OWNER_PID: u32 = 80;
+
+/// Takes a `{ReplyTo, {call, M, F, Args, GroupLeader}}` request and returns
+/// the pid to reply to along with the result of the call.
+pub(crate) fn handle_call(node: &LocalNode, request: &OwnedTerm) -> Option<(OwnedTerm, OwnedTerm)> {
+    let [reply_to, call] = request.as_tuple()? else {
+        return None;
+    };
+    let [tag, module, function, args, _group_leader] = call.as_tuple()? else {
+        return None;
+    };
+    if !tag.is_atom_with_name("call") {
+        return None;
+    }
+
+    let args = match args {
+        OwnedTerm::List(args) => args.as_slice(),
+        OwnedTerm::Nil => &[],
+        _ => return None,
+    };
+    let result = apply(node, module.atom_name()?, function.atom_name()?, args);
+
+    Some((reply_to.clone(), result))
+}
+
+fn apply(node: &LocalNode, module: &str, function: &str, args: &[OwnedTerm]) -> OwnedTerm {
+    let table = |tab: &OwnedTerm| tab.atom_name().and_then(|name| node.fixture.table(name));
+    // Like a real node, other processes cannot read private tables.
+    let readable = |tab: &OwnedTerm| table(tab).filter(|t| t.protection != Protection::Private);
+
+    match (module, function, args) {
+        ("ets", "all", []) => OwnedTerm::list(
+            node.fixture
+                .tables
+                .iter()
+                .map(|t| OwnedTerm::atom(&t.name))
+                .collect(),
+        ),
+        ("ets", "info", [tab]) => match table(tab) {
+            Some(t) => OwnedTerm::list(table_info(node, t)),
+            None => OwnedTerm::atom("undefined"),
+        },
+        ("ets", "info", [tab, item]) => table(tab)
+            .and_then(|t| {
+                table_info(node, t)
+                    .into_iter()
+                    .find_map(|pair| match pair.as_tuple() {
+                        Some([key, value]) if key == item => Some(value.clone()),
+                        _ => None,
+                    })
+            })
+            .unwrap_or_else(|| OwnedTerm::atom("undefined")),
+        ("ets", "tab2list", [tab]) => match readable(tab) {
+            Some(t) => OwnedTerm::list(t.objects.clone()),
+            None => badrpc("badarg", module, function, args),
+        },
+        ("ets", "lookup", [tab, key]) => match readable(tab) {
+            Some(t) => OwnedTerm::list(
+                t.objects
+                    .iter()
+                    .filter(|o| t.key_of(o) == Some(key))
+                    .cloned()
+                    .collect(),
+            ),
+            None => badrpc("badarg", module, function, args),
+        },
+        ("ets", "member", [tab, key]) => match readable(tab) {
+            Some(t) => OwnedTerm::boolean(t.objects.iter().any(|o| t.key_of(o) == Some(key))),
+            None => badrpc("badarg", module, function, args),
+        },
+        ("erlang", "system_info", [item]) if item.is_atom_with_name("wordsize") => {
+            OwnedTerm::integer(WORD_SIZE as i64)
+        }
+        ("erlang", "system_info", _) => badrpc("badarg", module, function, args),
+        ("erlang", "node", []) => OwnedTerm::atom(&node.name),
+        ("erlang", "nodes", []) => OwnedTerm::nil(),
+        _ => badrpc("undef", module, function, args),
+    }
+}
+
+fn table_info(node: &LocalNode, table: &FixtureTable) -> Vec<OwnedTerm> {
+    let pair = |key: &str, value: OwnedTerm| OwnedTerm::tuple(vec![OwnedTerm::atom(key), value]);
+    let name = OwnedTerm::atom(&table.name);
+
+    vec![
+        pair("id", name.clone()),
+        pair("decentralized_counters", OwnedTerm::boolean(false)),
+        pair("read_concurrency", OwnedTerm::boolean(false)),
+        pair("write_concurrency", OwnedTerm::boolean(false)),
+        pair("compressed", OwnedTerm::boolean(false)),
+        pair("memory", OwnedTerm::integer(table.memory_words() as i64)),
+        pair("owner", owner_pid(node, table)),
+        pair("heir", OwnedTerm::atom("none")),
+        pair("name", name),
+        pair("size", OwnedTerm::integer(table.objects.len() as i64)),
+        pair("node", OwnedTerm::atom(&node.name)),
+        pair("named_table", OwnedTerm::boolean(true)),
+        pair("type", OwnedTerm::atom(table.table_type.to_string())),
+        pair("keypos", OwnedTerm::integer(table.keypos as i64)),
+        pair("protection", OwnedTerm::atom(table.protection.to_string())),
+    ]
+}
+
+fn owner_pid(node: &LocalNode, table: &FixtureTable) -> OwnedTerm {
+    let index = node
+        .fixture
+        .tables
+        .iter()
+        .position(|t| t.name == table.name)
+        .unwrap_or_default() as u32;
+
+    OwnedTerm::Pid(ExternalPid::new(
+        Atom::new(&node.name),
+        FIRST_OWNER_PID + index,
+        0,
+        node.creation,
+    ))
+}
+
+/// The reply `rpc:call/4` gives when the called function raises
+/// `error:Reason`.
+fn badrpc(reason: &str, module: &str, function: &str, args: &[OwnedTerm]) -> OwnedTerm {
+    let frame = OwnedTerm::tuple(vec![
+        OwnedTerm::atom(module),
+        OwnedTerm::atom(function),
+        OwnedTerm::list(args.to_vec()),
+        OwnedTerm::nil(),
+    ]);
+    let exit = OwnedTerm::tuple(vec![
+        OwnedTerm::atom("EXIT"),
+        OwnedTerm::tuple(vec![OwnedTerm::atom(reason), OwnedTerm::list(vec![frame])]),
+    ]);
+
+    OwnedTerm::tuple(vec![OwnedTerm::atom("badrpc"), exit])
+}
@@ -0,0 +1,170 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use erltf::{OwnedTerm, erl_atom, erl_int, erl_tuple};
+use ets_fake_node::{FakeNode, Fixture, FixtureTable};
+use ets_lib::{Error, EtsSource, LiveNode, Protection, TableType};
+use regex::Regex;
+
+fn start_node() -> FakeNode {
+    let sessions = FixtureTable::new("sessions", TableType::Bag)
+        .with_protection(Protection::Public)
+        .with_keypos(2)
+        .with_objects(vec![
+            erl_tuple!(erl_atom!("session"), erl_int!(1), erl_atom!("alice")),
+            erl_tuple!(erl_atom!("session"), erl_int!(2), erl_atom!("bob")),
+            erl_tuple!(erl_atom!("session"), erl_int!(1), erl_atom!("carol")),
+        ]);
+    let empty = FixtureTable::new("empty_registry", TableType::OrderedSet);
+
+    FakeNode::start(
+        Fixture::new()
+            .with_cookie("fixture-cookie")
+            .with_table(sessions)
+            .with_table(empty),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_list_tables_returns_fixture_tables() {
+    let node = start_node();
+
+    let tables = ets_lib::list_tables(node.name(), node.cookie())
+        .await
+        .unwrap();
+
+    assert_eq!(tables.len(), 2);
+    assert_eq!(tables[0].name, "empty_registry");
+    assert_eq!(tables[0].table_type, TableType::OrderedSet);
+    assert_eq!(tables[0].size, 0);
+
+    let sessions = &tables[1];
+    assert_eq!(sessions.name, "sessions");
+    assert_eq!(sessions.table_type, TableType::Bag);
+    assert_eq!(sessions.protection, Protection::Public);
+    assert_eq!(sessions.keypos, 2);
+    assert_eq!(sessions.size, 3);
+    assert!(sessions.memory_bytes > tables[0].memory_bytes);
+    assert!(sessions.owner.starts_with('<'));
+}
+
+#[tokio::test]
+async fn test_dump_table_returns_fixture_objects() {
+    let node = start_node();
+
+    let objects = ets_lib::dump_table(node.name(), node.cookie(), "sessions")
+        .await
+        .unwrap();
+
+    assert_eq!(objects.len(), 3);
+    assert_eq!(
+        objects[1],
+        erl_tuple!(erl_atom!("session"), erl_int!(2), erl_atom!("bob"))
+    );
+}
+
+#[tokio::test]
+async fn test_dump_of_a_missing_table_fails() {
+    let node = start_node();
+
+    let result = ets_lib::dump_table(node.name(), node.cookie(), "missing").await;
+
+    assert!(matches!(result, Err(Error::TableNotFound(_))));
+}
+
+#[tokio::test]
+async fn test_private_tables_cannot_be_read() {
+    let secrets = FixtureTable::new("secrets", TableType::Set)
+        .with_protection(Protection::Private)
+        .with_objects(vec![erl_tuple!(erl_int!(1), erl_atom!("hidden"))]);
+    let node = FakeNode::start(Fixture::new().with_table(secrets)).unwrap();
+
+    let dump = ets_lib::dump_table(node.name(), node.cookie(), "secrets").await;
+    let lookup = ets_lib::lookup_table(node.name(), node.cookie(), "secrets", &erl_int!(1)).await;
+
+    assert!(matches!(dump, Err(Error::BadRpc(_))));
+    assert!(matches!(lookup, Err(Error::BadRpc(_))));
+}
+
+#[tokio::test]
+async fn test_lookup_uses_the_key_position() {
+    let node = start_node();
+    let source = LiveNode::new(node.name(), node.cookie());
+
+    let objects = source.lookup_table("sessions", &erl_int!(1)).await.unwrap();
+    let none: Vec<OwnedTerm> = source.lookup_table("sessions", &erl_int!(3)).await.unwrap();
+
+    assert_eq!(objects.len(), 2);
+    assert!(none.is_empty());
+}
+
+#[tokio::test]
+async fn test_table_details_without_stats_need_no_erl_eval() {
+    let node = start_node();
+
+    let details = ets_lib::get_table_details(node.name(), node.cookie(), "sessions", false)
+        .await
+        .unwrap();
+    let missing = ets_lib::get_table_details(node.name(), node.cookie(), "missing", false).await;
+
+    assert_eq!(details.info.keypos, 2);
+    assert!(details.hash_stats.is_none());
+    assert!(matches!(missing, Err(Error::TableNotFound(_))));
+}
+
+#[tokio::test]
+async fn test_calls_that_need_erl_eval_fail_cleanly() {
+    let node = start_node();
+
+    let result = ets_lib::page_table(node.name(), node.cookie(), "sessions", None, 10).await;
+
+    assert!(matches!(result, Err(Error::BadRpc(_))));
+}
+
+#[tokio::test]
+async fn test_wrong_cookie_is_rejected() {
+    let node = start_node();
+
+    let result = ets_lib::list_tables(node.name(), "not-the-cookie").await;
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_nodes_get_distinct_names() {
+    let first = start_node();
+    let second = start_node();
+
+    assert_ne!(first.name(), second.name());
+    assert_ne!(first.port(), second.port());
+}
+
+#[tokio::test]
+async fn test_grep_reports_tables_it_could_not_finish() {
+    let node = start_node();
+    let regex = Regex::new("alice").unwrap();
+
+    // the fake node cannot run the paging code, so no page can be read
+    let result = ets_lib::grep_tables(node.name(), node.cookie(), &regex, None, None)
+        .await
+        .unwrap();
+
+    assert!(result.matches.is_empty());
+    assert_eq!(result.tables_searched, 2);
+    assert_eq!(result.stopped_early.len(), 2);
+    assert_eq!(result.stopped_early[1].table_name, "sessions");
+    assert_eq!(result.stopped_early[1].entries_searched, 0);
+    assert!(result.stopped_early[1].error.is_some());
+}
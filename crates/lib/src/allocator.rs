@@ -0,0 +1,214 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::{Error, Result};
+use crate::ets::{create_connected_node, fetch_remote_tables};
+use crate::eval::eval;
+use erltf::OwnedTerm;
+use serde::Serialize;
+
+/// Summarizes `erlang:system_info({allocator, ets_alloc})` per instance as
+/// `{Instance, {BlocksBytes, CarriersBytes, Carriers}}` triples for the
+/// multiblock, singleblock and pool carriers. Handles both the per-type
+/// `blocks` lists of OTP 23+ and the older `blocks_size` entries.
+const ETS_ALLOC_SOURCE: &str = r#"
+Get = fun(Key, Props) ->
+          case lists:keyfind(Key, 1, Props) of
+              false -> 0;
+              T -> element(2, T)
+          end
+      end,
+Blocks = fun(Props) ->
+             case lists:keyfind(blocks, 1, Props) of
+                 {blocks, Types} when is_list(Types) -> lists:sum([Get(size, P) || {_, P} <- Types]);
+                 _ -> Get(blocks_size, Props)
+             end
+         end,
+Section = fun(Key, Props) ->
+              case lists:keyfind(Key, 1, Props) of
+                  {Key, S} when is_list(S) -> {Blocks(S), Get(carriers_size, S), Get(carriers, S)};
+                  _ -> {0, 0, 0}
+              end
+          end,
+Instances = case erlang:system_info({allocator, ets_alloc}) of
+                L when is_list(L) ->
+                    [{N, Section(mbcs, P), Section(sbcs, P), Section(mbcs_pool, P)}
+                     || {instance, N, P} <- L];
+                _ -> []
+            end,
+{erlang:memory(ets), Instances}.
+"#;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CarrierUsage {
+    pub blocks_bytes: u64,
+    pub carriers_bytes: u64,
+    pub carriers: u64,
+}
+
+impl CarrierUsage {
+    fn add(&mut self, other: &CarrierUsage) {
+        self.blocks_bytes += other.blocks_bytes;
+        self.carriers_bytes += other.carriers_bytes;
+        self.carriers += other.carriers;
+    }
+}
+
+pub fn fragmentation_ratio(blocks_bytes: u64, carriers_bytes: u64) -> f64 {
+    if carriers_bytes == 0 {
+        0.0
+    } else {
+        1.0 - (blocks_bytes as f64 / carriers_bytes as f64).min(1.0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AllocatorInstance {
+    pub instance: u64,
+    pub blocks_bytes: u64,
+    pub carriers_bytes: u64,
+    pub mbcs_carriers: u64,
+    pub sbcs_carriers: u64,
+    pub fragmentation: f64,
+}
+
+impl AllocatorInstance {
+    /// Pool carriers are counted as multiblock carriers.
+    pub fn new(
+        instance: u64,
+        mbcs: &CarrierUsage,
+        sbcs: &CarrierUsage,
+        pool: &CarrierUsage,
+    ) -> Self {
+        let mut total = CarrierUsage::default();
+        total.add(mbcs);
+        total.add(sbcs);
+        total.add(pool);
+
+        Self {
+            instance,
+            blocks_bytes: total.blocks_bytes,
+            carriers_bytes: total.carriers_bytes,
+            mbcs_carriers: mbcs.carriers + pool.carriers,
+            sbcs_carriers: sbcs.carriers,
+            fragmentation: fragmentation_ratio(total.blocks_bytes, total.carriers_bytes),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EtsAllocReport {
+    /// `false` when the node runs with `+MEe false` or reports no instances.
+    pub enabled: bool,
+    pub instances: Vec<AllocatorInstance>,
+    pub blocks_bytes: u64,
+    pub carriers_bytes: u64,
+    pub fragmentation: f64,
+    pub erlang_memory_ets_bytes: u64,
+    pub tables_memory_bytes: u64,
+    pub table_count: usize,
+    /// ETS memory reported by the runtime but not by any table.
+    pub unattributed_bytes: i64,
+    /// Carrier memory the allocator holds beyond what tables report.
+    pub allocator_overhead_bytes: i64,
+}
+
+impl EtsAllocReport {
+    pub fn new(
+        instances: Vec<AllocatorInstance>,
+        erlang_memory_ets_bytes: u64,
+        tables_memory_bytes: u64,
+        table_count: usize,
+    ) -> Self {
+        let blocks_bytes: u64 = instances.iter().map(|i| i.blocks_bytes).sum();
+        let carriers_bytes: u64 = instances.iter().map(|i| i.carriers_bytes).sum();
+
+        Self {
+            enabled: !instances.is_empty(),
+            fragmentation: fragmentation_ratio(blocks_bytes, carriers_bytes),
+            unattributed_bytes: erlang_memory_ets_bytes as i64 - tables_memory_bytes as i64,
+            allocator_overhead_bytes: carriers_bytes as i64 - tables_memory_bytes as i64,
+            instances,
+            blocks_bytes,
+            carriers_bytes,
+            erlang_memory_ets_bytes,
+            tables_memory_bytes,
+            table_count,
+        }
+    }
+}
+
+fn as_u64(term: &OwnedTerm) -> Result<u64> {
+    match term {
+        OwnedTerm::Integer(n) if *n >= 0 => Ok(*n as u64),
+        other => Err(Error::UnexpectedResponse(format!(
+            "Expected non-negative integer in allocator info, got: {}",
+            other
+        ))),
+    }
+}
+
+fn parse_usage(term: &OwnedTerm) -> Result<CarrierUsage> {
+    match term.as_tuple() {
+        Some([blocks_bytes, carriers_bytes, carriers]) => Ok(CarrierUsage {
+            blocks_bytes: as_u64(blocks_bytes)?,
+            carriers_bytes: as_u64(carriers_bytes)?,
+            carriers: as_u64(carriers)?,
+        }),
+        _ => Err(Error::UnexpectedResponse(format!(
+            "Expected a {{Blocks, CarriersSize, Carriers}} tuple, got: {}",
+            term
+        ))),
+    }
+}
+
+fn parse_instance(term: &OwnedTerm) -> Result<AllocatorInstance> {
+    match term.as_tuple() {
+        Some([instance, mbcs, sbcs, pool]) => Ok(AllocatorInstance::new(
+            as_u64(instance)?,
+            &parse_usage(mbcs)?,
+            &parse_usage(sbcs)?,
+            &parse_usage(pool)?,
+        )),
+        _ => Err(Error::UnexpectedResponse(format!(
+            "Expected an {{Instance, Mbcs, Sbcs, Pool}} tuple, got: {}",
+            term
+        ))),
+    }
+}
+
+pub async fn ets_alloc_report(remote_node: &str, cookie: &str) -> Result<EtsAllocReport> {
+    let node = create_connected_node(remote_node, cookie).await?;
+
+    let tables = fetch_remote_tables(&node, remote_node).await?;
+    let tables_memory_bytes = tables.iter().map(|t| t.info.memory_bytes).sum();
+
+    let reply = eval(&node, remote_node, ETS_ALLOC_SOURCE, vec![]).await?;
+    let [memory_ets, instances]: [OwnedTerm; 2] =
+        reply.try_into_tuple()?.try_into().map_err(|_| {
+            Error::UnexpectedResponse("Expected an {EtsMemory, Instances} tuple".to_string())
+        })?;
+    let instances = instances
+        .try_into_list()?
+        .iter()
+        .map(parse_instance)
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(EtsAllocReport::new(
+        instances,
+        as_u64(&memory_ets)?,
+        tables_memory_bytes,
+        tables.len(),
+    ))
+}
@@ -0,0 +1,335 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::Result;
+use crate::{
+    ClusterTables, ComparisonMethod, CompressionEstimate, DetsTableInfo, EtsAllocReport,
+    EtsTableInfo, GrepResult, InventoryComparison, KeySearchResult, LeakReport, LintReport,
+    LintThresholds, MnesiaTableDump, MnesiaTableInfo, ObjectLayout, OwnerState, OwnerStateKind,
+    PersistentTerms, Snapshot, SupervisionTree, TableChecksums, TableComparison, TableDetails,
+    TablePage, TableSchema,
+};
+use erltf::OwnedTerm;
+use regex::Regex;
+use serde::de::DeserializeOwned;
+use std::time::Duration;
+use tokio::runtime::{Builder, Runtime};
+
+/// Must not be used from within an async context.
+pub struct Client {
+    runtime: Runtime,
+    node: String,
+    cookie: String,
+}
+
+impl Client {
+    pub fn new(node: &str, cookie: &str) -> Result<Self> {
+        let runtime = Builder::new_current_thread().enable_all().build()?;
+
+        Ok(Self {
+            runtime,
+            node: node.to_string(),
+            cookie: cookie.to_string(),
+        })
+    }
+
+    /// Uses the cookie found by [`crate::get_erlang_cookie`].
+    pub fn with_default_cookie(node: &str) -> Result<Self> {
+        let cookie = crate::get_erlang_cookie(None, None)?;
+        Self::new(node, &cookie)
+    }
+
+    pub fn node(&self) -> &str {
+        &self.node
+    }
+
+    pub fn list_tables(&self) -> Result<Vec<EtsTableInfo>> {
+        self.runtime
+            .block_on(crate::list_tables(&self.node, &self.cookie))
+    }
+
+    pub fn list_tables_with_applications(&self) -> Result<Vec<EtsTableInfo>> {
+        self.runtime.block_on(crate::list_tables_with_applications(
+            &self.node,
+            &self.cookie,
+        ))
+    }
+
+    pub fn list_tables_with_off_heap(&self, sample_size: usize) -> Result<Vec<EtsTableInfo>> {
+        self.runtime.block_on(crate::list_tables_with_off_heap(
+            &self.node,
+            &self.cookie,
+            sample_size,
+        ))
+    }
+
+    pub fn list_cluster_tables(&self) -> Result<ClusterTables> {
+        self.runtime
+            .block_on(crate::list_cluster_tables(&self.node, &self.cookie))
+    }
+
+    pub fn get_table_details(&self, table_name: &str, with_stats: bool) -> Result<TableDetails> {
+        self.runtime.block_on(crate::get_table_details(
+            &self.node,
+            &self.cookie,
+            table_name,
+            with_stats,
+        ))
+    }
+
+    pub fn dump_table(&self, table_name: &str) -> Result<Vec<OwnedTerm>> {
+        self.runtime
+            .block_on(crate::dump_table(&self.node, &self.cookie, table_name))
+    }
+
+    pub fn page_table(
+        &self,
+        table_name: &str,
+        cursor: Option<OwnedTerm>,
+        limit: usize,
+    ) -> Result<TablePage> {
+        self.runtime.block_on(crate::page_table(
+            &self.node,
+            &self.cookie,
+            table_name,
+            cursor,
+            limit,
+        ))
+    }
+
+    pub fn lookup_table(&self, table_name: &str, key: &OwnedTerm) -> Result<Vec<OwnedTerm>> {
+        self.runtime.block_on(crate::lookup_table(
+            &self.node,
+            &self.cookie,
+            table_name,
+            key,
+        ))
+    }
+
+    pub fn dump_table_as<T: DeserializeOwned>(
+        &self,
+        table_name: &str,
+        layout: &ObjectLayout,
+    ) -> Result<Vec<Result<T>>> {
+        self.runtime.block_on(crate::dump_table_as(
+            &self.node,
+            &self.cookie,
+            table_name,
+            layout,
+        ))
+    }
+
+    pub fn lookup_as<T: DeserializeOwned>(
+        &self,
+        table_name: &str,
+        key: &OwnedTerm,
+        layout: &ObjectLayout,
+    ) -> Result<Vec<Result<T>>> {
+        self.runtime.block_on(crate::lookup_as(
+            &self.node,
+            &self.cookie,
+            table_name,
+            key,
+            layout,
+        ))
+    }
+
+    pub fn infer_table_schema(
+        &self,
+        table_name: &str,
+        sample_size: Option<usize>,
+    ) -> Result<TableSchema> {
+        self.runtime.block_on(crate::infer_table_schema(
+            &self.node,
+            &self.cookie,
+            table_name,
+            sample_size,
+        ))
+    }
+
+    pub fn find_key(&self, key: &str, pattern: Option<&Regex>) -> Result<KeySearchResult> {
+        self.runtime
+            .block_on(crate::find_key(&self.node, &self.cookie, key, pattern))
+    }
+
+    pub fn grep_tables(
+        &self,
+        regex: &Regex,
+        table_pattern: Option<&Regex>,
+        max_matches: Option<usize>,
+    ) -> Result<GrepResult> {
+        self.runtime.block_on(crate::grep_tables(
+            &self.node,
+            &self.cookie,
+            regex,
+            table_pattern,
+            max_matches,
+        ))
+    }
+
+    /// Compares a table on this client's node (the left side) and `right_node`.
+    pub fn compare_table(
+        &self,
+        right_node: &str,
+        table_name: &str,
+        method: ComparisonMethod,
+    ) -> Result<TableComparison> {
+        self.runtime.block_on(crate::compare_table(
+            &self.node,
+            right_node,
+            &self.cookie,
+            table_name,
+            method,
+        ))
+    }
+
+    /// Compares table inventories of `remote_nodes`, which share this client's cookie.
+    pub fn compare_inventories(
+        &self,
+        remote_nodes: &[&str],
+        pattern: Option<&Regex>,
+    ) -> Result<InventoryComparison> {
+        self.runtime.block_on(crate::compare_inventories(
+            remote_nodes,
+            &self.cookie,
+            pattern,
+        ))
+    }
+
+    /// Checksums a table on `remote_nodes`, which share this client's cookie.
+    pub fn checksum_table(
+        &self,
+        remote_nodes: &[&str],
+        table_name: &str,
+        chunk_size: usize,
+        timeout_ms: u64,
+    ) -> Result<TableChecksums> {
+        self.runtime.block_on(crate::checksum_table(
+            remote_nodes,
+            &self.cookie,
+            table_name,
+            chunk_size,
+            timeout_ms,
+        ))
+    }
+
+    pub fn take_snapshot(&self, include_contents: bool) -> Result<Snapshot> {
+        self.runtime.block_on(crate::take_snapshot(
+            &self.node,
+            &self.cookie,
+            include_contents,
+        ))
+    }
+
+    pub fn supervision_tree(&self) -> Result<SupervisionTree> {
+        self.runtime
+            .block_on(crate::supervision_tree(&self.node, &self.cookie))
+    }
+
+    pub fn get_owner_state(
+        &self,
+        table_name: &str,
+        kind: OwnerStateKind,
+        timeout_ms: u64,
+    ) -> Result<OwnerState> {
+        self.runtime.block_on(crate::get_owner_state(
+            &self.node,
+            &self.cookie,
+            table_name,
+            kind,
+            timeout_ms,
+        ))
+    }
+
+    pub fn lint_node(&self, thresholds: &LintThresholds) -> Result<LintReport> {
+        self.runtime
+            .block_on(crate::lint_node(&self.node, &self.cookie, thresholds))
+    }
+
+    pub fn sample_tables(&self, interval: Duration, samples: usize) -> Result<LeakReport> {
+        self.runtime.block_on(crate::sample_tables(
+            &self.node,
+            &self.cookie,
+            interval,
+            samples,
+        ))
+    }
+
+    pub fn estimate_compression(
+        &self,
+        pattern: Option<&Regex>,
+        sample_size: usize,
+    ) -> Result<CompressionEstimate> {
+        self.runtime.block_on(crate::estimate_compression(
+            &self.node,
+            &self.cookie,
+            pattern,
+            sample_size,
+        ))
+    }
+
+    pub fn ets_alloc_report(&self) -> Result<EtsAllocReport> {
+        self.runtime
+            .block_on(crate::ets_alloc_report(&self.node, &self.cookie))
+    }
+
+    pub fn list_dets_tables(&self) -> Result<Vec<DetsTableInfo>> {
+        self.runtime
+            .block_on(crate::list_dets_tables(&self.node, &self.cookie))
+    }
+
+    pub fn dump_dets_table(&self, table_name: &str, chunk_size: usize) -> Result<Vec<OwnedTerm>> {
+        self.runtime.block_on(crate::dump_dets_table(
+            &self.node,
+            &self.cookie,
+            table_name,
+            chunk_size,
+        ))
+    }
+
+    pub fn lookup_dets_table(&self, table_name: &str, key: &str) -> Result<Vec<OwnedTerm>> {
+        self.runtime.block_on(crate::lookup_dets_table(
+            &self.node,
+            &self.cookie,
+            table_name,
+            key,
+        ))
+    }
+
+    pub fn list_mnesia_tables(&self) -> Result<Vec<MnesiaTableInfo>> {
+        self.runtime
+            .block_on(crate::list_mnesia_tables(&self.node, &self.cookie))
+    }
+
+    pub fn dump_mnesia_table(&self, table_name: &str) -> Result<MnesiaTableDump> {
+        self.runtime.block_on(crate::dump_mnesia_table(
+            &self.node,
+            &self.cookie,
+            table_name,
+        ))
+    }
+
+    pub fn list_persistent_terms(&self, pattern: Option<&Regex>) -> Result<PersistentTerms> {
+        self.runtime.block_on(crate::list_persistent_terms(
+            &self.node,
+            &self.cookie,
+            pattern,
+        ))
+    }
+
+    pub fn get_persistent_term(&self, key: &str) -> Result<OwnedTerm> {
+        self.runtime
+            .block_on(crate::get_persistent_term(&self.node, &self.cookie, key))
+    }
+}
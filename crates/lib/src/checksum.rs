@@ -0,0 +1,167 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::cluster::NodeFailure;
+use crate::errors::{Error, Result};
+use crate::ets::create_node_connected_to_all;
+use crate::eval::eval_with_timeout;
+use edp_node::Node;
+use erltf::{OwnedTerm, erl_atom, erl_int};
+use serde::Serialize;
+use std::time::Duration;
+
+pub const DEFAULT_CHECKSUM_CHUNK_SIZE: usize = 1000;
+
+pub const DEFAULT_CHECKSUM_TIMEOUT_MS: u64 = 60_000;
+
+// Object hashes are summed modulo 2^32 and XOR-ed, so the result does not
+// depend on traversal order.
+const CHECKSUM_SOURCE: &str = r#"
+case ets:info(Tab, size) of
+    undefined -> undefined;
+    _ ->
+        Fold = fun F('$end_of_table', Acc) -> Acc;
+                   F({Objects, Cont}, Acc0) ->
+                       Acc = lists:foldl(
+                               fun(O, {N, Sum, Xor}) ->
+                                   H = erlang:phash2(O, 4294967296),
+                                   {N + 1, (Sum + H) band 16#FFFFFFFF, Xor bxor H}
+                               end, Acc0, Objects),
+                       F(ets:select(Cont), Acc)
+               end,
+        ets:safe_fixtable(Tab, true),
+        try
+            Fold(ets:select(Tab, [{'_', [], ['$_']}], ChunkSize), {0, 0, 0})
+        after
+            ets:safe_fixtable(Tab, false)
+        end
+end.
+"#;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NodeChecksum {
+    pub node: String,
+    pub objects: u64,
+    /// 16 hex digits: the sum of the object hashes followed by their XOR.
+    pub checksum: String,
+}
+
+impl NodeChecksum {
+    pub fn new(node: &str, objects: u64, sum: u32, xor: u32) -> Self {
+        Self {
+            node: node.to_string(),
+            objects,
+            checksum: format!("{:08x}{:08x}", sum, xor),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TableChecksums {
+    pub table: String,
+    pub checksums: Vec<NodeChecksum>,
+    pub failures: Vec<NodeFailure>,
+}
+
+impl TableChecksums {
+    pub fn all_match(&self) -> bool {
+        self.failures.is_empty()
+            && self.checksums.windows(2).all(|pair| {
+                pair[0].checksum == pair[1].checksum && pair[0].objects == pair[1].objects
+            })
+    }
+}
+
+fn parse_checksum(remote_node: &str, table_name: &str, reply: OwnedTerm) -> Result<NodeChecksum> {
+    if reply.is_undefined() {
+        return Err(Error::TableNotFound(format!(
+            "{} on {}",
+            table_name, remote_node
+        )));
+    }
+
+    match reply.try_into_tuple()?.as_slice() {
+        [
+            OwnedTerm::Integer(objects),
+            OwnedTerm::Integer(sum),
+            OwnedTerm::Integer(xor),
+        ] => Ok(NodeChecksum::new(
+            remote_node,
+            (*objects).max(0) as u64,
+            *sum as u32,
+            *xor as u32,
+        )),
+        other => Err(Error::UnexpectedResponse(format!(
+            "Expected a {{Objects, Sum, Xor}} tuple, got: {:?}",
+            other
+        ))),
+    }
+}
+
+async fn remote_checksum(
+    node: &Node,
+    remote_node: &str,
+    table_name: &str,
+    chunk_size: usize,
+    timeout_ms: u64,
+) -> Result<NodeChecksum> {
+    let reply = eval_with_timeout(
+        node,
+        remote_node,
+        CHECKSUM_SOURCE,
+        vec![
+            ("Tab", erl_atom!(table_name)),
+            ("ChunkSize", erl_int!(chunk_size as i64)),
+        ],
+        Duration::from_millis(timeout_ms),
+    )
+    .await?;
+
+    parse_checksum(remote_node, table_name, reply)
+}
+
+/// A node where the table is missing or unreadable, or that does not
+/// finish within `timeout_ms`, is reported as a failure.
+pub async fn checksum_table(
+    remote_nodes: &[&str],
+    cookie: &str,
+    table_name: &str,
+    chunk_size: usize,
+    timeout_ms: u64,
+) -> Result<TableChecksums> {
+    if chunk_size == 0 {
+        return Err(Error::InvalidArgument(
+            "the chunk size must be positive".to_string(),
+        ));
+    }
+    let node = create_node_connected_to_all(remote_nodes, cookie).await?;
+
+    let mut checksums = Vec::with_capacity(remote_nodes.len());
+    let mut failures = Vec::new();
+    for remote_node in remote_nodes {
+        match remote_checksum(&node, remote_node, table_name, chunk_size, timeout_ms).await {
+            Ok(checksum) => checksums.push(checksum),
+            Err(e) => failures.push(NodeFailure {
+                node: remote_node.to_string(),
+                reason: e.to_string(),
+            }),
+        }
+    }
+
+    Ok(TableChecksums {
+        table: table_name.to_string(),
+        checksums,
+        failures,
+    })
+}
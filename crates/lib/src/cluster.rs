@@ -0,0 +1,153 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::{Error, Result};
+use crate::ets::{EtsTableInfo, check_badrpc, create_connected_node, parse_table_info};
+use crate::eval::{bindings_term, eval_result_value, parse_exprs};
+use edp_node::Node;
+use erltf::{OwnedTerm, erl_atom, erl_int};
+use serde::Serialize;
+
+/// How long the gateway waits for each cluster member, in milliseconds.
+pub const MEMBER_TIMEOUT_MS: i64 = 5_000;
+
+const MEMBER_TABLES_SOURCE: &str = r#"
+Infos = lists:filtermap(
+    fun(T) ->
+        case ets:info(T) of
+            undefined -> false;
+            Info -> {true, Info}
+        end
+    end, ets:all()),
+{erlang:system_info(wordsize), Infos}.
+"#;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ClusterTableInfo {
+    pub node: String,
+    #[serde(flatten)]
+    pub info: EtsTableInfo,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeFailure {
+    pub node: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ClusterTables {
+    pub tables: Vec<ClusterTableInfo>,
+    pub failures: Vec<NodeFailure>,
+}
+
+pub(crate) async fn discover_members(node: &Node, gateway: &str) -> Result<Vec<OwnedTerm>> {
+    let others = node.rpc_call(gateway, "erlang", "nodes", vec![]).await?;
+    let mut members = vec![erl_atom!(gateway)];
+    members.extend(check_badrpc(others)?.try_into_list()?);
+    Ok(members)
+}
+
+/// Each member's result is returned separately so that one unresponsive
+/// node does not fail the whole operation.
+pub(crate) async fn multicall_eval(
+    node: &Node,
+    gateway: &str,
+    source: &str,
+    bindings: Vec<(&str, OwnedTerm)>,
+) -> Result<Vec<(String, Result<OwnedTerm>)>> {
+    let members = discover_members(node, gateway).await?;
+    let exprs = parse_exprs(node, gateway, source).await?;
+
+    let replies = node
+        .rpc_call(
+            gateway,
+            "erpc",
+            "multicall",
+            vec![
+                OwnedTerm::List(members.clone()),
+                erl_atom!("erl_eval"),
+                erl_atom!("exprs"),
+                OwnedTerm::List(vec![exprs, bindings_term(bindings)]),
+                erl_int!(MEMBER_TIMEOUT_MS),
+            ],
+        )
+        .await?;
+    let replies = check_badrpc(replies)?.try_into_list()?;
+
+    Ok(members
+        .into_iter()
+        .zip(replies)
+        .map(|(member, reply)| (member.to_string(), member_result(reply)))
+        .collect())
+}
+
+fn member_result(reply: OwnedTerm) -> Result<OwnedTerm> {
+    match reply.try_into_tuple()?.as_slice() {
+        [tag, value] if tag.is_atom_with_name("ok") => eval_result_value(value.clone()),
+        [class, reason] => Err(Error::BadRpc(format!("{}: {}", class, reason))),
+        other => Err(Error::UnexpectedResponse(format!(
+            "Unexpected multicall reply: {:?}",
+            other
+        ))),
+    }
+}
+
+fn parse_member_tables(value: OwnedTerm) -> Result<Vec<EtsTableInfo>> {
+    let [word_size, infos]: [OwnedTerm; 2] = value
+        .try_into_tuple()?
+        .try_into()
+        .map_err(|_| Error::UnexpectedResponse("Expected a {WordSize, Infos} tuple".to_string()))?;
+    let word_size = match word_size {
+        OwnedTerm::Integer(n) if n > 0 => n as u64,
+        other => {
+            return Err(Error::UnexpectedResponse(format!(
+                "Expected positive integer for wordsize, got: {}",
+                other
+            )));
+        }
+    };
+
+    infos
+        .try_into_list()?
+        .into_iter()
+        .map(|info| parse_table_info(info, word_size))
+        .collect()
+}
+
+pub async fn list_cluster_tables(gateway: &str, cookie: &str) -> Result<ClusterTables> {
+    let node = create_connected_node(gateway, cookie).await?;
+    let results = multicall_eval(&node, gateway, MEMBER_TABLES_SOURCE, vec![]).await?;
+
+    let mut tables = Vec::new();
+    let mut failures = Vec::new();
+    for (member, result) in results {
+        match result.and_then(parse_member_tables) {
+            Ok(member_tables) => {
+                tables.extend(member_tables.into_iter().map(|info| ClusterTableInfo {
+                    node: member.clone(),
+                    info,
+                }))
+            }
+            Err(e) => failures.push(NodeFailure {
+                node: member,
+                reason: e.to_string(),
+            }),
+        }
+    }
+
+    tables.sort_by(|a, b| (&a.info.name, &a.node).cmp(&(&b.info.name, &b.node)));
+
+    Ok(ClusterTables { tables, failures })
+}
@@ -0,0 +1,368 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::diff::group_by_key;
+use crate::errors::{Error, Result};
+use crate::ets::{EtsTableInfo, check_badrpc, create_node_connected_to_all, fetch_remote_tables};
+use crate::eval::eval;
+use crate::filter_tables;
+use edp_node::Node;
+use erltf::{OwnedTerm, erl_atom};
+use regex::Regex;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Tables with more objects than this on either node are compared
+/// using per-key hashes unless a method is requested explicitly.
+pub const DEFAULT_HASH_THRESHOLD: u64 = 10_000;
+
+const KEY_HASHES_SOURCE: &str = r#"
+KeyPos = ets:info(Tab, keypos),
+Hashes = ets:foldl(
+    fun(Obj, Acc) ->
+        Key = element(KeyPos, Obj),
+        Hash = erlang:phash2(Obj, 4294967296),
+        maps:update_with(Key, fun(Sum) -> (Sum + Hash) band 16#FFFFFFFF end, Hash, Acc)
+    end, #{}, Tab),
+maps:to_list(Hashes).
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonMethod {
+    /// Picks `KeyHashes` for tables larger than the threshold, `Contents` otherwise.
+    Auto { hash_threshold: u64 },
+    /// Transfers all objects from both nodes and compares them locally.
+    Contents,
+    /// Computes an `erlang:phash2/2` digest per key on each node and
+    /// compares only the digests.
+    KeyHashes,
+}
+
+impl fmt::Display for ComparisonMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComparisonMethod::Auto { .. } => write!(f, "auto"),
+            ComparisonMethod::Contents => write!(f, "contents"),
+            ComparisonMethod::KeyHashes => write!(f, "key hashes"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TableComparison {
+    pub table_name: String,
+    pub left_node: String,
+    pub right_node: String,
+    pub left_size: u64,
+    pub right_size: u64,
+    pub method: ComparisonMethod,
+    pub only_left: Vec<OwnedTerm>,
+    pub only_right: Vec<OwnedTerm>,
+    pub differing: Vec<OwnedTerm>,
+}
+
+impl TableComparison {
+    pub fn is_identical(&self) -> bool {
+        self.only_left.is_empty() && self.only_right.is_empty() && self.differing.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyComparison<K> {
+    pub only_left: Vec<K>,
+    pub only_right: Vec<K>,
+    pub differing: Vec<K>,
+}
+
+/// Compares two key-to-value maps, reporting keys present on only one
+/// side and keys whose values differ.
+pub fn compare_keyed<K: Ord + Clone, V: PartialEq>(
+    left: &BTreeMap<K, V>,
+    right: &BTreeMap<K, V>,
+) -> KeyComparison<K> {
+    let mut result = KeyComparison {
+        only_left: Vec::new(),
+        only_right: Vec::new(),
+        differing: Vec::new(),
+    };
+
+    for (key, left_value) in left {
+        match right.get(key) {
+            None => result.only_left.push(key.clone()),
+            Some(right_value) if right_value != left_value => result.differing.push(key.clone()),
+            Some(_) => {}
+        }
+    }
+    result.only_right = right
+        .keys()
+        .filter(|key| !left.contains_key(*key))
+        .cloned()
+        .collect();
+
+    result
+}
+
+async fn remote_table_size(node: &Node, remote_node: &str, table_name: &str) -> Result<u64> {
+    let size = node
+        .rpc_call(
+            remote_node,
+            "ets",
+            "info",
+            vec![erl_atom!(table_name), erl_atom!("size")],
+        )
+        .await?;
+
+    match check_badrpc(size)? {
+        OwnedTerm::Integer(n) if n >= 0 => Ok(n as u64),
+        _ => Err(Error::TableNotFound(format!(
+            "{} on {}",
+            table_name, remote_node
+        ))),
+    }
+}
+
+async fn remote_keypos(node: &Node, remote_node: &str, table_name: &str) -> Result<u64> {
+    let keypos = node
+        .rpc_call(
+            remote_node,
+            "ets",
+            "info",
+            vec![erl_atom!(table_name), erl_atom!("keypos")],
+        )
+        .await?;
+
+    match check_badrpc(keypos)? {
+        OwnedTerm::Integer(n) if n > 0 => Ok(n as u64),
+        other => Err(Error::UnexpectedResponse(format!(
+            "Expected positive integer for keypos, got: {}",
+            other
+        ))),
+    }
+}
+
+async fn remote_objects_by_key(
+    node: &Node,
+    remote_node: &str,
+    table_name: &str,
+) -> Result<BTreeMap<OwnedTerm, Vec<OwnedTerm>>> {
+    let keypos = remote_keypos(node, remote_node, table_name).await?;
+    let objects = node
+        .rpc_call(remote_node, "ets", "tab2list", vec![erl_atom!(table_name)])
+        .await?;
+    let objects = check_badrpc(objects)?.try_into_list()?;
+
+    Ok(group_by_key(&objects, keypos))
+}
+
+async fn remote_key_hashes(
+    node: &Node,
+    remote_node: &str,
+    table_name: &str,
+) -> Result<BTreeMap<OwnedTerm, i64>> {
+    let pairs = eval(
+        node,
+        remote_node,
+        KEY_HASHES_SOURCE,
+        vec![("Tab", erl_atom!(table_name))],
+    )
+    .await?;
+
+    let mut hashes = BTreeMap::new();
+    for pair in pairs.try_into_list()? {
+        if let [key, OwnedTerm::Integer(hash)] = pair.try_into_tuple()?.as_slice() {
+            hashes.insert(key.clone(), *hash);
+        }
+    }
+    Ok(hashes)
+}
+
+/// Compares the contents of a named table on two nodes.
+pub async fn compare_table(
+    left_node: &str,
+    right_node: &str,
+    cookie: &str,
+    table_name: &str,
+    method: ComparisonMethod,
+) -> Result<TableComparison> {
+    let node = create_node_connected_to_all(&[left_node, right_node], cookie).await?;
+
+    let left_size = remote_table_size(&node, left_node, table_name).await?;
+    let right_size = remote_table_size(&node, right_node, table_name).await?;
+
+    let method = match method {
+        ComparisonMethod::Auto { hash_threshold } => {
+            if left_size.max(right_size) > hash_threshold {
+                ComparisonMethod::KeyHashes
+            } else {
+                ComparisonMethod::Contents
+            }
+        }
+        explicit => explicit,
+    };
+
+    let keys = match method {
+        ComparisonMethod::KeyHashes => {
+            let left = remote_key_hashes(&node, left_node, table_name).await?;
+            let right = remote_key_hashes(&node, right_node, table_name).await?;
+            compare_keyed(&left, &right)
+        }
+        _ => {
+            let left = remote_objects_by_key(&node, left_node, table_name).await?;
+            let right = remote_objects_by_key(&node, right_node, table_name).await?;
+            compare_keyed(&left, &right)
+        }
+    };
+
+    Ok(TableComparison {
+        table_name: table_name.to_string(),
+        left_node: left_node.to_string(),
+        right_node: right_node.to_string(),
+        left_size,
+        right_size,
+        method,
+        only_left: keys.only_left,
+        only_right: keys.only_right,
+        differing: keys.differing,
+    })
+}
+
+/// A node's objects are flagged as an outlier when they differ from the
+/// median across nodes by more than this fraction of the median...
+pub const OUTLIER_RATIO: f64 = 0.5;
+/// ...and by at least this many objects, so that tiny tables are not flagged.
+pub const OUTLIER_MIN_OBJECTS: u64 = 10;
+/// Outliers are only reported when at least this many nodes have the table.
+pub const MIN_OUTLIER_NODES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct NodeTableStats {
+    /// Number of tables with this name on the node (unnamed tables can share a name).
+    pub tables: usize,
+    pub size: u64,
+    pub memory_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InventoryRow {
+    pub name: String,
+    /// One entry per node, in the same order as [`InventoryComparison::nodes`].
+    pub per_node: Vec<Option<NodeTableStats>>,
+    /// Indexes of nodes whose object count is far from the median.
+    pub size_outliers: Vec<usize>,
+}
+
+impl InventoryRow {
+    pub fn is_missing_on_some(&self) -> bool {
+        self.per_node.iter().any(Option::is_none)
+    }
+
+    pub fn is_outlier(&self, node_index: usize) -> bool {
+        self.size_outliers.contains(&node_index)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InventoryComparison {
+    pub nodes: Vec<String>,
+    pub rows: Vec<InventoryRow>,
+}
+
+fn median(values: &mut [u64]) -> f64 {
+    values.sort_unstable();
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) as f64 / 2.0
+    } else {
+        values[mid] as f64
+    }
+}
+
+fn size_outliers(per_node: &[Option<NodeTableStats>]) -> Vec<usize> {
+    let mut sizes: Vec<u64> = per_node.iter().flatten().map(|s| s.size).collect();
+    // With two nodes both are equally far from the median, so neither can
+    // be singled out.
+    if sizes.len() < MIN_OUTLIER_NODES {
+        return Vec::new();
+    }
+    let median = median(&mut sizes);
+
+    per_node
+        .iter()
+        .enumerate()
+        .filter_map(|(i, stats)| {
+            let deviation = (stats.as_ref()?.size as f64 - median).abs();
+            (deviation > median * OUTLIER_RATIO && deviation >= OUTLIER_MIN_OBJECTS as f64)
+                .then_some(i)
+        })
+        .collect()
+}
+
+/// Builds a side-by-side inventory with one row per table name from
+/// per-node table listings.
+pub fn build_inventory(per_node_tables: Vec<(String, Vec<EtsTableInfo>)>) -> InventoryComparison {
+    let node_count = per_node_tables.len();
+    let mut by_name: BTreeMap<String, Vec<Option<NodeTableStats>>> = BTreeMap::new();
+
+    for (i, (_, tables)) in per_node_tables.iter().enumerate() {
+        for table in tables {
+            let slot = &mut by_name
+                .entry(table.name.clone())
+                .or_insert_with(|| vec![None; node_count])[i];
+            let stats = slot.get_or_insert(NodeTableStats {
+                tables: 0,
+                size: 0,
+                memory_bytes: 0,
+            });
+            stats.tables += 1;
+            stats.size += table.size;
+            stats.memory_bytes += table.memory_bytes;
+        }
+    }
+
+    let rows = by_name
+        .into_iter()
+        .map(|(name, per_node)| InventoryRow {
+            size_outliers: size_outliers(&per_node),
+            name,
+            per_node,
+        })
+        .collect();
+
+    InventoryComparison {
+        nodes: per_node_tables.into_iter().map(|(node, _)| node).collect(),
+        rows,
+    }
+}
+
+/// Lists tables on every node and lines them up by name.
+pub async fn compare_inventories(
+    remote_nodes: &[&str],
+    cookie: &str,
+    pattern: Option<&Regex>,
+) -> Result<InventoryComparison> {
+    let node = create_node_connected_to_all(remote_nodes, cookie).await?;
+
+    let mut per_node_tables = Vec::with_capacity(remote_nodes.len());
+    for remote_node in remote_nodes {
+        let tables = fetch_remote_tables(&node, remote_node)
+            .await?
+            .into_iter()
+            .map(|t| t.info)
+            .collect();
+        per_node_tables.push((remote_node.to_string(), filter_tables(tables, pattern)));
+    }
+
+    Ok(build_inventory(per_node_tables))
+}
@@ -0,0 +1,214 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::{Error, Result};
+use crate::ets::{EtsTableInfo, create_connected_node, fetch_remote_tables, get_word_size};
+use crate::eval::eval;
+use erltf::{OwnedTerm, erl_int};
+use regex::Regex;
+use serde::Serialize;
+
+pub const DEFAULT_COMPRESSION_SAMPLE_SIZE: usize = 1000;
+
+// Compressed tables store objects in the external term format, so
+// term_to_binary/1 against flat_size/1 approximates the effect.
+const SAMPLE_SOURCE: &str = r#"
+Measure = fun(T) ->
+              case ets:info(T, compressed) of
+                  true -> already_compressed;
+                  _ ->
+                      try ets:select(T, [{'_', [], ['$_']}], Limit) of
+                          '$end_of_table' -> {0, 0, 0};
+                          {Objects, _} ->
+                              lists:foldl(fun(O, {N, Flat, External}) ->
+                                              {N + 1,
+                                               Flat + erts_debug:flat_size(O),
+                                               External + byte_size(term_to_binary(O))}
+                                          end, {0, 0, 0}, Objects)
+                      catch _:_ -> unreadable
+                      end
+              end
+          end,
+[Measure(T) || T <- Tabs].
+"#;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompressionSample {
+    pub objects: u64,
+    pub flat_bytes: u64,
+    pub external_bytes: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompressionStatus {
+    Estimated,
+    Empty,
+    AlreadyCompressed,
+    Unreadable,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TableCompressionEstimate {
+    pub name: String,
+    pub size: u64,
+    pub memory_bytes: u64,
+    pub status: CompressionStatus,
+    pub sampled_objects: u64,
+    /// Compressed to uncompressed size of the sampled objects. Above 1.0
+    /// when compression would make the objects larger.
+    pub ratio: Option<f64>,
+    pub estimated_savings_bytes: u64,
+    pub projected_memory_bytes: u64,
+}
+
+impl TableCompressionEstimate {
+    /// Projects the sampled ratio onto the whole table. The object data is
+    /// extrapolated from the average sampled object and capped at the
+    /// table's memory; only a ratio below 1.0 yields savings.
+    pub fn from_sample(info: &EtsTableInfo, sample: CompressionSample) -> Self {
+        let mut estimate = Self {
+            name: info.name.clone(),
+            size: info.size,
+            memory_bytes: info.memory_bytes,
+            status: CompressionStatus::Empty,
+            sampled_objects: sample.objects,
+            ratio: None,
+            estimated_savings_bytes: 0,
+            projected_memory_bytes: info.memory_bytes,
+        };
+        if sample.objects == 0 || sample.flat_bytes == 0 {
+            return estimate;
+        }
+
+        let ratio = sample.external_bytes as f64 / sample.flat_bytes as f64;
+        let average_object = sample.flat_bytes as f64 / sample.objects as f64;
+        let data_bytes = (average_object * info.size as f64).min(info.memory_bytes as f64);
+        let savings = (data_bytes * (1.0 - ratio)).max(0.0) as u64;
+
+        estimate.status = CompressionStatus::Estimated;
+        estimate.ratio = Some(ratio);
+        estimate.estimated_savings_bytes = savings;
+        estimate.projected_memory_bytes = info.memory_bytes.saturating_sub(savings);
+        estimate
+    }
+
+    fn with_status(info: &EtsTableInfo, status: CompressionStatus) -> Self {
+        Self {
+            status,
+            ..Self::from_sample(info, CompressionSample::default())
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CompressionEstimate {
+    /// Sorted by estimated savings, largest first.
+    pub tables: Vec<TableCompressionEstimate>,
+    pub total_memory_bytes: u64,
+    pub estimated_savings_bytes: u64,
+}
+
+impl CompressionEstimate {
+    pub fn from_tables(mut tables: Vec<TableCompressionEstimate>) -> Self {
+        tables.sort_by(|a, b| {
+            b.estimated_savings_bytes
+                .cmp(&a.estimated_savings_bytes)
+                .then(b.memory_bytes.cmp(&a.memory_bytes))
+        });
+        Self {
+            total_memory_bytes: tables.iter().map(|t| t.memory_bytes).sum(),
+            estimated_savings_bytes: tables.iter().map(|t| t.estimated_savings_bytes).sum(),
+            tables,
+        }
+    }
+}
+
+fn parse_sample(term: &OwnedTerm, word_size: u64) -> Result<Option<CompressionSample>> {
+    let Some([objects, flat_words, external_bytes]) = term.as_tuple() else {
+        return Ok(None);
+    };
+    let as_u64 = |t: &OwnedTerm| match t {
+        OwnedTerm::Integer(n) if *n >= 0 => Ok(*n as u64),
+        other => Err(Error::UnexpectedResponse(format!(
+            "Expected non-negative integer in compression sample, got: {}",
+            other
+        ))),
+    };
+
+    Ok(Some(CompressionSample {
+        objects: as_u64(objects)?,
+        flat_bytes: as_u64(flat_words)? * word_size,
+        external_bytes: as_u64(external_bytes)?,
+    }))
+}
+
+pub async fn estimate_compression(
+    remote_node: &str,
+    cookie: &str,
+    pattern: Option<&Regex>,
+    sample_size: usize,
+) -> Result<CompressionEstimate> {
+    if sample_size == 0 {
+        return Err(Error::InvalidArgument(
+            "sample size must be greater than zero".to_string(),
+        ));
+    }
+
+    let node = create_connected_node(remote_node, cookie).await?;
+    let word_size = get_word_size(&node, remote_node).await?;
+
+    let tables: Vec<_> = fetch_remote_tables(&node, remote_node)
+        .await?
+        .into_iter()
+        .filter(|t| pattern.is_none_or(|p| p.is_match(&t.info.name)))
+        .collect();
+
+    let samples = eval(
+        &node,
+        remote_node,
+        SAMPLE_SOURCE,
+        vec![
+            (
+                "Tabs",
+                OwnedTerm::List(tables.iter().map(|t| t.reference.clone()).collect()),
+            ),
+            ("Limit", erl_int!(sample_size as i64)),
+        ],
+    )
+    .await?
+    .try_into_list()?;
+
+    let estimates = tables
+        .iter()
+        .zip(samples.iter())
+        .map(|(table, sample)| {
+            Ok(match parse_sample(sample, word_size)? {
+                Some(sample) => TableCompressionEstimate::from_sample(&table.info, sample),
+                None if sample.is_atom_with_name("already_compressed") => {
+                    TableCompressionEstimate::with_status(
+                        &table.info,
+                        CompressionStatus::AlreadyCompressed,
+                    )
+                }
+                None => TableCompressionEstimate::with_status(
+                    &table.info,
+                    CompressionStatus::Unreadable,
+                ),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(CompressionEstimate::from_tables(estimates))
+}
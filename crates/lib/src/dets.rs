@@ -0,0 +1,168 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::{Error, Result};
+use crate::ets::{TableType, check_badrpc, create_connected_node};
+use crate::eval::parse_term;
+use crate::typed::from_proplist;
+use edp_node::Node;
+use erltf::{OwnedTerm, erl_atom, erl_int};
+use serde::{Deserialize, Serialize};
+
+/// Approximate number of objects fetched per `dets:match_object/3` round trip.
+pub const DEFAULT_CHUNK_SIZE: usize = 500;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DetsTableInfo {
+    pub name: String,
+    pub file: String,
+    pub table_type: TableType,
+    pub size: u64,
+    pub file_size: u64,
+    pub keypos: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct DetsTableInfoPartial {
+    #[serde(rename = "type")]
+    table_type: TableType,
+    size: u64,
+    file_size: u64,
+    keypos: u64,
+}
+
+pub(crate) fn parse_dets_info(name: String, info_list: OwnedTerm) -> Result<DetsTableInfo> {
+    let file = info_list
+        .proplist_get_string("filename")
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let partial: DetsTableInfoPartial = from_proplist(&info_list)?;
+
+    Ok(DetsTableInfo {
+        name,
+        file,
+        table_type: partial.table_type,
+        size: partial.size,
+        file_size: partial.file_size,
+        keypos: partial.keypos,
+    })
+}
+
+fn check_dets_error(reply: OwnedTerm) -> Result<OwnedTerm> {
+    match reply.as_tuple() {
+        Some([tag, reason]) if tag.is_atom_with_name("error") => {
+            Err(Error::Dets(reason.to_string()))
+        }
+        _ => Ok(reply),
+    }
+}
+
+async fn ensure_dets_table(node: &Node, remote_node: &str, table_name: &str) -> Result<OwnedTerm> {
+    let table_ref = erl_atom!(table_name);
+    let info = node
+        .rpc_call(remote_node, "dets", "info", vec![table_ref.clone()])
+        .await?;
+
+    if check_badrpc(info)?.is_undefined() {
+        return Err(Error::TableNotFound(table_name.to_string()));
+    }
+
+    Ok(table_ref)
+}
+
+pub async fn list_dets_tables(remote_node: &str, cookie: &str) -> Result<Vec<DetsTableInfo>> {
+    let node = create_connected_node(remote_node, cookie).await?;
+
+    let names = node.rpc_call(remote_node, "dets", "all", vec![]).await?;
+    let names = check_badrpc(names)?.try_into_list()?;
+
+    let mut tables = Vec::with_capacity(names.len());
+    for name in names {
+        let info_list = node
+            .rpc_call(remote_node, "dets", "info", vec![name.clone()])
+            .await?;
+
+        // the table was closed after dets:all/0 returned
+        if info_list.is_undefined() {
+            continue;
+        }
+
+        tables.push(parse_dets_info(name.to_string(), check_badrpc(info_list)?)?);
+    }
+
+    tables.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(tables)
+}
+
+/// Reads `chunk_size` objects at a time with `dets:match_object/3`.
+pub async fn dump_dets_table(
+    remote_node: &str,
+    cookie: &str,
+    table_name: &str,
+    chunk_size: usize,
+) -> Result<Vec<OwnedTerm>> {
+    let node = create_connected_node(remote_node, cookie).await?;
+    let table_ref = ensure_dets_table(&node, remote_node, table_name).await?;
+
+    let mut entries = Vec::new();
+    let mut reply = node
+        .rpc_call(
+            remote_node,
+            "dets",
+            "match_object",
+            vec![
+                table_ref,
+                erl_atom!("_"),
+                erl_int!(chunk_size.max(1) as i64),
+            ],
+        )
+        .await?;
+
+    loop {
+        let chunk = check_dets_error(check_badrpc(reply)?)?;
+        if chunk.is_atom_with_name("$end_of_table") {
+            break;
+        }
+
+        let [objects, continuation]: [OwnedTerm; 2] =
+            chunk.try_into_tuple()?.try_into().map_err(|_| {
+                Error::UnexpectedResponse("Expected an {Objects, Continuation} tuple".to_string())
+            })?;
+        entries.extend(objects.try_into_list()?);
+
+        reply = node
+            .rpc_call(remote_node, "dets", "match_object", vec![continuation])
+            .await?;
+    }
+
+    Ok(entries)
+}
+
+pub async fn lookup_dets_table(
+    remote_node: &str,
+    cookie: &str,
+    table_name: &str,
+    key: &str,
+) -> Result<Vec<OwnedTerm>> {
+    let node = create_connected_node(remote_node, cookie).await?;
+    let table_ref = ensure_dets_table(&node, remote_node, table_name).await?;
+    let key = parse_term(&node, remote_node, key).await?;
+
+    let objects = node
+        .rpc_call(remote_node, "dets", "lookup", vec![table_ref, key])
+        .await?;
+
+    Ok(check_dets_error(check_badrpc(objects)?)?.try_into_list()?)
+}
@@ -0,0 +1,223 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ets::EtsTableInfo;
+use crate::snapshot::Snapshot;
+use erltf::OwnedTerm;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Size and memory changes of the tables sharing a name. Unnamed tables
+/// can share a name, so sizes and memory are summed over all of them and
+/// `old_count`/`new_count` report how many there were on each side.
+#[derive(Debug, Clone, Serialize)]
+pub struct TableDelta {
+    pub name: String,
+    pub old_count: usize,
+    pub new_count: usize,
+    pub old_size: u64,
+    pub new_size: u64,
+    pub size_delta: i64,
+    pub old_memory_bytes: u64,
+    pub new_memory_bytes: u64,
+    pub memory_delta: i64,
+}
+
+impl TableDelta {
+    pub fn is_unchanged(&self) -> bool {
+        self.old_count == self.new_count && self.size_delta == 0 && self.memory_delta == 0
+    }
+
+    pub fn count_delta(&self) -> i64 {
+        self.new_count as i64 - self.old_count as i64
+    }
+}
+
+/// Objects sharing a key whose values differ between the two sides.
+/// For bag tables a key can map to several objects.
+#[derive(Debug, Clone)]
+pub struct ChangedObjects {
+    pub key: OwnedTerm,
+    pub old: Vec<OwnedTerm>,
+    pub new: Vec<OwnedTerm>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ContentsDiff {
+    pub table_name: String,
+    pub added: Vec<OwnedTerm>,
+    pub removed: Vec<OwnedTerm>,
+    pub changed: Vec<ChangedObjects>,
+}
+
+impl ContentsDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SnapshotDiff {
+    pub added: Vec<EtsTableInfo>,
+    pub removed: Vec<EtsTableInfo>,
+    pub changed: Vec<TableDelta>,
+    pub contents: Vec<ContentsDiff>,
+}
+
+/// Returns the key of an ETS object given the table's 1-based `keypos`.
+pub fn object_key(object: &OwnedTerm, keypos: u64) -> Option<&OwnedTerm> {
+    let index = usize::try_from(keypos).ok()?.checked_sub(1)?;
+    object.as_tuple()?.get(index)
+}
+
+fn signed_delta(old: u64, new: u64) -> i64 {
+    new as i64 - old as i64
+}
+
+pub(crate) fn group_by_key(
+    objects: &[OwnedTerm],
+    keypos: u64,
+) -> BTreeMap<OwnedTerm, Vec<OwnedTerm>> {
+    let mut groups: BTreeMap<OwnedTerm, Vec<OwnedTerm>> = BTreeMap::new();
+    for object in objects {
+        let key = object_key(object, keypos)
+            .cloned()
+            .unwrap_or_else(|| object.clone());
+        groups.entry(key).or_default().push(object.clone());
+    }
+    for group in groups.values_mut() {
+        group.sort();
+    }
+    groups
+}
+
+/// Compares two sets of objects of the same table, matching them by key.
+pub fn diff_contents(
+    table_name: &str,
+    keypos: u64,
+    old: &[OwnedTerm],
+    new: &[OwnedTerm],
+) -> ContentsDiff {
+    let mut old_groups = group_by_key(old, keypos);
+    let new_groups = group_by_key(new, keypos);
+
+    let mut diff = ContentsDiff {
+        table_name: table_name.to_string(),
+        added: Vec::new(),
+        removed: Vec::new(),
+        changed: Vec::new(),
+    };
+
+    for (key, new_objects) in new_groups {
+        match old_groups.remove(&key) {
+            None => diff.added.extend(new_objects),
+            Some(old_objects) if old_objects != new_objects => {
+                diff.changed.push(ChangedObjects {
+                    key,
+                    old: old_objects,
+                    new: new_objects,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+
+    diff.removed = old_groups.into_values().flatten().collect();
+
+    diff
+}
+
+fn group_by_name(tables: &[EtsTableInfo]) -> BTreeMap<&str, Vec<&EtsTableInfo>> {
+    let mut groups: BTreeMap<&str, Vec<&EtsTableInfo>> = BTreeMap::new();
+    for table in tables {
+        groups.entry(table.name.as_str()).or_default().push(table);
+    }
+    groups
+}
+
+fn table_delta(name: &str, old: &[&EtsTableInfo], new: &[&EtsTableInfo]) -> TableDelta {
+    let sum = |tables: &[&EtsTableInfo], f: fn(&EtsTableInfo) -> u64| -> u64 {
+        tables.iter().map(|t| f(t)).sum()
+    };
+    let (old_size, new_size) = (sum(old, |t| t.size), sum(new, |t| t.size));
+    let (old_memory_bytes, new_memory_bytes) =
+        (sum(old, |t| t.memory_bytes), sum(new, |t| t.memory_bytes));
+
+    TableDelta {
+        name: name.to_string(),
+        old_count: old.len(),
+        new_count: new.len(),
+        old_size,
+        new_size,
+        size_delta: signed_delta(old_size, new_size),
+        old_memory_bytes,
+        new_memory_bytes,
+        memory_delta: signed_delta(old_memory_bytes, new_memory_bytes),
+    }
+}
+
+/// Compares two snapshots. Tables are matched by name; several unnamed
+/// tables can share a name, so each name is compared as a group. Contents
+/// are only compared for names that belong to a single table in both
+/// snapshots and have captured contents.
+pub fn diff_snapshots(old: &Snapshot, new: &Snapshot) -> SnapshotDiff {
+    let old_tables = group_by_name(&old.tables);
+    let new_tables = group_by_name(&new.tables);
+
+    let added = new_tables
+        .iter()
+        .filter(|(name, _)| !old_tables.contains_key(*name))
+        .flat_map(|(_, tables)| tables.iter().map(|t| (*t).clone()))
+        .collect();
+    let removed = old_tables
+        .iter()
+        .filter(|(name, _)| !new_tables.contains_key(*name))
+        .flat_map(|(_, tables)| tables.iter().map(|t| (*t).clone()))
+        .collect();
+
+    let common: BTreeSet<&str> = old_tables
+        .keys()
+        .filter(|name| new_tables.contains_key(*name))
+        .copied()
+        .collect();
+
+    let changed = common
+        .iter()
+        .map(|name| table_delta(name, &old_tables[name], &new_tables[name]))
+        .filter(|d| !d.is_unchanged())
+        .collect();
+
+    let contents = common
+        .iter()
+        .filter_map(|name| {
+            let [_] = old_tables[name].as_slice() else {
+                return None;
+            };
+            let [new_table] = new_tables[name].as_slice() else {
+                return None;
+            };
+            let old_objects = old.contents.get(*name)?;
+            let new_objects = new.contents.get(*name)?;
+            let diff = diff_contents(name, new_table.keypos, old_objects, new_objects);
+            (!diff.is_empty()).then_some(diff)
+        })
+        .collect();
+
+    SnapshotDiff {
+        added,
+        removed,
+        changed,
+        contents,
+    }
+}
@@ -33,11 +33,44 @@ pub enum Error {
     #[error("Invalid regex pattern: {0}")]
     InvalidPattern(String),
 
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
+    #[error("Invalid Erlang term: {0}")]
+    InvalidTerm(String),
+
     #[error("Table not found: {0}")]
     TableNotFound(String),
 
+    #[error("Persistent term not found: {0}")]
+    PersistentTermNotFound(String),
+
+    #[error("DETS operation failed: {0}")]
+    Dets(String),
+
+    #[error("Could not fetch owner process state: {0}")]
+    OwnerState(String),
+
     #[error("Unexpected response format: {0}")]
     UnexpectedResponse(String),
+
+    #[error("Remote call failed: {0}")]
+    BadRpc(String),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Term encoding error: {0}")]
+    Encode(#[from] erltf::EncodeError),
+
+    #[error("Term decoding error: {0}")]
+    Decode(#[from] erltf::DecodeError),
+
+    #[error("Invalid snapshot: {0}")]
+    InvalidSnapshot(String),
+
+    #[error("Not supported by this source: {0}")]
+    Unsupported(String),
 }
 
 pub type Result<T, E = Error> = StdResult<T, E>;
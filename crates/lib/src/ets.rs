@@ -13,11 +13,13 @@
 // limitations under the License.
 
 use crate::errors::{Error, Result};
+use crate::eval::eval;
+use crate::typed::from_proplist;
 use edp_node::Node;
-use erltf::{OwnedTerm, erl_atom};
-use erltf_serde::from_term;
+use erltf::{OwnedTerm, erl_atom, erl_int};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -66,6 +68,14 @@ pub struct EtsTableInfo {
     pub memory_bytes: u64,
     pub owner: String,
     pub protection: Protection,
+    pub keypos: u64,
+    /// OTP application of the owner process, only resolved on request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application: Option<String>,
+    /// Estimated bytes of refc binaries referenced by the table's objects,
+    /// which `memory_bytes` does not include. Only computed on request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub off_heap_bytes: Option<u64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -76,6 +86,16 @@ struct EtsTableInfoPartial {
     size: u64,
     memory: u64,
     protection: Protection,
+    keypos: u64,
+}
+
+/// A table as seen through `ets:all/0`: the reference that addresses it
+/// plus its parsed `ets:info/1` data.
+pub(crate) struct RemoteTable {
+    pub reference: OwnedTerm,
+    pub named: bool,
+    pub owner_pid: OwnedTerm,
+    pub info: EtsTableInfo,
 }
 
 fn generate_local_node_name() -> String {
@@ -87,15 +107,24 @@ fn generate_local_node_name() -> String {
     format!("ets_browser_{}@{}", suffix, host)
 }
 
-async fn create_connected_node(remote_node: &str, cookie: &str) -> Result<Node> {
+pub(crate) async fn create_connected_node(remote_node: &str, cookie: &str) -> Result<Node> {
+    create_node_connected_to_all(&[remote_node], cookie).await
+}
+
+pub(crate) async fn create_node_connected_to_all(
+    remote_nodes: &[&str],
+    cookie: &str,
+) -> Result<Node> {
     let local_name = generate_local_node_name();
     let mut node = Node::new_hidden(&local_name, cookie);
     node.start(0).await?;
-    node.connect(remote_node).await?;
+    for remote_node in remote_nodes {
+        node.connect(*remote_node).await?;
+    }
     Ok(node)
 }
 
-async fn get_word_size(node: &Node, remote_node: &str) -> Result<u64> {
+pub(crate) async fn get_word_size(node: &Node, remote_node: &str) -> Result<u64> {
     let word_size = node
         .rpc_call(
             remote_node,
@@ -114,14 +143,70 @@ async fn get_word_size(node: &Node, remote_node: &str) -> Result<u64> {
     }
 }
 
-fn parse_table_info(info_list: OwnedTerm, word_size: u64) -> Result<EtsTableInfo> {
+const OWNER_APPLICATIONS_SOURCE: &str = r#"
+[{P, case is_pid(P) of
+         true -> application:get_application(P);
+         false -> undefined
+     end} || P <- Pids].
+"#;
+
+/// Number of objects scanned per table when estimating off-heap binaries.
+pub const DEFAULT_OFF_HEAP_SAMPLE_SIZE: usize = 1000;
+
+/// Sums the sizes of the refc binaries (larger than 64 bytes) that up to
+/// `Limit` objects of each table point to. A binary referenced from several
+/// objects is counted once per reference.
+const OFF_HEAP_SOURCE: &str = r#"
+Walk = fun W(T, Acc) when is_binary(T) ->
+               case binary:referenced_byte_size(T) of
+                   N when N > 64 -> Acc + N;
+                   _ -> Acc
+               end;
+           W(T, Acc) when is_tuple(T) -> lists:foldl(W, Acc, tuple_to_list(T));
+           W([H | Tail], Acc) -> W(Tail, W(H, Acc));
+           W(T, Acc) when is_map(T) -> maps:fold(fun(K, V, A) -> W(V, W(K, A)) end, Acc, T);
+           W(_, Acc) -> Acc
+       end,
+Measure = fun(T) ->
+              try ets:select(T, [{'_', [], ['$_']}], Limit) of
+                  '$end_of_table' -> {0, 0};
+                  {Objects, _} -> {length(Objects), lists:foldl(Walk, 0, Objects)}
+              catch _:_ -> undefined
+              end
+          end,
+[Measure(T) || T <- Tabs].
+"#;
+
+/// Number of keys fetched per round trip when traversing a table page by page.
+pub const DEFAULT_PAGE_SIZE: usize = 500;
+
+const PAGE_SOURCE: &str = r#"
+Walk = fun W(_, '$end_of_table', _, Acc) -> {lists:reverse(Acc), '$end_of_table'};
+           W(_, K, 0, Acc) -> {lists:reverse(Acc), {next, K}};
+           W(T, K, N, Acc) -> W(T, ets:next(T, K), N - 1, lists:reverse(ets:lookup(T, K), Acc))
+       end,
+First = case Cursor of
+            start -> ets:first(Tab);
+            {next, K} -> K
+        end,
+Walk(Tab, First, Limit, []).
+"#;
+
+/// A slice of a table's objects. `next` is the cursor to pass to get the
+/// following page, `None` once the end of the table has been reached.
+#[derive(Debug, Clone)]
+pub struct TablePage {
+    pub entries: Vec<OwnedTerm>,
+    pub next: Option<OwnedTerm>,
+}
+
+pub(crate) fn parse_table_info(info_list: OwnedTerm, word_size: u64) -> Result<EtsTableInfo> {
     let owner = info_list
         .proplist_get_atom_key("owner")
         .map(|t| t.to_string())
         .unwrap_or_else(|| "unknown".to_string());
 
-    let info_map = info_list.to_map_recursive()?;
-    let partial: EtsTableInfoPartial = from_term(&info_map)?;
+    let partial: EtsTableInfoPartial = from_proplist(&info_list)?;
 
     Ok(EtsTableInfo {
         name: partial.name,
@@ -130,12 +215,27 @@ fn parse_table_info(info_list: OwnedTerm, word_size: u64) -> Result<EtsTableInfo
         memory_bytes: partial.memory * word_size,
         owner,
         protection: partial.protection,
+        keypos: partial.keypos,
+        application: None,
+        off_heap_bytes: None,
     })
 }
 
-pub async fn list_tables(remote_node: &str, cookie: &str) -> Result<Vec<EtsTableInfo>> {
-    let node = create_connected_node(remote_node, cookie).await?;
-    let word_size = get_word_size(&node, remote_node).await?;
+/// Turns a `{badrpc, Reason}` reply into an error.
+pub(crate) fn check_badrpc(reply: OwnedTerm) -> Result<OwnedTerm> {
+    match reply.as_tuple() {
+        Some([tag, reason]) if tag.is_atom_with_name("badrpc") => {
+            Err(Error::BadRpc(reason.to_string()))
+        }
+        _ => Ok(reply),
+    }
+}
+
+pub(crate) async fn fetch_remote_tables(
+    node: &Node,
+    remote_node: &str,
+) -> Result<Vec<RemoteTable>> {
+    let word_size = get_word_size(node, remote_node).await?;
 
     let tables_list = node.rpc_call(remote_node, "ets", "all", vec![]).await?;
     let table_refs = tables_list.try_into_list()?;
@@ -144,30 +244,158 @@ pub async fn list_tables(remote_node: &str, cookie: &str) -> Result<Vec<EtsTable
 
     for table_ref in table_refs {
         let info_list = node
-            .rpc_call(remote_node, "ets", "info", vec![table_ref])
+            .rpc_call(remote_node, "ets", "info", vec![table_ref.clone()])
             .await?;
 
         if info_list.is_undefined() {
             continue;
         }
 
-        tables.push(parse_table_info(info_list, word_size)?);
+        let named = info_list.proplist_get_bool_or("named_table", false);
+        let owner_pid = info_list
+            .proplist_get_atom_key("owner")
+            .cloned()
+            .unwrap_or_else(|| erl_atom!("undefined"));
+        tables.push(RemoteTable {
+            reference: table_ref,
+            named,
+            owner_pid,
+            info: parse_table_info(info_list, word_size)?,
+        });
     }
 
-    tables.sort_by(|a, b| a.name.cmp(&b.name));
+    tables.sort_by(|a, b| a.info.name.cmp(&b.info.name));
 
     Ok(tables)
 }
 
-pub async fn dump_table(
+pub async fn list_tables(remote_node: &str, cookie: &str) -> Result<Vec<EtsTableInfo>> {
+    let node = create_connected_node(remote_node, cookie).await?;
+    let tables = fetch_remote_tables(&node, remote_node).await?;
+
+    Ok(tables.into_iter().map(|t| t.info).collect())
+}
+
+/// Sets `application` on each table from its owner process, resolving
+/// every distinct owner once with `application:get_application/1`.
+pub(crate) async fn resolve_applications(
+    node: &Node,
+    remote_node: &str,
+    tables: &mut [RemoteTable],
+) -> Result<()> {
+    let owners: BTreeSet<OwnedTerm> = tables.iter().map(|t| t.owner_pid.clone()).collect();
+    let resolved = eval(
+        node,
+        remote_node,
+        OWNER_APPLICATIONS_SOURCE,
+        vec![("Pids", OwnedTerm::List(owners.into_iter().collect()))],
+    )
+    .await?;
+
+    let mut applications = BTreeMap::new();
+    for pair in resolved.try_into_list()? {
+        if let Some([pid, app]) = pair.as_tuple()
+            && let Some([_, name]) = app.as_tuple()
+        {
+            applications.insert(pid.clone(), name.to_string());
+        }
+    }
+
+    for table in tables {
+        table.info.application = applications.get(&table.owner_pid).cloned();
+    }
+
+    Ok(())
+}
+
+/// Projects the off-heap binary bytes of a sample onto the whole table.
+pub fn extrapolate_off_heap(sampled_objects: u64, sampled_bytes: u64, table_size: u64) -> u64 {
+    if sampled_objects == 0 || sampled_objects >= table_size {
+        sampled_bytes
+    } else {
+        (sampled_bytes as f64 / sampled_objects as f64 * table_size as f64) as u64
+    }
+}
+
+/// Sets `off_heap_bytes` on each readable table by scanning up to
+/// `sample_size` of its objects on the remote node. Private tables are left
+/// at `None`.
+pub(crate) async fn resolve_off_heap(
+    node: &Node,
+    remote_node: &str,
+    tables: &mut [RemoteTable],
+    sample_size: usize,
+) -> Result<()> {
+    let measured = eval(
+        node,
+        remote_node,
+        OFF_HEAP_SOURCE,
+        vec![
+            (
+                "Tabs",
+                OwnedTerm::List(tables.iter().map(|t| t.reference.clone()).collect()),
+            ),
+            ("Limit", erl_int!(sample_size as i64)),
+        ],
+    )
+    .await?
+    .try_into_list()?;
+
+    for (table, measurement) in tables.iter_mut().zip(measured) {
+        if let Some([OwnedTerm::Integer(objects), OwnedTerm::Integer(bytes)]) =
+            measurement.as_tuple()
+        {
+            table.info.off_heap_bytes = Some(extrapolate_off_heap(
+                (*objects).max(0) as u64,
+                (*bytes).max(0) as u64,
+                table.info.size,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Like [`list_tables`], but also estimates the off-heap binary bytes
+/// referenced by each table.
+pub async fn list_tables_with_off_heap(
     remote_node: &str,
     cookie: &str,
-    table_name: &str,
-) -> Result<Vec<OwnedTerm>> {
+    sample_size: usize,
+) -> Result<Vec<EtsTableInfo>> {
+    if sample_size == 0 {
+        return Err(Error::InvalidArgument(
+            "sample size must be greater than zero".to_string(),
+        ));
+    }
+
     let node = create_connected_node(remote_node, cookie).await?;
+    let mut tables = fetch_remote_tables(&node, remote_node).await?;
+    resolve_off_heap(&node, remote_node, &mut tables, sample_size).await?;
 
-    let table_ref = erl_atom!(table_name);
+    Ok(tables.into_iter().map(|t| t.info).collect())
+}
+
+/// Like [`list_tables`], but also resolves the OTP application of each
+/// table's owner process.
+pub async fn list_tables_with_applications(
+    remote_node: &str,
+    cookie: &str,
+) -> Result<Vec<EtsTableInfo>> {
+    let node = create_connected_node(remote_node, cookie).await?;
+    let mut tables = fetch_remote_tables(&node, remote_node).await?;
+    resolve_applications(&node, remote_node, &mut tables).await?;
+
+    Ok(tables.into_iter().map(|t| t.info).collect())
+}
 
+/// Returns the reference of a named table, or an error if there is no such table.
+pub(crate) async fn ensure_table(
+    node: &Node,
+    remote_node: &str,
+    table_name: &str,
+) -> Result<OwnedTerm> {
+    let table_ref = erl_atom!(table_name);
     let info = node
         .rpc_call(remote_node, "ets", "info", vec![table_ref.clone()])
         .await?;
@@ -176,9 +404,93 @@ pub async fn dump_table(
         return Err(Error::TableNotFound(table_name.to_string()));
     }
 
+    Ok(table_ref)
+}
+
+pub(crate) async fn lookup_objects(
+    node: &Node,
+    remote_node: &str,
+    table_ref: OwnedTerm,
+    key: &OwnedTerm,
+) -> Result<Vec<OwnedTerm>> {
+    let objects = node
+        .rpc_call(remote_node, "ets", "lookup", vec![table_ref, key.clone()])
+        .await?;
+
+    Ok(check_badrpc(objects)?.try_into_list()?)
+}
+
+/// Looks up the objects stored under `key` in a named table.
+pub async fn lookup_table(
+    remote_node: &str,
+    cookie: &str,
+    table_name: &str,
+    key: &OwnedTerm,
+) -> Result<Vec<OwnedTerm>> {
+    let node = create_connected_node(remote_node, cookie).await?;
+    let table_ref = ensure_table(&node, remote_node, table_name).await?;
+
+    lookup_objects(&node, remote_node, table_ref, key).await
+}
+
+pub async fn dump_table(
+    remote_node: &str,
+    cookie: &str,
+    table_name: &str,
+) -> Result<Vec<OwnedTerm>> {
+    let node = create_connected_node(remote_node, cookie).await?;
+    let table_ref = ensure_table(&node, remote_node, table_name).await?;
+
     let entries = node
         .rpc_call(remote_node, "ets", "tab2list", vec![table_ref])
         .await?;
 
-    Ok(entries.try_into_list()?)
+    Ok(check_badrpc(entries)?.try_into_list()?)
+}
+
+/// Fetches up to `limit` keys worth of objects (bags can hold several
+/// objects per key) starting at `cursor`, traversing the table with
+/// `ets:first/1` and `ets:next/2` on the remote node.
+pub(crate) async fn fetch_page(
+    node: &Node,
+    remote_node: &str,
+    table_ref: OwnedTerm,
+    cursor: Option<OwnedTerm>,
+    limit: usize,
+) -> Result<TablePage> {
+    let cursor = cursor.unwrap_or_else(|| erl_atom!("start"));
+    let page = eval(
+        node,
+        remote_node,
+        PAGE_SOURCE,
+        vec![
+            ("Tab", table_ref),
+            ("Cursor", cursor),
+            ("Limit", OwnedTerm::Integer(limit.max(1) as i64)),
+        ],
+    )
+    .await?;
+
+    let [entries, next]: [OwnedTerm; 2] = page
+        .try_into_tuple()?
+        .try_into()
+        .map_err(|_| Error::UnexpectedResponse("Expected an {Entries, Next} tuple".to_string()))?;
+
+    Ok(TablePage {
+        entries: entries.try_into_list()?,
+        next: (!next.is_atom_with_name("$end_of_table")).then_some(next),
+    })
+}
+
+pub async fn page_table(
+    remote_node: &str,
+    cookie: &str,
+    table_name: &str,
+    cursor: Option<OwnedTerm>,
+    limit: usize,
+) -> Result<TablePage> {
+    let node = create_connected_node(remote_node, cookie).await?;
+    let table_ref = ensure_table(&node, remote_node, table_name).await?;
+
+    fetch_page(&node, remote_node, table_ref, cursor, limit).await
 }
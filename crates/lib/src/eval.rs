@@ -0,0 +1,124 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::{Error, Result};
+use crate::ets::check_badrpc;
+use edp_node::{DEFAULT_RPC_TIMEOUT, Node};
+use erltf::{OwnedTerm, erl_atom, erl_tuple};
+use std::time::Duration;
+
+/// Scanning and parsing happen on `remote_node`, as there is no Erlang
+/// parser on this side.
+pub(crate) async fn parse_exprs(node: &Node, remote_node: &str, source: &str) -> Result<OwnedTerm> {
+    let scanned = node
+        .rpc_call(
+            remote_node,
+            "erl_scan",
+            "string",
+            vec![OwnedTerm::charlist(source)],
+        )
+        .await?;
+    let tokens = match check_badrpc(scanned)?.try_into_tuple()?.as_slice() {
+        [ok, tokens, _] if ok.is_atom_with_name("ok") => tokens.clone(),
+        other => {
+            return Err(Error::UnexpectedResponse(format!(
+                "Failed to scan remote expression: {:?}",
+                other
+            )));
+        }
+    };
+
+    let parsed = node
+        .rpc_call(remote_node, "erl_parse", "parse_exprs", vec![tokens])
+        .await?;
+    check_badrpc(parsed)?
+        .into_ok_value()
+        .ok_or_else(|| Error::UnexpectedResponse("Failed to parse remote expression".to_string()))
+}
+
+/// Parses a term written in Erlang syntax, e.g. `<<"guest">>`, on `remote_node`.
+pub(crate) async fn parse_term(node: &Node, remote_node: &str, source: &str) -> Result<OwnedTerm> {
+    let source = format!("{}.", source.trim().trim_end_matches('.'));
+    let scanned = node
+        .rpc_call(
+            remote_node,
+            "erl_scan",
+            "string",
+            vec![OwnedTerm::charlist(&source)],
+        )
+        .await?;
+    let tokens = match check_badrpc(scanned)?.try_into_tuple()?.as_slice() {
+        [ok, tokens, _] if ok.is_atom_with_name("ok") => tokens.clone(),
+        _ => return Err(Error::InvalidTerm(source)),
+    };
+
+    let parsed = node
+        .rpc_call(remote_node, "erl_parse", "parse_term", vec![tokens])
+        .await?;
+    check_badrpc(parsed)?
+        .into_ok_value()
+        .ok_or(Error::InvalidTerm(source))
+}
+
+pub(crate) fn bindings_term(mut bindings: Vec<(&str, OwnedTerm)>) -> OwnedTerm {
+    bindings.sort_by_key(|(name, _)| *name);
+    OwnedTerm::List(
+        bindings
+            .into_iter()
+            .map(|(name, value)| erl_tuple!(erl_atom!(name), value))
+            .collect(),
+    )
+}
+
+pub(crate) fn eval_result_value(result: OwnedTerm) -> Result<OwnedTerm> {
+    match result.try_into_tuple()?.as_slice() {
+        [tag, value, _] if tag.is_atom_with_name("value") => Ok(value.clone()),
+        other => Err(Error::UnexpectedResponse(format!(
+            "Unexpected result of remote evaluation: {:?}",
+            other
+        ))),
+    }
+}
+
+pub(crate) async fn eval(
+    node: &Node,
+    remote_node: &str,
+    source: &str,
+    bindings: Vec<(&str, OwnedTerm)>,
+) -> Result<OwnedTerm> {
+    eval_with_timeout(node, remote_node, source, bindings, DEFAULT_RPC_TIMEOUT).await
+}
+
+/// Like [`eval`], but waits up to `timeout` for the evaluation to finish
+/// instead of the default RPC timeout.
+pub(crate) async fn eval_with_timeout(
+    node: &Node,
+    remote_node: &str,
+    source: &str,
+    bindings: Vec<(&str, OwnedTerm)>,
+    timeout: Duration,
+) -> Result<OwnedTerm> {
+    let exprs = parse_exprs(node, remote_node, source).await?;
+
+    let evaluated = node
+        .rpc_call_with_timeout(
+            remote_node,
+            "erl_eval",
+            "exprs",
+            vec![exprs, bindings_term(bindings)],
+            timeout,
+        )
+        .await?;
+    eval_result_value(check_badrpc(evaluated)?)
+}
@@ -0,0 +1,170 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::{Error, Result};
+use crate::ets::{
+    EtsTableInfo, check_badrpc, create_connected_node, get_word_size, parse_table_info,
+};
+use crate::eval::eval;
+use erltf::{OwnedTerm, erl_atom};
+use serde::Serialize;
+
+/// How many times the expected standard deviation of chain lengths the
+/// observed one may be before the key distribution is reported as skewed.
+pub const DEFAULT_SKEW_FACTOR: f64 = 3.0;
+
+const TABLE_DETAILS_SOURCE: &str = r#"
+case ets:info(Tab) of
+    undefined -> undefined;
+    Info ->
+        Stats = case WithStats of
+                    true -> ets:info(Tab, stats);
+                    false -> false
+                end,
+        {Info, Stats}
+end.
+"#;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HashStats {
+    pub buckets: u64,
+    pub min_chain_len: u64,
+    pub max_chain_len: u64,
+    pub avg_chain_len: f64,
+    pub std_dev: f64,
+    pub expected_std_dev: f64,
+}
+
+impl HashStats {
+    /// Parses the tuple returned by `ets:info(Tab, stats)`. Returns `None`
+    /// for tables that are not hash-based, for which it returns `false`.
+    pub fn from_term(term: &OwnedTerm) -> Result<Option<Self>> {
+        let Some(elements) = term.as_tuple() else {
+            return Ok(None);
+        };
+        if elements.len() < 6 {
+            return Err(Error::UnexpectedResponse(format!(
+                "Expected at least 6 elements in table stats, got: {}",
+                term
+            )));
+        }
+
+        let integer = |t: &OwnedTerm| {
+            t.as_integer()
+                .filter(|n| *n >= 0)
+                .map(|n| n as u64)
+                .ok_or_else(|| {
+                    Error::UnexpectedResponse(format!(
+                        "Expected an integer in table stats, got: {}",
+                        t
+                    ))
+                })
+        };
+        let number = |t: &OwnedTerm| {
+            t.as_float()
+                .or_else(|| t.as_integer().map(|n| n as f64))
+                .ok_or_else(|| {
+                    Error::UnexpectedResponse(format!(
+                        "Expected a number in table stats, got: {}",
+                        t
+                    ))
+                })
+        };
+
+        Ok(Some(Self {
+            buckets: integer(&elements[0])?,
+            min_chain_len: integer(&elements[1])?,
+            max_chain_len: integer(&elements[2])?,
+            avg_chain_len: number(&elements[3])?,
+            std_dev: number(&elements[4])?,
+            expected_std_dev: number(&elements[5])?,
+        }))
+    }
+
+    pub fn deviation_ratio(&self) -> Option<f64> {
+        (self.expected_std_dev > 0.0).then(|| self.std_dev / self.expected_std_dev)
+    }
+
+    pub fn is_skewed(&self, factor: f64) -> bool {
+        self.deviation_ratio().is_some_and(|ratio| ratio > factor)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TableDetails {
+    pub info: EtsTableInfo,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash_stats: Option<HashStats>,
+    /// Set when the observed deviation exceeds [`DEFAULT_SKEW_FACTOR`]
+    /// times the expected one.
+    pub skewed: bool,
+}
+
+pub async fn get_table_details(
+    remote_node: &str,
+    cookie: &str,
+    table_name: &str,
+    with_stats: bool,
+) -> Result<TableDetails> {
+    let node = create_connected_node(remote_node, cookie).await?;
+    let word_size = get_word_size(&node, remote_node).await?;
+
+    // Plain info needs no remote evaluation.
+    if !with_stats {
+        let info = node
+            .rpc_call(remote_node, "ets", "info", vec![erl_atom!(table_name)])
+            .await?;
+        let info = check_badrpc(info)?;
+        if info.is_undefined() {
+            return Err(Error::TableNotFound(table_name.to_string()));
+        }
+        return Ok(TableDetails {
+            info: parse_table_info(info, word_size)?,
+            hash_stats: None,
+            skewed: false,
+        });
+    }
+
+    let reply = eval(
+        &node,
+        remote_node,
+        TABLE_DETAILS_SOURCE,
+        vec![
+            ("Tab", erl_atom!(table_name)),
+            (
+                "WithStats",
+                erl_atom!(if with_stats { "true" } else { "false" }),
+            ),
+        ],
+    )
+    .await?;
+
+    if reply.is_undefined() {
+        return Err(Error::TableNotFound(table_name.to_string()));
+    }
+
+    let [info, stats]: [OwnedTerm; 2] = reply
+        .try_into_tuple()?
+        .try_into()
+        .map_err(|_| Error::UnexpectedResponse("Expected an {Info, Stats} tuple".to_string()))?;
+    let hash_stats = HashStats::from_term(&stats)?;
+
+    Ok(TableDetails {
+        info: parse_table_info(info, word_size)?,
+        skewed: hash_stats
+            .as_ref()
+            .is_some_and(|s| s.is_skewed(DEFAULT_SKEW_FACTOR)),
+        hash_stats,
+    })
+}
@@ -0,0 +1,197 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::{Error, Result};
+use crate::ets::{EtsTableInfo, create_connected_node, fetch_remote_tables};
+use erltf::{OwnedTerm, erl_atom};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+/// A table listing taken `elapsed_secs` after the first one.
+#[derive(Debug, Clone)]
+pub struct TableSample {
+    pub elapsed_secs: f64,
+    pub tables: Vec<EtsTableInfo>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GrowthSeries {
+    pub key: String,
+    pub table_counts: Vec<usize>,
+    pub object_counts: Vec<u64>,
+    pub count_growing: bool,
+    pub size_growing: bool,
+    pub tables_per_minute: f64,
+    pub objects_per_minute: f64,
+    /// When the table count grows, how long until the node reaches
+    /// `ets_limit` at the observed rate.
+    pub seconds_to_ets_limit: Option<f64>,
+}
+
+impl GrowthSeries {
+    pub fn is_growing(&self) -> bool {
+        self.count_growing || self.size_growing
+    }
+
+    pub fn latest_table_count(&self) -> usize {
+        self.table_counts.last().copied().unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LeakReport {
+    pub elapsed_secs: f64,
+    pub ets_limit: u64,
+    pub table_counts: Vec<usize>,
+    /// Growing owners first, then by their latest table count.
+    pub owners: Vec<GrowthSeries>,
+    /// Growing names first, then by their latest table count.
+    pub names: Vec<GrowthSeries>,
+}
+
+impl LeakReport {
+    pub fn growing_owners(&self) -> impl Iterator<Item = &GrowthSeries> {
+        self.owners.iter().filter(|o| o.is_growing())
+    }
+}
+
+/// Never decreases and ends higher than it started.
+fn grows_monotonically<T: PartialOrd + Copy>(values: &[T]) -> bool {
+    values.windows(2).all(|w| w[1] >= w[0])
+        && matches!((values.first(), values.last()), (Some(first), Some(last)) if last > first)
+}
+
+fn per_minute(first: f64, last: f64, elapsed_secs: f64) -> f64 {
+    if elapsed_secs > 0.0 {
+        (last - first) / elapsed_secs * 60.0
+    } else {
+        0.0
+    }
+}
+
+fn growth_series<F>(samples: &[TableSample], ets_limit: u64, group_key: F) -> Vec<GrowthSeries>
+where
+    F: Fn(&EtsTableInfo) -> &str,
+{
+    let mut per_key: BTreeMap<&str, (Vec<usize>, Vec<u64>)> = BTreeMap::new();
+    for (i, sample) in samples.iter().enumerate() {
+        for table in &sample.tables {
+            let (counts, objects) = per_key
+                .entry(group_key(table))
+                .or_insert_with(|| (vec![0; samples.len()], vec![0; samples.len()]));
+            counts[i] += 1;
+            objects[i] += table.size;
+        }
+    }
+
+    let elapsed_secs = samples.last().map_or(0.0, |s| s.elapsed_secs);
+    let latest_total = samples.last().map_or(0, |s| s.tables.len()) as f64;
+
+    let mut series: Vec<GrowthSeries> = per_key
+        .into_iter()
+        .map(|(key, (table_counts, object_counts))| {
+            let first_count = table_counts[0] as f64;
+            let last_count = table_counts[table_counts.len() - 1] as f64;
+            let count_growing = grows_monotonically(&table_counts);
+            let tables_per_minute = per_minute(first_count, last_count, elapsed_secs);
+            let seconds_to_ets_limit = (count_growing && tables_per_minute > 0.0)
+                .then(|| (ets_limit as f64 - latest_total).max(0.0) / tables_per_minute * 60.0);
+
+            GrowthSeries {
+                key: key.to_string(),
+                count_growing,
+                size_growing: grows_monotonically(&object_counts),
+                tables_per_minute,
+                objects_per_minute: per_minute(
+                    object_counts[0] as f64,
+                    object_counts[object_counts.len() - 1] as f64,
+                    elapsed_secs,
+                ),
+                seconds_to_ets_limit,
+                table_counts,
+                object_counts,
+            }
+        })
+        .collect();
+
+    series.sort_by(|a, b| {
+        b.is_growing()
+            .cmp(&a.is_growing())
+            .then(b.latest_table_count().cmp(&a.latest_table_count()))
+            .then(a.key.cmp(&b.key))
+    });
+    series
+}
+
+pub fn analyze_samples(samples: &[TableSample], ets_limit: u64) -> LeakReport {
+    LeakReport {
+        elapsed_secs: samples.last().map_or(0.0, |s| s.elapsed_secs),
+        ets_limit,
+        table_counts: samples.iter().map(|s| s.tables.len()).collect(),
+        owners: growth_series(samples, ets_limit, |t| &t.owner),
+        names: growth_series(samples, ets_limit, |t| &t.name),
+    }
+}
+
+/// Lists the node's tables `samples` times, `interval` apart, over a single
+/// connection, and analyzes the listings with [`analyze_samples`].
+pub async fn sample_tables(
+    remote_node: &str,
+    cookie: &str,
+    interval: Duration,
+    samples: usize,
+) -> Result<LeakReport> {
+    if samples < 2 {
+        return Err(Error::InvalidArgument(
+            "at least two samples are needed to detect growth".to_string(),
+        ));
+    }
+
+    let node = create_connected_node(remote_node, cookie).await?;
+
+    let ets_limit = match node
+        .rpc_call(
+            remote_node,
+            "erlang",
+            "system_info",
+            vec![erl_atom!("ets_limit")],
+        )
+        .await?
+    {
+        OwnedTerm::Integer(n) if n > 0 => n as u64,
+        other => {
+            return Err(Error::UnexpectedResponse(format!(
+                "Expected positive integer for ets_limit, got: {}",
+                other
+            )));
+        }
+    };
+
+    let started = Instant::now();
+    let mut taken = Vec::with_capacity(samples);
+    for i in 0..samples {
+        if i > 0 {
+            tokio::time::sleep(interval).await;
+        }
+        let elapsed_secs = started.elapsed().as_secs_f64();
+        let tables = fetch_remote_tables(&node, remote_node).await?;
+        taken.push(TableSample {
+            elapsed_secs,
+            tables: tables.into_iter().map(|t| t.info).collect(),
+        });
+    }
+
+    Ok(analyze_samples(&taken, ets_limit))
+}
@@ -12,24 +12,260 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod allocator;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod checksum;
+pub mod cluster;
+pub mod compare;
+pub mod compression;
+pub mod dets;
+pub mod diff;
 pub mod errors;
 pub mod ets;
+mod eval;
+pub mod hash_stats;
+pub mod leaks;
+pub mod lint;
+pub mod mnesia;
+pub mod owner;
+pub mod persistent_term;
+pub mod schema;
+pub mod search;
+pub mod snapshot;
+pub mod source;
+pub mod supervision;
+pub mod typed;
 
 use regex::Regex;
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 
+pub use allocator::{
+    AllocatorInstance, CarrierUsage, EtsAllocReport, ets_alloc_report, fragmentation_ratio,
+};
+pub use checksum::{NodeChecksum, TableChecksums, checksum_table};
+pub use cluster::{ClusterTableInfo, ClusterTables, NodeFailure, list_cluster_tables};
+pub use compare::{
+    ComparisonMethod, InventoryComparison, TableComparison, compare_inventories, compare_table,
+};
+pub use compression::{
+    CompressionEstimate, CompressionSample, CompressionStatus, TableCompressionEstimate,
+    estimate_compression,
+};
+pub use dets::{DetsTableInfo, dump_dets_table, list_dets_tables, lookup_dets_table};
+pub use diff::{ChangedObjects, ContentsDiff, SnapshotDiff, TableDelta, diff_snapshots};
 pub use errors::{Error, Result};
-pub use ets::{EtsTableInfo, Protection, TableType, dump_table, list_tables};
+pub use ets::{
+    EtsTableInfo, Protection, TablePage, TableType, dump_table, extrapolate_off_heap, list_tables,
+    list_tables_with_applications, list_tables_with_off_heap, lookup_table, page_table,
+};
+pub use hash_stats::{DEFAULT_SKEW_FACTOR, HashStats, TableDetails, get_table_details};
+pub use leaks::{GrowthSeries, LeakReport, TableSample, analyze_samples, sample_tables};
+pub use lint::{
+    LintFinding, LintReport, LintThresholds, OwnerProcessInfo, Severity, TableLintInput, lint_node,
+    lint_tables,
+};
+pub use mnesia::{MnesiaTableDump, MnesiaTableInfo, dump_mnesia_table, list_mnesia_tables};
+pub use owner::{
+    DEFAULT_OWNER_STATE_TIMEOUT_MS, MAX_OWNER_STATE_TIMEOUT_MS, OwnerState, OwnerStateKind,
+    get_owner_state,
+};
+pub use persistent_term::{
+    PersistentTermInfo, PersistentTerms, get_persistent_term, list_persistent_terms,
+};
+pub use schema::{
+    DEFAULT_SCHEMA_SAMPLE_SIZE, TableSchema, TermKind, TermShape, infer_table_schema,
+};
+pub use search::{
+    GrepMatch, GrepResult, KeyHit, KeySearchResult, StoppedTable, find_key, grep_tables,
+};
+pub use snapshot::{Snapshot, take_snapshot};
+pub use source::{BoxFuture, EtsSource, LiveNode};
+pub use supervision::{ProcessKind, ProcessNode, SupervisionTree, supervision_tree};
+pub use typed::{ObjectLayout, dump_table_as, from_object, from_proplist, lookup_as};
 
-pub fn filter_tables(tables: Vec<EtsTableInfo>, pattern: Option<&Regex>) -> Vec<EtsTableInfo> {
+fn filter_by_name<T>(items: Vec<T>, pattern: Option<&Regex>, name: impl Fn(&T) -> &str) -> Vec<T> {
     match pattern {
-        Some(re) => tables
-            .into_iter()
-            .filter(|t| re.is_match(&t.name))
-            .collect(),
-        None => tables,
+        Some(re) => items.into_iter().filter(|t| re.is_match(name(t))).collect(),
+        None => items,
+    }
+}
+
+pub fn filter_cluster_tables(
+    tables: Vec<ClusterTableInfo>,
+    pattern: Option<&Regex>,
+) -> Vec<ClusterTableInfo> {
+    filter_by_name(tables, pattern, |t| &t.info.name)
+}
+
+pub fn filter_dets_tables(
+    tables: Vec<DetsTableInfo>,
+    pattern: Option<&Regex>,
+) -> Vec<DetsTableInfo> {
+    filter_by_name(tables, pattern, |t| &t.name)
+}
+
+pub fn filter_mnesia_tables(
+    tables: Vec<MnesiaTableInfo>,
+    pattern: Option<&Regex>,
+) -> Vec<MnesiaTableInfo> {
+    filter_by_name(tables, pattern, |t| &t.name)
+}
+
+pub fn filter_tables(tables: Vec<EtsTableInfo>, pattern: Option<&Regex>) -> Vec<EtsTableInfo> {
+    filter_by_name(tables, pattern, |t| &t.name)
+}
+
+/// Group name for tables whose owner does not belong to an OTP application.
+pub const NO_APPLICATION: &str = "(none)";
+/// Group name for tables whose name does not match the grouping regex.
+pub const NO_MATCH: &str = "(no match)";
+/// Group that [`top_groups`] folds the remaining groups into.
+pub const OTHER_GROUP: &str = "other";
+
+#[derive(Debug, Clone)]
+pub enum GroupBy {
+    /// Requires tables listed with [`list_tables_with_applications`].
+    Application,
+    Owner,
+    Type,
+    Protection,
+    /// Groups by the first capture group of the regex matched against the
+    /// table name, or by the whole match if the regex has no groups.
+    RegexCapture(Regex),
+}
+
+impl GroupBy {
+    /// Names accepted by [`GroupBy::from_name`].
+    pub const NAMES: [&str; 5] = [
+        "application",
+        "owner",
+        "type",
+        "protection",
+        "regex-capture",
+    ];
+
+    pub fn from_name(name: &str, group_regex: Option<&str>) -> Result<Self> {
+        match (name, group_regex) {
+            ("application", None) => Ok(GroupBy::Application),
+            ("owner", None) => Ok(GroupBy::Owner),
+            ("type", None) => Ok(GroupBy::Type),
+            ("protection", None) => Ok(GroupBy::Protection),
+            ("regex-capture", Some(re)) => Regex::new(re)
+                .map(GroupBy::RegexCapture)
+                .map_err(|e| Error::InvalidPattern(e.to_string())),
+            ("regex-capture", None) => Err(Error::InvalidArgument(
+                "grouping by regex-capture requires a group regex".to_string(),
+            )),
+            (other, Some(_)) if Self::NAMES.contains(&other) => Err(Error::InvalidArgument(
+                format!("a group regex only applies to regex-capture, not {}", other),
+            )),
+            (other, _) => Err(Error::InvalidArgument(format!(
+                "unsupported grouping: {}",
+                other
+            ))),
+        }
+    }
+
+    pub fn needs_applications(&self) -> bool {
+        matches!(self, GroupBy::Application)
+    }
+
+    fn group_name(&self, table: &EtsTableInfo) -> String {
+        match self {
+            GroupBy::Application => table
+                .application
+                .clone()
+                .unwrap_or_else(|| NO_APPLICATION.to_string()),
+            GroupBy::Owner => table.owner.clone(),
+            GroupBy::Type => table.table_type.to_string(),
+            GroupBy::Protection => table.protection.to_string(),
+            GroupBy::RegexCapture(re) => re
+                .captures(&table.name)
+                .and_then(|c| c.get(1).or_else(|| c.get(0)))
+                .map(|m| m.as_str().to_string())
+                .unwrap_or_else(|| NO_MATCH.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TableGroup {
+    pub name: String,
+    pub table_count: usize,
+    pub object_count: u64,
+    pub memory_bytes: u64,
+    /// Share of the memory used by all grouped tables, from 0 to 100.
+    pub memory_percentage: f64,
+}
+
+impl TableGroup {
+    fn empty(name: String) -> Self {
+        TableGroup {
+            name,
+            table_count: 0,
+            object_count: 0,
+            memory_bytes: 0,
+            memory_percentage: 0.0,
+        }
+    }
+
+    fn absorb(&mut self, other: &TableGroup) {
+        self.table_count += other.table_count;
+        self.object_count += other.object_count;
+        self.memory_bytes += other.memory_bytes;
+        self.memory_percentage += other.memory_percentage;
+    }
+}
+
+/// Aggregates tables into groups, largest memory users first.
+pub fn group_tables(tables: &[EtsTableInfo], group_by: &GroupBy) -> Vec<TableGroup> {
+    let total_memory: u64 = tables.iter().map(|t| t.memory_bytes).sum();
+
+    let mut groups: BTreeMap<String, TableGroup> = BTreeMap::new();
+    for table in tables {
+        let name = group_by.group_name(table);
+        let group = groups
+            .entry(name.clone())
+            .or_insert_with(|| TableGroup::empty(name));
+        group.table_count += 1;
+        group.object_count += table.size;
+        group.memory_bytes += table.memory_bytes;
+    }
+
+    let mut groups: Vec<TableGroup> = groups
+        .into_values()
+        .map(|mut g| {
+            if total_memory > 0 {
+                g.memory_percentage = g.memory_bytes as f64 * 100.0 / total_memory as f64;
+            }
+            g
+        })
+        .collect();
+    groups.sort_by(|a, b| {
+        b.memory_bytes
+            .cmp(&a.memory_bytes)
+            .then(a.name.cmp(&b.name))
+    });
+    groups
+}
+
+/// Keeps the first `top` groups (as sorted by [`group_tables`]) and folds
+/// the rest into a single trailing [`OTHER_GROUP`] row.
+pub fn top_groups(mut groups: Vec<TableGroup>, top: usize) -> Vec<TableGroup> {
+    if groups.len() <= top {
+        return groups;
+    }
+
+    let mut other = TableGroup::empty(OTHER_GROUP.to_string());
+    for group in groups.drain(top..) {
+        other.absorb(&group);
     }
+    groups.push(other);
+    groups
 }
 
 pub fn get_erlang_cookie(
@@ -0,0 +1,318 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::{Error, Result};
+use crate::ets::{EtsTableInfo, Protection, TableType, create_connected_node, parse_table_info};
+use crate::eval::eval;
+use erltf::OwnedTerm;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+const LINT_SOURCE: &str = r#"
+Infos = [I || T <- ets:all(), I <- [ets:info(T)], I =/= undefined],
+Owners = lists:usort([proplists:get_value(owner, I) || I <- Infos]),
+{erlang:system_info(ets_count),
+ erlang:system_info(ets_limit),
+ erlang:system_info(wordsize),
+ Infos,
+ [{P, erlang:process_info(P, [message_queue_len, registered_name])} || P <- Owners]}.
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Info,
+    Warning,
+    Critical,
+}
+
+impl Severity {
+    pub const NAMES: [&'static str; 3] = ["info", "warning", "critical"];
+
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name {
+            "info" => Ok(Severity::Info),
+            "warning" => Ok(Severity::Warning),
+            "critical" => Ok(Severity::Critical),
+            other => Err(Error::InvalidArgument(format!(
+                "unknown severity '{}', expected one of: {}",
+                other,
+                Self::NAMES.join(", ")
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Critical => "critical",
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LintFinding {
+    pub severity: Severity,
+    pub rule: &'static str,
+    /// `None` for node-wide findings.
+    pub table: Option<String>,
+    pub explanation: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct LintThresholds {
+    pub large_table_size: u64,
+    /// Owner message queue length that is reported as a warning.
+    /// Ten times this value is reported as critical.
+    pub message_queue_len: u64,
+    /// Fraction of `ets_limit` in use that is reported as a warning.
+    pub ets_limit_warning: f64,
+    /// Fraction of `ets_limit` in use that is reported as critical.
+    pub ets_limit_critical: f64,
+}
+
+impl Default for LintThresholds {
+    fn default() -> Self {
+        Self {
+            large_table_size: 100_000,
+            message_queue_len: 10_000,
+            ets_limit_warning: 0.8,
+            ets_limit_critical: 0.95,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OwnerProcessInfo {
+    pub message_queue_len: u64,
+    pub registered_name: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TableLintInput {
+    pub info: EtsTableInfo,
+    pub write_concurrency: bool,
+    pub read_concurrency: bool,
+    pub has_heir: bool,
+    /// `None` when the owner has exited since the table list was taken.
+    pub owner: Option<OwnerProcessInfo>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LintReport {
+    pub findings: Vec<LintFinding>,
+    pub tables_checked: usize,
+    pub ets_count: u64,
+    pub ets_limit: u64,
+}
+
+impl LintReport {
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.findings.iter().map(|f| f.severity).max()
+    }
+}
+
+fn lint_table(table: &TableLintInput, thresholds: &LintThresholds) -> Vec<LintFinding> {
+    let info = &table.info;
+    let is_public = info.protection == Protection::Public;
+    let mut findings = Vec::new();
+    let mut finding = |severity, rule, explanation| {
+        findings.push(LintFinding {
+            severity,
+            rule,
+            table: Some(info.name.clone()),
+            explanation,
+        })
+    };
+
+    if info.table_type == TableType::Set
+        && is_public
+        && !table.write_concurrency
+        && info.size >= thresholds.large_table_size
+    {
+        finding(
+            Severity::Warning,
+            "set-without-write-concurrency",
+            format!(
+                "public set with {} objects has write_concurrency disabled, so concurrent writers serialize on a single table lock",
+                info.size
+            ),
+        );
+    }
+
+    if table.read_concurrency && table.write_concurrency && is_public {
+        finding(
+            Severity::Info,
+            "read-concurrency-on-write-heavy-table",
+            "read_concurrency is enabled on a public table tuned for concurrent writes; switching between reads and writes is more expensive with it, so keep it only if reads dominate".to_string(),
+        );
+    }
+
+    if let Some(owner) = &table.owner {
+        let queue = owner.message_queue_len;
+        if queue >= thresholds.message_queue_len {
+            let severity = if queue >= thresholds.message_queue_len.saturating_mul(10) {
+                Severity::Critical
+            } else {
+                Severity::Warning
+            };
+            finding(
+                severity,
+                "owner-message-queue",
+                format!(
+                    "owner {} has {} messages queued; operations that go through it will lag",
+                    info.owner, queue
+                ),
+            );
+        }
+
+        if is_public && !table.has_heir && owner.registered_name.is_none() {
+            finding(
+                Severity::Warning,
+                "public-table-without-heir",
+                format!(
+                    "public table is owned by the unregistered process {} and has no heir, so it is deleted together with its data when that process exits",
+                    info.owner
+                ),
+            );
+        }
+    }
+
+    findings
+}
+
+/// Applies the lint rules to already collected table and node data.
+/// Findings are sorted by severity (most severe first), then by table name.
+pub fn lint_tables(
+    tables: &[TableLintInput],
+    ets_count: u64,
+    ets_limit: u64,
+    thresholds: &LintThresholds,
+) -> Vec<LintFinding> {
+    let mut findings: Vec<LintFinding> = tables
+        .iter()
+        .flat_map(|t| lint_table(t, thresholds))
+        .collect();
+
+    if ets_limit > 0 {
+        let ratio = ets_count as f64 / ets_limit as f64;
+        let severity = if ratio >= thresholds.ets_limit_critical {
+            Some(Severity::Critical)
+        } else if ratio >= thresholds.ets_limit_warning {
+            Some(Severity::Warning)
+        } else {
+            None
+        };
+        if let Some(severity) = severity {
+            findings.push(LintFinding {
+                severity,
+                rule: "ets-limit",
+                table: None,
+                explanation: format!(
+                    "{} of {} ETS tables allowed by ets_limit are in use ({:.0}%); creating a table fails with system_limit once it is reached",
+                    ets_count,
+                    ets_limit,
+                    ratio * 100.0
+                ),
+            });
+        }
+    }
+
+    findings.sort_by(|a, b| b.severity.cmp(&a.severity).then(a.table.cmp(&b.table)));
+    findings
+}
+
+fn parse_owner_info(info: &OwnedTerm) -> Option<OwnerProcessInfo> {
+    let message_queue_len = info.proplist_get_i64("message_queue_len")?;
+    let registered_name = info
+        .proplist_get_atom_key("registered_name")
+        .and_then(|n| n.as_atom())
+        .map(|n| n.to_string());
+
+    Some(OwnerProcessInfo {
+        message_queue_len: message_queue_len.max(0) as u64,
+        registered_name,
+    })
+}
+
+fn expect_u64(term: &OwnedTerm, what: &str) -> Result<u64> {
+    match term {
+        OwnedTerm::Integer(n) if *n >= 0 => Ok(*n as u64),
+        other => Err(Error::UnexpectedResponse(format!(
+            "Expected non-negative integer for {}, got: {}",
+            what, other
+        ))),
+    }
+}
+
+pub async fn lint_node(
+    remote_node: &str,
+    cookie: &str,
+    thresholds: &LintThresholds,
+) -> Result<LintReport> {
+    let node = create_connected_node(remote_node, cookie).await?;
+
+    let reply = eval(&node, remote_node, LINT_SOURCE, vec![]).await?;
+    let [ets_count, ets_limit, word_size, infos, owners]: [OwnedTerm; 5] =
+        reply.try_into_tuple()?.try_into().map_err(|_| {
+            Error::UnexpectedResponse(
+                "Expected an {EtsCount, EtsLimit, WordSize, Infos, Owners} tuple".to_string(),
+            )
+        })?;
+    let ets_count = expect_u64(&ets_count, "ets_count")?;
+    let ets_limit = expect_u64(&ets_limit, "ets_limit")?;
+    let word_size = expect_u64(&word_size, "wordsize")?;
+
+    let mut owner_infos = BTreeMap::new();
+    for pair in owners.try_into_list()? {
+        if let Some([pid, info]) = pair.as_tuple() {
+            owner_infos.insert(pid.clone(), parse_owner_info(info));
+        }
+    }
+
+    let mut tables = Vec::new();
+    for info_list in infos.try_into_list()? {
+        let owner = info_list
+            .proplist_get_atom_key("owner")
+            .and_then(|pid| owner_infos.get(pid).cloned())
+            .flatten();
+        let write_concurrency = info_list
+            .proplist_get_atom_key("write_concurrency")
+            .is_some_and(|v| !v.is_atom_with_name("false"));
+        let read_concurrency = info_list.proplist_get_bool_or("read_concurrency", false);
+        let has_heir = info_list
+            .proplist_get_atom_key("heir")
+            .is_some_and(|h| !h.is_atom_with_name("none"));
+
+        tables.push(TableLintInput {
+            info: parse_table_info(info_list, word_size)?,
+            write_concurrency,
+            read_concurrency,
+            has_heir,
+            owner,
+        });
+    }
+
+    Ok(LintReport {
+        findings: lint_tables(&tables, ets_count, ets_limit, thresholds),
+        tables_checked: tables.len(),
+        ets_count,
+        ets_limit,
+    })
+}
@@ -0,0 +1,212 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::{Error, Result};
+use crate::ets::{create_connected_node, get_word_size};
+use crate::eval::eval;
+use crate::search::render_entry;
+use edp_node::Node;
+use erltf::{OwnedTerm, erl_atom};
+use serde::{Deserialize, Serialize};
+
+const TABLE_INFO_SOURCE: &str = r#"
+Items = [storage_type, ram_copies, disc_copies, disc_only_copies,
+         attributes, record_name, size, memory],
+[{T, [{I, mnesia:table_info(T, I)} || I <- Items]} || T <- Tabs].
+"#;
+
+const DUMP_SOURCE: &str = r#"
+mnesia:dirty_match_object(Tab, mnesia:table_info(Tab, wild_pattern)).
+"#;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MnesiaTableInfo {
+    pub name: String,
+    /// Storage type on the queried node: `ram_copies`, `disc_copies`,
+    /// `disc_only_copies` or `unknown` when it holds no replica.
+    pub storage_type: String,
+    pub ram_copies: Vec<String>,
+    pub disc_copies: Vec<String>,
+    pub disc_only_copies: Vec<String>,
+    pub record_name: String,
+    pub attributes: Vec<String>,
+    pub size: u64,
+    pub memory_bytes: u64,
+}
+
+impl MnesiaTableInfo {
+    /// Renders a record as `#record_name{attr = Value, ...}`, falling back
+    /// to plain term syntax when it does not have the expected shape.
+    pub fn render_record(&self, record: &OwnedTerm) -> String {
+        let fields = match record.as_tuple() {
+            Some([tag, fields @ ..])
+                if tag.is_atom_with_name(&self.record_name)
+                    && fields.len() == self.attributes.len() =>
+            {
+                fields
+            }
+            _ => return render_entry(record),
+        };
+
+        let rendered: Vec<String> = self
+            .attributes
+            .iter()
+            .zip(fields)
+            .map(|(attribute, value)| format!("{} = {}", attribute, render_entry(value)))
+            .collect();
+        format!("#{}{{{}}}", self.record_name, rendered.join(", "))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MnesiaTableDump {
+    pub info: MnesiaTableInfo,
+    pub records: Vec<OwnedTerm>,
+}
+
+impl MnesiaTableDump {
+    pub fn rendered_records(&self) -> Vec<String> {
+        self.records
+            .iter()
+            .map(|r| self.info.render_record(r))
+            .collect()
+    }
+}
+
+fn atom_names(term: &OwnedTerm) -> Vec<String> {
+    term.as_list_or_empty()
+        .iter()
+        .map(|t| t.to_string())
+        .collect()
+}
+
+fn non_negative(term: Option<&OwnedTerm>, item: &str) -> Result<u64> {
+    match term {
+        Some(OwnedTerm::Integer(n)) if *n >= 0 => Ok(*n as u64),
+        other => Err(Error::UnexpectedResponse(format!(
+            "Expected non-negative integer for {}, got: {:?}",
+            item, other
+        ))),
+    }
+}
+
+pub(crate) fn parse_mnesia_table_info(
+    name: String,
+    items: &OwnedTerm,
+    word_size: u64,
+) -> Result<MnesiaTableInfo> {
+    let storage_type = items
+        .proplist_get_atom_string("storage_type")
+        .unwrap_or_else(|| "unknown".to_string());
+    let memory = non_negative(items.proplist_get_atom_key("memory"), "memory")?;
+    // disc_only_copies tables live in DETS, which reports memory in bytes
+    let memory_bytes = if storage_type == "disc_only_copies" {
+        memory
+    } else {
+        memory * word_size
+    };
+    let list_of = |item: &str| {
+        items
+            .proplist_get_atom_key(item)
+            .map(atom_names)
+            .unwrap_or_default()
+    };
+
+    Ok(MnesiaTableInfo {
+        record_name: items
+            .proplist_get_atom_string("record_name")
+            .unwrap_or_else(|| name.clone()),
+        name,
+        storage_type,
+        ram_copies: list_of("ram_copies"),
+        disc_copies: list_of("disc_copies"),
+        disc_only_copies: list_of("disc_only_copies"),
+        attributes: list_of("attributes"),
+        size: non_negative(items.proplist_get_atom_key("size"), "size")?,
+        memory_bytes,
+    })
+}
+
+async fn fetch_table_infos(
+    node: &Node,
+    remote_node: &str,
+    tables: OwnedTerm,
+) -> Result<Vec<MnesiaTableInfo>> {
+    let word_size = get_word_size(node, remote_node).await?;
+    let infos = eval(node, remote_node, TABLE_INFO_SOURCE, vec![("Tabs", tables)]).await?;
+
+    let mut tables = Vec::new();
+    for pair in infos.try_into_list()? {
+        let [name, items]: [OwnedTerm; 2] = pair
+            .try_into_tuple()?
+            .try_into()
+            .map_err(|_| Error::UnexpectedResponse("Expected a {Table, Info} tuple".to_string()))?;
+        tables.push(parse_mnesia_table_info(
+            name.to_string(),
+            &items,
+            word_size,
+        )?);
+    }
+    Ok(tables)
+}
+
+/// Includes the `schema` table itself.
+pub async fn list_mnesia_tables(remote_node: &str, cookie: &str) -> Result<Vec<MnesiaTableInfo>> {
+    let node = create_connected_node(remote_node, cookie).await?;
+    let tables = eval(&node, remote_node, "mnesia:system_info(tables).", vec![]).await?;
+
+    let mut tables = fetch_table_infos(&node, remote_node, tables).await?;
+    tables.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(tables)
+}
+
+pub async fn dump_mnesia_table(
+    remote_node: &str,
+    cookie: &str,
+    table_name: &str,
+) -> Result<MnesiaTableDump> {
+    let node = create_connected_node(remote_node, cookie).await?;
+
+    let known = eval(
+        &node,
+        remote_node,
+        "lists:member(Tab, mnesia:system_info(tables)).",
+        vec![("Tab", erl_atom!(table_name))],
+    )
+    .await?;
+    if !known.is_true() {
+        return Err(Error::TableNotFound(table_name.to_string()));
+    }
+
+    let info = fetch_table_infos(
+        &node,
+        remote_node,
+        OwnedTerm::List(vec![erl_atom!(table_name)]),
+    )
+    .await?
+    .pop()
+    .ok_or_else(|| Error::TableNotFound(table_name.to_string()))?;
+
+    let records = eval(
+        &node,
+        remote_node,
+        DUMP_SOURCE,
+        vec![("Tab", erl_atom!(table_name))],
+    )
+    .await?
+    .try_into_list()?;
+
+    Ok(MnesiaTableDump { info, records })
+}
@@ -0,0 +1,140 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::{Error, Result};
+use crate::ets::create_connected_node;
+use crate::eval::eval_with_timeout;
+use erltf::{OwnedTerm, erl_atom, erl_int};
+use std::time::Duration;
+
+pub const DEFAULT_OWNER_STATE_TIMEOUT_MS: u64 = 5_000;
+pub const MAX_OWNER_STATE_TIMEOUT_MS: u64 = 60_000;
+
+/// Extra time the RPC gets so that the `sys` call times out first.
+const RPC_TIMEOUT_MARGIN: Duration = Duration::from_secs(5);
+
+const OWNER_STATE_SOURCE: &str = r#"
+case ets:info(Tab, owner) of
+    undefined -> undefined;
+    Owner ->
+        try
+            Value = case Kind of
+                        state -> sys:get_state(Owner, Timeout);
+                        status -> sys:get_status(Owner, Timeout)
+                    end,
+            {ok, Owner, Value}
+        catch
+            exit:{timeout, _} -> {error, Owner, timeout};
+            exit:{noproc, _} -> {error, Owner, noproc};
+            Class:Reason -> {error, Owner, {Class, Reason}}
+        end
+end.
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OwnerStateKind {
+    /// `sys:get_state/2`: the callback module state.
+    #[default]
+    State,
+    /// `sys:get_status/2`: the full status, including the process dictionary.
+    Status,
+}
+
+impl OwnerStateKind {
+    pub const NAMES: [&'static str; 2] = ["state", "status"];
+
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name {
+            "state" => Ok(OwnerStateKind::State),
+            "status" => Ok(OwnerStateKind::Status),
+            other => Err(Error::InvalidArgument(format!(
+                "unknown owner state kind '{}', expected one of: {}",
+                other,
+                Self::NAMES.join(", ")
+            ))),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            OwnerStateKind::State => "state",
+            OwnerStateKind::Status => "status",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OwnerState {
+    pub table_name: String,
+    pub owner: String,
+    pub kind: OwnerStateKind,
+    pub value: OwnedTerm,
+}
+
+pub async fn get_owner_state(
+    remote_node: &str,
+    cookie: &str,
+    table_name: &str,
+    kind: OwnerStateKind,
+    timeout_ms: u64,
+) -> Result<OwnerState> {
+    if !(1..=MAX_OWNER_STATE_TIMEOUT_MS).contains(&timeout_ms) {
+        return Err(Error::InvalidArgument(format!(
+            "the owner state timeout must be between 1 and {} ms",
+            MAX_OWNER_STATE_TIMEOUT_MS
+        )));
+    }
+    let node = create_connected_node(remote_node, cookie).await?;
+
+    let result = eval_with_timeout(
+        &node,
+        remote_node,
+        OWNER_STATE_SOURCE,
+        vec![
+            ("Tab", erl_atom!(table_name)),
+            ("Kind", erl_atom!(kind.name())),
+            ("Timeout", erl_int!(timeout_ms as i64)),
+        ],
+        Duration::from_millis(timeout_ms) + RPC_TIMEOUT_MARGIN,
+    )
+    .await?;
+
+    if result.is_undefined() {
+        return Err(Error::TableNotFound(table_name.to_string()));
+    }
+
+    let [tag, owner, value]: [OwnedTerm; 3] =
+        result.try_into_tuple()?.try_into().map_err(|_| {
+            Error::UnexpectedResponse("Expected an {ok | error, Owner, Value} tuple".to_string())
+        })?;
+    let owner = owner.to_string();
+
+    if !tag.is_atom_with_name("ok") {
+        let reason = if value.is_atom_with_name("timeout") {
+            format!("{} did not reply within {} ms", owner, timeout_ms)
+        } else if value.is_atom_with_name("noproc") {
+            format!("{} is no longer running", owner)
+        } else {
+            format!("{} could not be inspected: {}", owner, value)
+        };
+        return Err(Error::OwnerState(reason));
+    }
+
+    Ok(OwnerState {
+        table_name: table_name.to_string(),
+        owner,
+        kind,
+        value,
+    })
+}
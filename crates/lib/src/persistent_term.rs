@@ -0,0 +1,132 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::{Error, Result};
+use crate::ets::{create_connected_node, get_word_size};
+use crate::eval::{eval, parse_term};
+use crate::search::render_entry;
+use erltf::{OwnedTerm, erl_atom};
+use erltf_serde::from_term;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Collects keys with value sizes rather than values so that only
+/// the keys travel over distribution.
+const LIST_SOURCE: &str = r#"
+{persistent_term:info(),
+ [{K, erts_debug:flat_size(V)} || {K, V} <- persistent_term:get()]}.
+"#;
+
+const GET_SOURCE: &str = r#"
+case persistent_term:get(Key, Missing) of
+    Missing -> not_found;
+    Value -> {found, Value}
+end.
+"#;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PersistentTermInfo {
+    pub key: String,
+    /// Estimated from the flat size of the value.
+    pub memory_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PersistentTerms {
+    pub terms: Vec<PersistentTermInfo>,
+    pub count: u64,
+    pub total_memory_bytes: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct PersistentTermTotals {
+    count: u64,
+    memory: u64,
+}
+
+/// Largest values first. `pattern` is matched against the rendered key.
+pub async fn list_persistent_terms(
+    remote_node: &str,
+    cookie: &str,
+    pattern: Option<&Regex>,
+) -> Result<PersistentTerms> {
+    let node = create_connected_node(remote_node, cookie).await?;
+    let word_size = get_word_size(&node, remote_node).await?;
+
+    let [totals, sizes]: [OwnedTerm; 2] = eval(&node, remote_node, LIST_SOURCE, vec![])
+        .await?
+        .try_into_tuple()?
+        .try_into()
+        .map_err(|_| Error::UnexpectedResponse("Expected an {Info, Sizes} tuple".to_string()))?;
+    let totals: PersistentTermTotals = from_term(&totals)?;
+
+    let mut terms = Vec::new();
+    for pair in sizes.try_into_list()? {
+        let [key, words]: [OwnedTerm; 2] = pair.try_into_tuple()?.try_into().map_err(|_| {
+            Error::UnexpectedResponse("Expected a {Key, FlatSize} tuple".to_string())
+        })?;
+        let key = render_entry(&key);
+        if pattern.is_some_and(|re| !re.is_match(&key)) {
+            continue;
+        }
+        let words = match words {
+            OwnedTerm::Integer(n) if n >= 0 => n as u64,
+            other => {
+                return Err(Error::UnexpectedResponse(format!(
+                    "Expected non-negative integer for flat size, got: {}",
+                    other
+                )));
+            }
+        };
+        terms.push(PersistentTermInfo {
+            key,
+            memory_bytes: words * word_size,
+        });
+    }
+
+    terms.sort_by(|a, b| b.memory_bytes.cmp(&a.memory_bytes).then(a.key.cmp(&b.key)));
+
+    Ok(PersistentTerms {
+        terms,
+        count: totals.count,
+        total_memory_bytes: totals.memory,
+    })
+}
+
+pub async fn get_persistent_term(remote_node: &str, cookie: &str, key: &str) -> Result<OwnedTerm> {
+    let node = create_connected_node(remote_node, cookie).await?;
+    let parsed = parse_term(&node, remote_node, key).await?;
+
+    let result = eval(
+        &node,
+        remote_node,
+        GET_SOURCE,
+        vec![
+            ("Key", parsed),
+            ("Missing", erl_atom!("$ets_browser_missing")),
+        ],
+    )
+    .await?;
+
+    if result.is_atom_with_name("not_found") {
+        return Err(Error::PersistentTermNotFound(key.to_string()));
+    }
+    match result.try_into_tuple()?.as_slice() {
+        [tag, value] if tag.is_atom_with_name("found") => Ok(value.clone()),
+        other => Err(Error::UnexpectedResponse(format!(
+            "Unexpected persistent term lookup result: {:?}",
+            other
+        ))),
+    }
+}
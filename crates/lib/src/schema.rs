@@ -0,0 +1,675 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::{Error, Result};
+use crate::ets::create_connected_node;
+use crate::eval::eval;
+use erltf::{OwnedTerm, erl_atom, erl_int};
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+pub const DEFAULT_SCHEMA_SAMPLE_SIZE: usize = 1000;
+/// Atom-only positions with at most this many distinct values are enum-like.
+pub const MAX_ENUM_VALUES: usize = 16;
+/// Maps with more distinct keys than this are not broken down by key.
+pub const MAX_MAP_KEYS: usize = 64;
+
+const SAMPLE_SOURCE: &str = r#"
+case ets:info(Tab, size) of
+    undefined -> undefined;
+    Size ->
+        Objects = case Limit of
+                      all -> ets:tab2list(Tab);
+                      _ ->
+                          case ets:select(Tab, [{'_', [], ['$_']}], Limit) of
+                              '$end_of_table' -> [];
+                              {Sampled, _} -> Sampled
+                          end
+                  end,
+        {Size, Objects}
+end.
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TermKind {
+    Atom,
+    Boolean,
+    Integer,
+    Float,
+    Binary,
+    String,
+    List,
+    Tuple,
+    Map,
+    Pid,
+    Port,
+    Reference,
+    Fun,
+    Bitstring,
+}
+
+impl TermKind {
+    /// `undefined` is not a kind: it counts as a missing value.
+    pub fn of(term: &OwnedTerm) -> Self {
+        match term {
+            OwnedTerm::Atom(atom) if matches!(atom.as_str(), "true" | "false") => TermKind::Boolean,
+            OwnedTerm::Atom(_) => TermKind::Atom,
+            OwnedTerm::Integer(_) | OwnedTerm::BigInt(_) => TermKind::Integer,
+            OwnedTerm::Float(_) => TermKind::Float,
+            OwnedTerm::Binary(_) => TermKind::Binary,
+            OwnedTerm::BitBinary { .. } => TermKind::Bitstring,
+            OwnedTerm::String(_) => TermKind::String,
+            OwnedTerm::List(_) | OwnedTerm::ImproperList { .. } | OwnedTerm::Nil => TermKind::List,
+            OwnedTerm::Tuple(_) => TermKind::Tuple,
+            OwnedTerm::Map(_) => TermKind::Map,
+            OwnedTerm::Pid(_) => TermKind::Pid,
+            OwnedTerm::Port(_) => TermKind::Port,
+            OwnedTerm::Reference(_) => TermKind::Reference,
+            OwnedTerm::ExternalFun(_) | OwnedTerm::InternalFun(_) => TermKind::Fun,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TermKind::Atom => "atom",
+            TermKind::Boolean => "boolean",
+            TermKind::Integer => "integer",
+            TermKind::Float => "float",
+            TermKind::Binary => "binary",
+            TermKind::String => "charlist",
+            TermKind::List => "list",
+            TermKind::Tuple => "tuple",
+            TermKind::Map => "map",
+            TermKind::Pid => "pid",
+            TermKind::Port => "port",
+            TermKind::Reference => "reference",
+            TermKind::Fun => "fun",
+            TermKind::Bitstring => "bitstring",
+        }
+    }
+}
+
+impl fmt::Display for TermKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TermShape {
+    /// Values observed at this position, `undefined` included.
+    pub observed: u64,
+    pub undefined: u64,
+    pub kinds: BTreeMap<TermKind, u64>,
+    /// Atom values and their counts, dropped once there are more than
+    /// [`MAX_ENUM_VALUES`] of them.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub atom_values: BTreeMap<String, u64>,
+    pub atom_values_truncated: bool,
+    pub non_utf8_binaries: bool,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub tuple_arities: BTreeMap<usize, u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tuple_elements: Vec<TermShape>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list_elements: Option<Box<TermShape>>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub map_keys: BTreeMap<String, TermShape>,
+    pub map_keys_truncated: bool,
+    pub non_atom_map_keys: bool,
+}
+
+impl TermShape {
+    pub fn observe(&mut self, term: &OwnedTerm) {
+        self.observed += 1;
+        if term.is_undefined() {
+            self.undefined += 1;
+            return;
+        }
+
+        let kind = TermKind::of(term);
+        *self.kinds.entry(kind).or_default() += 1;
+
+        match term {
+            OwnedTerm::Atom(atom) if kind == TermKind::Atom => self.observe_atom(atom.as_str()),
+            OwnedTerm::Binary(bytes) => {
+                self.non_utf8_binaries |= std::str::from_utf8(bytes).is_err();
+            }
+            OwnedTerm::List(elements) | OwnedTerm::ImproperList { elements, .. } => {
+                let shape = self.list_elements.get_or_insert_with(Box::default);
+                elements.iter().for_each(|e| shape.observe(e));
+            }
+            OwnedTerm::Nil => {
+                self.list_elements.get_or_insert_with(Box::default);
+            }
+            OwnedTerm::Tuple(elements) => {
+                *self.tuple_arities.entry(elements.len()).or_default() += 1;
+                if self.tuple_elements.len() < elements.len() {
+                    self.tuple_elements
+                        .resize_with(elements.len(), TermShape::default);
+                }
+                for (shape, element) in self.tuple_elements.iter_mut().zip(elements) {
+                    shape.observe(element);
+                }
+            }
+            OwnedTerm::Map(entries) => {
+                for (key, value) in entries {
+                    let name = match key {
+                        OwnedTerm::Atom(atom) => atom.as_str().to_string(),
+                        other => {
+                            self.non_atom_map_keys = true;
+                            match other.as_binary().map(std::str::from_utf8) {
+                                Some(Ok(text)) => text.to_string(),
+                                _ => other.to_string(),
+                            }
+                        }
+                    };
+                    if !self.map_keys.contains_key(&name) && self.map_keys.len() >= MAX_MAP_KEYS {
+                        self.map_keys_truncated = true;
+                        continue;
+                    }
+                    self.map_keys.entry(name).or_default().observe(value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn observe_atom(&mut self, name: &str) {
+        if self.atom_values_truncated {
+            return;
+        }
+        *self.atom_values.entry(name.to_string()).or_default() += 1;
+        if self.atom_values.len() > MAX_ENUM_VALUES {
+            self.atom_values.clear();
+            self.atom_values_truncated = true;
+        }
+    }
+
+    pub fn count(&self, kind: TermKind) -> u64 {
+        self.kinds.get(&kind).copied().unwrap_or(0)
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.undefined > 0
+    }
+
+    /// Share of observed values that were `undefined`, from 0.0 to 1.0.
+    pub fn undefined_ratio(&self) -> f64 {
+        if self.observed == 0 {
+            0.0
+        } else {
+            self.undefined as f64 / self.observed as f64
+        }
+    }
+
+    pub fn enum_values(&self) -> Option<Vec<&str>> {
+        let atoms = self.count(TermKind::Atom);
+        let atoms_only = self.kinds.len() == 1 && atoms > 0;
+        (atoms_only && !self.atom_values_truncated && atoms > self.atom_values.len() as u64)
+            .then(|| self.atom_values.keys().map(String::as_str).collect())
+    }
+
+    /// Keys present in every observed map.
+    pub fn required_map_keys(&self) -> Vec<&str> {
+        let maps = self.count(TermKind::Map);
+        self.map_keys
+            .iter()
+            .filter(|(_, shape)| shape.observed == maps)
+            .map(|(key, _)| key.as_str())
+            .collect()
+    }
+
+    /// A one-line description such as `integer 90%, undefined 10%`.
+    pub fn summary(&self) -> String {
+        if self.observed == 0 {
+            return "never observed".to_string();
+        }
+
+        let share = |count: u64| count as f64 * 100.0 / self.observed as f64;
+        let mut parts: Vec<String> = self
+            .kinds
+            .iter()
+            .map(|(kind, count)| {
+                if *count == self.observed {
+                    kind.to_string()
+                } else {
+                    format!("{} {:.0}%", kind, share(*count))
+                }
+            })
+            .collect();
+        if self.undefined == self.observed {
+            parts.push("undefined".to_string());
+        } else if self.undefined > 0 {
+            parts.push(format!("undefined {:.0}%", share(self.undefined)));
+        }
+
+        let mut summary = parts.join(", ");
+        match self.enum_values() {
+            Some(values) if values.len() == 1 => {
+                summary.push_str(&format!(", always '{}'", values[0]))
+            }
+            Some(values) => summary.push_str(&format!(", one of: {}", values.join(", "))),
+            None => {}
+        }
+        if self.non_utf8_binaries {
+            summary.push_str(", some binaries are not UTF-8");
+        }
+        summary
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TableSchema {
+    pub table: String,
+    pub total_objects: u64,
+    pub sampled_objects: u64,
+    pub object: TermShape,
+}
+
+impl TableSchema {
+    pub fn infer(table: &str, total_objects: u64, objects: &[OwnedTerm]) -> Self {
+        let mut object = TermShape::default();
+        objects.iter().for_each(|o| object.observe(o));
+
+        Self {
+            table: table.to_string(),
+            total_objects,
+            sampled_objects: objects.len() as u64,
+            object,
+        }
+    }
+
+    pub fn arity(&self) -> Option<usize> {
+        self.object
+            .tuple_arities
+            .iter()
+            .max_by_key(|(arity, count)| (**count, std::cmp::Reverse(**arity)))
+            .map(|(arity, _)| *arity)
+    }
+
+    /// A JSON Schema (draft 2020-12) of the objects. Tuples become arrays,
+    /// atoms, binaries and charlists become strings and `undefined` is `null`.
+    pub fn to_json_schema(&self) -> Value {
+        let mut schema = match json_schema(&self.object) {
+            Value::Object(map) => map,
+            _ => Map::new(),
+        };
+        schema.insert(
+            "$schema".to_string(),
+            json!("https://json-schema.org/draft/2020-12/schema"),
+        );
+        schema.insert("title".to_string(), json!(self.table));
+        schema.insert(
+            "description".to_string(),
+            json!(format!(
+                "Inferred from {} of {} objects",
+                self.sampled_objects, self.total_objects
+            )),
+        );
+        Value::Object(schema)
+    }
+
+    /// Rust types that `erltf_serde::from_term` can deserialize the objects
+    /// into. Terms that cannot be deserialized, such as pids, and positions
+    /// with mixed kinds are skipped with `IgnoredAny`.
+    pub fn to_rust(&self) -> String {
+        let mut generator = RustGenerator::default();
+        let root = generator.reserve_name(&type_name(&self.table, "Table"));
+        let arity = self.arity().unwrap_or(0);
+
+        let mut fields = Vec::with_capacity(arity);
+        for (i, shape) in self.object.tuple_elements.iter().take(arity).enumerate() {
+            let hint = format!("{}Field{}", root, i + 1);
+            fields.push(format!(
+                "    /// {}\n    pub {},",
+                shape.summary(),
+                generator.rust_type(shape, &hint)
+            ));
+        }
+
+        let mut doc = format!(
+            "/// Objects of `{}`, inferred from {} of {} objects.",
+            self.table, self.sampled_objects, self.total_objects
+        );
+        if self.object.tuple_arities.len() > 1 {
+            let arities: Vec<String> = self
+                .object
+                .tuple_arities
+                .keys()
+                .map(|a| a.to_string())
+                .collect();
+            doc.push_str(&format!(
+                "\n/// Objects of arity {} were observed, this type covers arity {}.",
+                arities.join(", "),
+                arity
+            ));
+        }
+        let definition = if fields.is_empty() {
+            format!(
+                "{}\n#[derive(Debug, Clone, Deserialize)]\npub struct {};",
+                doc, root
+            )
+        } else {
+            format!(
+                "{}\n#[derive(Debug, Clone, Deserialize)]\npub struct {}(\n{}\n);",
+                doc,
+                root,
+                fields.join("\n")
+            )
+        };
+        generator.definitions.insert(0, definition);
+
+        format!(
+            "use serde::Deserialize;\n\n{}\n",
+            generator.definitions.join("\n\n")
+        )
+    }
+}
+
+fn json_schema(shape: &TermShape) -> Value {
+    let mut alternatives: Vec<Value> = shape
+        .kinds
+        .keys()
+        .map(|kind| match kind {
+            TermKind::Atom => match shape.enum_values() {
+                Some(values) => json!({ "type": "string", "enum": values }),
+                None => json!({ "type": "string" }),
+            },
+            TermKind::Boolean => json!({ "type": "boolean" }),
+            TermKind::Integer => json!({ "type": "integer" }),
+            TermKind::Float => json!({ "type": "number" }),
+            TermKind::Binary | TermKind::String => json!({ "type": "string" }),
+            TermKind::List => match &shape.list_elements {
+                Some(elements) if elements.observed > 0 => {
+                    json!({ "type": "array", "items": json_schema(elements) })
+                }
+                _ => json!({ "type": "array" }),
+            },
+            TermKind::Tuple => {
+                let items: Vec<Value> = shape.tuple_elements.iter().map(json_schema).collect();
+                let min = shape.tuple_arities.keys().min().copied().unwrap_or(0);
+                json!({
+                    "type": "array",
+                    "prefixItems": items,
+                    "minItems": min,
+                    "maxItems": shape.tuple_elements.len(),
+                })
+            }
+            TermKind::Map => {
+                let properties: Map<String, Value> = shape
+                    .map_keys
+                    .iter()
+                    .map(|(key, value)| (key.clone(), json_schema(value)))
+                    .collect();
+                json!({
+                    "type": "object",
+                    "properties": properties,
+                    "required": shape.required_map_keys(),
+                })
+            }
+            other => json!({ "type": "string", "description": other.name() }),
+        })
+        .collect();
+    if shape.is_nullable() {
+        alternatives.push(json!({ "type": "null" }));
+    }
+
+    match alternatives.len() {
+        0 => json!({}),
+        1 => alternatives.remove(0),
+        _ => json!({ "anyOf": alternatives }),
+    }
+}
+
+const IGNORED: &str = "serde::de::IgnoredAny";
+
+#[derive(Default)]
+struct RustGenerator {
+    definitions: Vec<String>,
+    names: BTreeSet<String>,
+}
+
+impl RustGenerator {
+    fn reserve_name(&mut self, name: &str) -> String {
+        let mut candidate = name.to_string();
+        let mut n = 2;
+        while !self.names.insert(candidate.clone()) {
+            candidate = format!("{}{}", name, n);
+            n += 1;
+        }
+        candidate
+    }
+
+    fn rust_type(&mut self, shape: &TermShape, hint: &str) -> String {
+        let base = self.base_type(shape, hint);
+        if shape.is_nullable() && base != IGNORED {
+            format!("Option<{}>", base)
+        } else {
+            base
+        }
+    }
+
+    fn base_type(&mut self, shape: &TermShape, hint: &str) -> String {
+        let kinds: Vec<TermKind> = shape.kinds.keys().copied().collect();
+        match kinds.as_slice() {
+            [TermKind::Atom] => match shape.enum_values() {
+                Some(values) if values.len() > 1 => self.define_enum(hint, &values),
+                _ => "String".to_string(),
+            },
+            [TermKind::Atom, TermKind::Boolean] => "String".to_string(),
+            [TermKind::Boolean] => "bool".to_string(),
+            [TermKind::Integer] => "i64".to_string(),
+            [TermKind::Float] => "f64".to_string(),
+            [TermKind::Binary] if !shape.non_utf8_binaries => "String".to_string(),
+            [TermKind::String] => "String".to_string(),
+            [TermKind::List] => match &shape.list_elements {
+                Some(elements) if elements.observed > 0 => {
+                    format!(
+                        "Vec<{}>",
+                        self.rust_type(elements, &format!("{}Item", hint))
+                    )
+                }
+                _ => format!("Vec<{}>", IGNORED),
+            },
+            [TermKind::Tuple] if shape.tuple_arities.len() == 1 => {
+                let elements: Vec<String> = shape
+                    .tuple_elements
+                    .iter()
+                    .enumerate()
+                    .map(|(i, element)| self.rust_type(element, &format!("{}{}", hint, i + 1)))
+                    .collect();
+                match elements.as_slice() {
+                    [single] => format!("({},)", single),
+                    _ => format!("({})", elements.join(", ")),
+                }
+            }
+            [TermKind::Map] if !shape.non_atom_map_keys && !shape.map_keys_truncated => {
+                self.define_struct(hint, shape)
+            }
+            _ => IGNORED.to_string(),
+        }
+    }
+
+    fn define_enum(&mut self, hint: &str, values: &[&str]) -> String {
+        let name = self.reserve_name(hint);
+        let mut variants = BTreeSet::new();
+        let body: Vec<String> = values
+            .iter()
+            .map(|value| {
+                let base = type_name(value, "V");
+                let mut variant = base.clone();
+                let mut n = 2;
+                while !variants.insert(variant.clone()) {
+                    variant = format!("{}{}", base, n);
+                    n += 1;
+                }
+                format!("    #[serde(rename = {:?})]\n    {},", value, variant)
+            })
+            .collect();
+
+        self.definitions.push(format!(
+            "#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]\npub enum {} {{\n{}\n}}",
+            name,
+            body.join("\n")
+        ));
+        name
+    }
+
+    fn define_struct(&mut self, hint: &str, shape: &TermShape) -> String {
+        let name = self.reserve_name(hint);
+        let maps = shape.count(TermKind::Map);
+        let mut fields = BTreeSet::new();
+        let mut body = Vec::with_capacity(shape.map_keys.len());
+        for (key, value) in &shape.map_keys {
+            let base = field_name(key);
+            let mut field = base.clone();
+            let mut n = 2;
+            while !fields.insert(field.clone()) {
+                field = format!("{}_{}", base, n);
+                n += 1;
+            }
+
+            let mut field_type = self.rust_type(value, &format!("{}{}", name, camel_case(key)));
+            if value.observed < maps && !field_type.starts_with("Option<") && field_type != IGNORED
+            {
+                field_type = format!("Option<{}>", field_type);
+            }
+            let rename = if field.trim_start_matches("r#") == key {
+                String::new()
+            } else {
+                format!("    #[serde(rename = {:?})]\n", key)
+            };
+            body.push(format!("{}    pub {}: {},", rename, field, field_type));
+        }
+
+        self.definitions.push(format!(
+            "#[derive(Debug, Clone, Deserialize)]\npub struct {} {{\n{}\n}}",
+            name,
+            body.join("\n")
+        ));
+        name
+    }
+}
+
+fn camel_case(value: &str) -> String {
+    value
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn identifier_start(value: String, prefix: &str) -> String {
+    if value.is_empty() || value.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("{}{}", prefix, value)
+    } else {
+        value
+    }
+}
+
+/// Turns an atom into a type or variant name. `Self` cannot be used as
+/// either, so it gets a trailing underscore.
+fn type_name(value: &str, prefix: &str) -> String {
+    let name = identifier_start(camel_case(value), prefix);
+    if name == "Self" { name + "_" } else { name }
+}
+
+/// Keywords and reserved words that are valid as raw identifiers.
+const RUST_KEYWORDS: [&str; 48] = [
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "gen", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "static", "struct", "trait", "true", "try", "type", "unsafe", "use", "where",
+    "while", "abstract", "become", "box", "do", "final", "macro", "override", "priv", "typeof",
+    "unsized", "virtual", "yield",
+];
+
+/// Keywords that cannot be raw identifiers.
+const NON_RAW_KEYWORDS: [&str; 4] = ["crate", "self", "Self", "super"];
+
+fn field_name(key: &str) -> String {
+    let mut name = String::with_capacity(key.len());
+    for (i, c) in key.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 && !name.ends_with('_') {
+            name.push('_');
+        }
+        name.push(if c.is_ascii_alphanumeric() {
+            c.to_ascii_lowercase()
+        } else {
+            '_'
+        });
+    }
+    let name = identifier_start(name.trim_matches('_').to_string(), "field_");
+    if RUST_KEYWORDS.contains(&name.as_str()) {
+        format!("r#{}", name)
+    } else if NON_RAW_KEYWORDS.contains(&name.as_str()) {
+        name + "_"
+    } else {
+        name
+    }
+}
+
+/// Scans every object when `sample_size` is `None`.
+pub async fn infer_table_schema(
+    remote_node: &str,
+    cookie: &str,
+    table_name: &str,
+    sample_size: Option<usize>,
+) -> Result<TableSchema> {
+    let node = create_connected_node(remote_node, cookie).await?;
+    let limit = match sample_size {
+        Some(0) => {
+            return Err(Error::InvalidArgument(
+                "the sample size must be positive".to_string(),
+            ));
+        }
+        Some(n) => erl_int!(n as i64),
+        None => erl_atom!("all"),
+    };
+
+    let reply = eval(
+        &node,
+        remote_node,
+        SAMPLE_SOURCE,
+        vec![("Tab", erl_atom!(table_name)), ("Limit", limit)],
+    )
+    .await?;
+
+    if reply.is_undefined() {
+        return Err(Error::TableNotFound(table_name.to_string()));
+    }
+
+    let [size, objects]: [OwnedTerm; 2] = reply
+        .try_into_tuple()?
+        .try_into()
+        .map_err(|_| Error::UnexpectedResponse("Expected a {Size, Objects} tuple".to_string()))?;
+    let total_objects = size.as_integer().ok_or_else(|| {
+        Error::UnexpectedResponse(format!("Expected a table size, got: {}", size))
+    })?;
+
+    Ok(TableSchema::infer(
+        table_name,
+        total_objects.max(0) as u64,
+        &objects.try_into_list()?,
+    ))
+}
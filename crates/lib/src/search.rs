@@ -0,0 +1,326 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::{Error, Result};
+use crate::ets::{
+    DEFAULT_PAGE_SIZE, EtsTableInfo, Protection, create_connected_node, fetch_page,
+    fetch_remote_tables,
+};
+use crate::eval::{eval, parse_term};
+use edp_node::Node;
+use erltf::{OwnedTerm, erl_atom};
+use regex::Regex;
+use serde::Serialize;
+
+const FIND_KEY_SOURCE: &str = r#"
+lists:filtermap(
+    fun(T) ->
+        try ets:member(T, Key) of
+            true -> {true, {T, ets:lookup(T, Key)}};
+            false -> false
+        catch
+            error:badarg -> false
+        end
+    end, Tabs).
+"#;
+
+#[derive(Debug, Clone)]
+pub struct KeyHit {
+    pub table: EtsTableInfo,
+    pub objects: Vec<OwnedTerm>,
+}
+
+#[derive(Debug, Clone)]
+pub struct KeySearchResult {
+    pub key: OwnedTerm,
+    pub hits: Vec<KeyHit>,
+    /// Names of private tables that could not be searched.
+    pub skipped_private: Vec<String>,
+}
+
+/// Finds every readable table on the node that has `key` (given in
+/// Erlang term syntax) as a key, optionally limited to tables whose
+/// names match `pattern`.
+pub async fn find_key(
+    remote_node: &str,
+    cookie: &str,
+    key: &str,
+    pattern: Option<&Regex>,
+) -> Result<KeySearchResult> {
+    let node = create_connected_node(remote_node, cookie).await?;
+    let key = parse_term(&node, remote_node, key).await?;
+
+    let (private, readable): (Vec<_>, Vec<_>) = fetch_remote_tables(&node, remote_node)
+        .await?
+        .into_iter()
+        .filter(|t| pattern.is_none_or(|re| re.is_match(&t.info.name)))
+        .partition(|t| t.info.protection == Protection::Private);
+
+    let refs = OwnedTerm::List(readable.iter().map(|t| t.reference.clone()).collect());
+    let found = eval(
+        &node,
+        remote_node,
+        FIND_KEY_SOURCE,
+        vec![("Key", key.clone()), ("Tabs", refs)],
+    )
+    .await?;
+
+    let mut hits = Vec::new();
+    for pair in found.try_into_list()? {
+        let [reference, objects]: [OwnedTerm; 2] = match pair.try_into_tuple()?.try_into() {
+            Ok(pair) => pair,
+            Err(_) => continue,
+        };
+        if let Some(table) = readable.iter().find(|t| t.reference == reference) {
+            hits.push(KeyHit {
+                table: table.info.clone(),
+                objects: objects.try_into_list()?,
+            });
+        }
+    }
+
+    Ok(KeySearchResult {
+        key,
+        hits,
+        skipped_private: private.into_iter().map(|t| t.info.name).collect(),
+    })
+}
+
+/// An entry whose rendered form matched the search regex.
+#[derive(Debug, Clone, Serialize)]
+pub struct GrepMatch {
+    pub table_name: String,
+    /// 1-based position of the entry in traversal order.
+    pub index: usize,
+    pub entry: String,
+    /// Byte ranges of the regex matches within `entry`.
+    pub ranges: Vec<(usize, usize)>,
+}
+
+impl GrepMatch {
+    /// Splits the entry into consecutive segments, flagging the ones
+    /// that matched.
+    pub fn segments(&self) -> Vec<(&str, bool)> {
+        let mut segments = Vec::new();
+        let mut position = 0;
+        for &(start, end) in &self.ranges {
+            if start > position {
+                segments.push((&self.entry[position..start], false));
+            }
+            segments.push((&self.entry[start..end], true));
+            position = end;
+        }
+        if position < self.entry.len() {
+            segments.push((&self.entry[position..], false));
+        }
+        segments
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GrepResult {
+    pub matches: Vec<GrepMatch>,
+    pub tables_searched: usize,
+    pub skipped_private: Vec<String>,
+    /// Tables whose traversal ended before their last page.
+    pub stopped_early: Vec<StoppedTable>,
+    /// Set when the search stopped early because `max_matches` was reached.
+    pub truncated: bool,
+}
+
+/// A table that was only partially searched.
+#[derive(Debug, Clone, Serialize)]
+pub struct StoppedTable {
+    pub table_name: String,
+    pub entries_searched: usize,
+    /// `None` if the table was deleted while it was being searched,
+    /// otherwise the reason the next page could not be read.
+    pub error: Option<String>,
+}
+
+/// Renders a term in Erlang-like syntax for searching. Unlike the
+/// `Display` implementation, printable binaries are spelled out
+/// (`<<"guest">>` rather than `<<5 bytes>>`) so their contents can be matched.
+pub fn render_entry(term: &OwnedTerm) -> String {
+    let mut out = String::new();
+    write_term(&mut out, term);
+    out
+}
+
+fn write_term(out: &mut String, term: &OwnedTerm) {
+    match term {
+        OwnedTerm::Binary(bytes) => match std::str::from_utf8(bytes) {
+            Ok(text) if !text.chars().any(char::is_control) => {
+                out.push_str("<<\"");
+                for c in text.chars() {
+                    if c == '"' || c == '\\' {
+                        out.push('\\');
+                    }
+                    out.push(c);
+                }
+                out.push_str("\">>");
+            }
+            _ => out.push_str(&term.to_string()),
+        },
+        OwnedTerm::List(items) => write_seq(out, "[", items, "]"),
+        OwnedTerm::Tuple(items) => write_seq(out, "{", items, "}"),
+        OwnedTerm::Map(map) => {
+            out.push_str("#{");
+            for (i, (k, v)) in map.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_term(out, k);
+                out.push_str(" => ");
+                write_term(out, v);
+            }
+            out.push('}');
+        }
+        OwnedTerm::ImproperList { elements, tail } => {
+            out.push('[');
+            for (i, item) in elements.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_term(out, item);
+            }
+            out.push_str(" | ");
+            write_term(out, tail);
+            out.push(']');
+        }
+        other => out.push_str(&other.to_string()),
+    }
+}
+
+fn write_seq(out: &mut String, open: &str, items: &[OwnedTerm], close: &str) {
+    out.push_str(open);
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write_term(out, item);
+    }
+    out.push_str(close);
+}
+
+/// Returns a match if the rendered entry matches `regex`.
+pub fn match_entry(
+    regex: &Regex,
+    table_name: &str,
+    index: usize,
+    entry: &OwnedTerm,
+) -> Option<GrepMatch> {
+    let rendered = render_entry(entry);
+    let ranges: Vec<(usize, usize)> = regex
+        .find_iter(&rendered)
+        .filter(|m| !m.is_empty())
+        .map(|m| (m.start(), m.end()))
+        .collect();
+
+    (!ranges.is_empty()).then(|| GrepMatch {
+        table_name: table_name.to_string(),
+        index,
+        entry: rendered,
+        ranges,
+    })
+}
+
+/// Searches the rendered entries of every readable table whose name
+/// matches `table_pattern`. Tables are traversed page by page, so only
+/// the matches are kept in memory.
+pub async fn grep_tables(
+    remote_node: &str,
+    cookie: &str,
+    regex: &Regex,
+    table_pattern: Option<&Regex>,
+    max_matches: Option<usize>,
+) -> Result<GrepResult> {
+    let node = create_connected_node(remote_node, cookie).await?;
+
+    let (private, readable): (Vec<_>, Vec<_>) = fetch_remote_tables(&node, remote_node)
+        .await?
+        .into_iter()
+        .filter(|t| table_pattern.is_none_or(|re| re.is_match(&t.info.name)))
+        .partition(|t| t.info.protection == Protection::Private);
+
+    let mut result = GrepResult {
+        matches: Vec::new(),
+        tables_searched: 0,
+        skipped_private: private.into_iter().map(|t| t.info.name).collect(),
+        stopped_early: Vec::new(),
+        truncated: false,
+    };
+
+    'tables: for table in &readable {
+        result.tables_searched += 1;
+        let mut cursor = None;
+        let mut index = 0;
+        loop {
+            let page = match fetch_page(
+                &node,
+                remote_node,
+                table.reference.clone(),
+                cursor,
+                DEFAULT_PAGE_SIZE,
+            )
+            .await
+            {
+                Ok(page) => page,
+                // Pages are not read under a fixation, so besides deletion of the
+                // table this happens when the cursor key is deleted between pages
+                Err(Error::BadRpc(reason)) => {
+                    let deleted = is_deleted(&node, remote_node, &table.reference).await?;
+                    result.stopped_early.push(StoppedTable {
+                        table_name: table.info.name.clone(),
+                        entries_searched: index,
+                        error: (!deleted).then_some(reason),
+                    });
+                    break;
+                }
+                Err(e) => return Err(e),
+            };
+
+            for entry in &page.entries {
+                index += 1;
+                if let Some(m) = match_entry(regex, &table.info.name, index, entry) {
+                    result.matches.push(m);
+                    if max_matches.is_some_and(|max| result.matches.len() >= max) {
+                        result.truncated = true;
+                        break 'tables;
+                    }
+                }
+            }
+
+            match page.next {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+async fn is_deleted(node: &Node, remote_node: &str, table_ref: &OwnedTerm) -> Result<bool> {
+    let id = node
+        .rpc_call(
+            remote_node,
+            "ets",
+            "info",
+            vec![table_ref.clone(), erl_atom!("id")],
+        )
+        .await?;
+
+    Ok(id.is_undefined())
+}
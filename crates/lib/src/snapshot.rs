@@ -0,0 +1,171 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::{Error, Result};
+use crate::ets::{
+    EtsTableInfo, Protection, check_badrpc, create_connected_node, fetch_remote_tables,
+};
+use erltf::{OwnedTerm, erl_atom, erl_int, erl_tuple};
+use erltf_serde::{from_term, to_term};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SNAPSHOT_TAG: &str = "ets_browser_snapshot";
+const SNAPSHOT_FORMAT_VERSION: i64 = 1;
+
+/// A point-in-time capture of a node's ETS tables.
+///
+/// Snapshots are stored in the Erlang external term format, so table
+/// contents keep their original types. Contents are only captured for
+/// named, non-private tables, keyed by table name.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub node: String,
+    pub taken_at: u64,
+    pub tables: Vec<EtsTableInfo>,
+    pub contents: BTreeMap<String, Vec<OwnedTerm>>,
+}
+
+impl Snapshot {
+    pub fn has_contents(&self) -> bool {
+        !self.contents.is_empty()
+    }
+
+    pub fn to_term(&self) -> Result<OwnedTerm> {
+        let tables = self
+            .tables
+            .iter()
+            .map(to_term)
+            .collect::<Result<Vec<_>, _>>()?;
+        let contents = self
+            .contents
+            .iter()
+            .map(|(name, objects)| erl_tuple!(erl_atom!(name.as_str()), objects.clone()))
+            .collect::<Vec<_>>();
+
+        Ok(erl_tuple!(
+            erl_atom!(SNAPSHOT_TAG),
+            erl_int!(SNAPSHOT_FORMAT_VERSION),
+            OwnedTerm::Binary(self.node.as_bytes().to_vec()),
+            erl_int!(self.taken_at),
+            tables,
+            contents
+        ))
+    }
+
+    pub fn from_term(term: OwnedTerm) -> Result<Self> {
+        let elements = term
+            .try_into_tuple()
+            .map_err(|_| Error::InvalidSnapshot("expected a snapshot tuple".to_string()))?;
+
+        let [tag, version, node, taken_at, tables, contents]: [OwnedTerm; 6] = elements
+            .try_into()
+            .map_err(|_| Error::InvalidSnapshot("unexpected snapshot tuple arity".to_string()))?;
+
+        if !tag.is_atom_with_name(SNAPSHOT_TAG) {
+            return Err(Error::InvalidSnapshot("missing snapshot tag".to_string()));
+        }
+        if version.as_integer() != Some(SNAPSHOT_FORMAT_VERSION) {
+            return Err(Error::InvalidSnapshot(format!(
+                "unsupported snapshot format version: {}",
+                version
+            )));
+        }
+
+        let node: String = from_term(&node)?;
+        let taken_at: u64 = from_term(&taken_at)?;
+        let tables = tables
+            .try_into_list()?
+            .iter()
+            .map(from_term)
+            .collect::<Result<Vec<EtsTableInfo>, _>>()?;
+
+        let mut contents_map = BTreeMap::new();
+        for entry in contents.try_into_list()? {
+            let [name, objects]: [OwnedTerm; 2] =
+                entry.try_into_tuple()?.try_into().map_err(|_| {
+                    Error::InvalidSnapshot("unexpected table contents entry".to_string())
+                })?;
+            contents_map.insert(name.try_into_atom()?.to_string(), objects.try_into_list()?);
+        }
+
+        Ok(Snapshot {
+            node,
+            taken_at,
+            tables,
+            contents: contents_map,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(erltf::encode(&self.to_term()?)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Self::from_term(erltf::decode(bytes)?)
+    }
+
+    pub fn write_to(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_bytes()?)?;
+        Ok(())
+    }
+
+    pub fn read_from(path: &Path) -> Result<Self> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+}
+
+pub async fn take_snapshot(
+    remote_node: &str,
+    cookie: &str,
+    include_contents: bool,
+) -> Result<Snapshot> {
+    let node = create_connected_node(remote_node, cookie).await?;
+    let tables = fetch_remote_tables(&node, remote_node).await?;
+
+    let mut contents = BTreeMap::new();
+    if include_contents {
+        for table in tables
+            .iter()
+            .filter(|t| t.named && t.info.protection != Protection::Private)
+        {
+            let reply = node
+                .rpc_call(
+                    remote_node,
+                    "ets",
+                    "tab2list",
+                    vec![table.reference.clone()],
+                )
+                .await?;
+            // the table may have been deleted since it was listed
+            if let Ok(objects) = check_badrpc(reply).and_then(|r| Ok(r.try_into_list()?)) {
+                contents.insert(table.info.name.clone(), objects);
+            }
+        }
+    }
+
+    let taken_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    Ok(Snapshot {
+        node: remote_node.to_string(),
+        taken_at,
+        tables: tables.into_iter().map(|t| t.info).collect(),
+        contents,
+    })
+}
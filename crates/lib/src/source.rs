@@ -0,0 +1,313 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::cluster::ClusterTables;
+use crate::diff::object_key;
+use crate::errors::{Error, Result};
+use crate::ets::{EtsTableInfo, TablePage};
+use crate::hash_stats::TableDetails;
+use crate::owner::{OwnerState, OwnerStateKind};
+use crate::snapshot::Snapshot;
+use erltf::OwnedTerm;
+use std::future::{Future, ready};
+use std::pin::Pin;
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+pub trait EtsSource: Send + Sync {
+    fn list_tables(&self) -> BoxFuture<'_, Result<Vec<EtsTableInfo>>>;
+
+    fn table_info<'a>(&'a self, table_name: &'a str) -> BoxFuture<'a, Result<EtsTableInfo>>;
+
+    fn dump_table<'a>(&'a self, table_name: &'a str) -> BoxFuture<'a, Result<Vec<OwnedTerm>>>;
+
+    /// Fetches up to `limit` entries starting at `cursor`, which is the
+    /// `next` value of the previous page. Cursors are source-specific.
+    fn page_table<'a>(
+        &'a self,
+        table_name: &'a str,
+        cursor: Option<OwnedTerm>,
+        limit: usize,
+    ) -> BoxFuture<'a, Result<TablePage>>;
+
+    /// Returns the objects whose key, at the table's `keypos`, is `key`.
+    fn lookup_table<'a>(
+        &'a self,
+        table_name: &'a str,
+        key: &'a OwnedTerm,
+    ) -> BoxFuture<'a, Result<Vec<OwnedTerm>>>;
+
+    // The methods below need a running node. Sources that cannot answer
+    // them return Error::Unsupported.
+
+    fn table_details<'a>(
+        &'a self,
+        table_name: &'a str,
+        with_stats: bool,
+    ) -> BoxFuture<'a, Result<TableDetails>> {
+        let _ = (table_name, with_stats);
+        unsupported("table details")
+    }
+
+    fn list_tables_with_off_heap(
+        &self,
+        sample_size: usize,
+    ) -> BoxFuture<'_, Result<Vec<EtsTableInfo>>> {
+        let _ = sample_size;
+        unsupported("off-heap binary sizes")
+    }
+
+    fn list_tables_with_applications(&self) -> BoxFuture<'_, Result<Vec<EtsTableInfo>>> {
+        unsupported("owning applications")
+    }
+
+    fn list_cluster_tables(&self) -> BoxFuture<'_, Result<ClusterTables>> {
+        unsupported("cluster-wide table listings")
+    }
+
+    fn owner_state<'a>(
+        &'a self,
+        table_name: &'a str,
+        kind: OwnerStateKind,
+        timeout_ms: u64,
+    ) -> BoxFuture<'a, Result<OwnerState>> {
+        let _ = (table_name, kind, timeout_ms);
+        unsupported("owner process state")
+    }
+}
+
+fn unsupported<'a, T: Send + 'a>(what: &str) -> BoxFuture<'a, Result<T>> {
+    Box::pin(ready(Err(Error::Unsupported(format!(
+        "{} require a running node",
+        what
+    )))))
+}
+
+/// Every call opens a new connection.
+#[derive(Debug, Clone)]
+pub struct LiveNode {
+    node: String,
+    cookie: String,
+}
+
+impl LiveNode {
+    pub fn new(node: &str, cookie: &str) -> Self {
+        Self {
+            node: node.to_string(),
+            cookie: cookie.to_string(),
+        }
+    }
+
+    pub fn node(&self) -> &str {
+        &self.node
+    }
+}
+
+impl EtsSource for LiveNode {
+    fn list_tables(&self) -> BoxFuture<'_, Result<Vec<EtsTableInfo>>> {
+        Box::pin(crate::list_tables(&self.node, &self.cookie))
+    }
+
+    fn table_info<'a>(&'a self, table_name: &'a str) -> BoxFuture<'a, Result<EtsTableInfo>> {
+        Box::pin(async move {
+            let details =
+                crate::get_table_details(&self.node, &self.cookie, table_name, false).await?;
+            Ok(details.info)
+        })
+    }
+
+    fn dump_table<'a>(&'a self, table_name: &'a str) -> BoxFuture<'a, Result<Vec<OwnedTerm>>> {
+        Box::pin(crate::dump_table(&self.node, &self.cookie, table_name))
+    }
+
+    fn page_table<'a>(
+        &'a self,
+        table_name: &'a str,
+        cursor: Option<OwnedTerm>,
+        limit: usize,
+    ) -> BoxFuture<'a, Result<TablePage>> {
+        Box::pin(crate::page_table(
+            &self.node,
+            &self.cookie,
+            table_name,
+            cursor,
+            limit,
+        ))
+    }
+
+    fn lookup_table<'a>(
+        &'a self,
+        table_name: &'a str,
+        key: &'a OwnedTerm,
+    ) -> BoxFuture<'a, Result<Vec<OwnedTerm>>> {
+        Box::pin(crate::lookup_table(
+            &self.node,
+            &self.cookie,
+            table_name,
+            key,
+        ))
+    }
+
+    fn table_details<'a>(
+        &'a self,
+        table_name: &'a str,
+        with_stats: bool,
+    ) -> BoxFuture<'a, Result<TableDetails>> {
+        Box::pin(crate::get_table_details(
+            &self.node,
+            &self.cookie,
+            table_name,
+            with_stats,
+        ))
+    }
+
+    fn list_tables_with_off_heap(
+        &self,
+        sample_size: usize,
+    ) -> BoxFuture<'_, Result<Vec<EtsTableInfo>>> {
+        Box::pin(crate::list_tables_with_off_heap(
+            &self.node,
+            &self.cookie,
+            sample_size,
+        ))
+    }
+
+    fn list_tables_with_applications(&self) -> BoxFuture<'_, Result<Vec<EtsTableInfo>>> {
+        Box::pin(crate::list_tables_with_applications(
+            &self.node,
+            &self.cookie,
+        ))
+    }
+
+    fn list_cluster_tables(&self) -> BoxFuture<'_, Result<ClusterTables>> {
+        Box::pin(crate::list_cluster_tables(&self.node, &self.cookie))
+    }
+
+    fn owner_state<'a>(
+        &'a self,
+        table_name: &'a str,
+        kind: OwnerStateKind,
+        timeout_ms: u64,
+    ) -> BoxFuture<'a, Result<OwnerState>> {
+        Box::pin(crate::get_owner_state(
+            &self.node,
+            &self.cookie,
+            table_name,
+            kind,
+            timeout_ms,
+        ))
+    }
+}
+
+impl Snapshot {
+    fn table(&self, table_name: &str) -> Result<&EtsTableInfo> {
+        self.tables
+            .iter()
+            .find(|t| t.name == table_name)
+            .ok_or_else(|| Error::TableNotFound(table_name.to_string()))
+    }
+
+    fn objects(&self, table_name: &str) -> Result<&[OwnedTerm]> {
+        self.table(table_name)?;
+        self.contents
+            .get(table_name)
+            .map(Vec::as_slice)
+            .ok_or_else(|| {
+                Error::InvalidSnapshot(format!(
+                    "no contents were captured for table {}",
+                    table_name
+                ))
+            })
+    }
+
+    /// Pages through the captured objects in order. The cursor is the
+    /// offset of the next object.
+    fn page(&self, table_name: &str, cursor: Option<OwnedTerm>, limit: usize) -> Result<TablePage> {
+        let objects = self.objects(table_name)?;
+        let start = match cursor {
+            None => 0,
+            Some(OwnedTerm::Integer(offset)) if offset >= 0 => offset as usize,
+            Some(other) => {
+                return Err(Error::InvalidArgument(format!(
+                    "invalid snapshot page cursor: {}",
+                    other
+                )));
+            }
+        };
+        let end = start.saturating_add(limit.max(1)).min(objects.len());
+
+        Ok(TablePage {
+            entries: objects.get(start..end).unwrap_or_default().to_vec(),
+            next: (end < objects.len()).then(|| OwnedTerm::Integer(end as i64)),
+        })
+    }
+
+    fn lookup(&self, table_name: &str, key: &OwnedTerm) -> Result<Vec<OwnedTerm>> {
+        let keypos = self.table(table_name)?.keypos;
+        Ok(self
+            .objects(table_name)?
+            .iter()
+            .filter(|o| object_key(o, keypos) == Some(key))
+            .cloned()
+            .collect())
+    }
+}
+
+/// Serves the tables a snapshot captured. Contents are only available for
+/// tables captured with them.
+impl EtsSource for Snapshot {
+    fn list_tables(&self) -> BoxFuture<'_, Result<Vec<EtsTableInfo>>> {
+        Box::pin(ready(Ok(self.tables.clone())))
+    }
+
+    fn table_info<'a>(&'a self, table_name: &'a str) -> BoxFuture<'a, Result<EtsTableInfo>> {
+        Box::pin(ready(self.table(table_name).cloned()))
+    }
+
+    fn dump_table<'a>(&'a self, table_name: &'a str) -> BoxFuture<'a, Result<Vec<OwnedTerm>>> {
+        Box::pin(ready(self.objects(table_name).map(<[_]>::to_vec)))
+    }
+
+    fn page_table<'a>(
+        &'a self,
+        table_name: &'a str,
+        cursor: Option<OwnedTerm>,
+        limit: usize,
+    ) -> BoxFuture<'a, Result<TablePage>> {
+        Box::pin(ready(self.page(table_name, cursor, limit)))
+    }
+
+    fn lookup_table<'a>(
+        &'a self,
+        table_name: &'a str,
+        key: &'a OwnedTerm,
+    ) -> BoxFuture<'a, Result<Vec<OwnedTerm>>> {
+        Box::pin(ready(self.lookup(table_name, key)))
+    }
+
+    fn table_details<'a>(
+        &'a self,
+        table_name: &'a str,
+        with_stats: bool,
+    ) -> BoxFuture<'a, Result<TableDetails>> {
+        if with_stats {
+            return unsupported("hash bucket statistics");
+        }
+        Box::pin(ready(self.table(table_name).map(|info| TableDetails {
+            info: info.clone(),
+            hash_stats: None,
+            skewed: false,
+        })))
+    }
+}
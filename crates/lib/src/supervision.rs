@@ -0,0 +1,166 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::{Error, Result};
+use crate::ets::{EtsTableInfo, create_connected_node, fetch_remote_tables};
+use crate::eval::eval;
+use erltf::{OwnedTerm, erl_int};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// How long to wait for a supervisor to list its children, in milliseconds.
+pub const WHICH_CHILDREN_TIMEOUT_MS: i64 = 5_000;
+
+// Uses gen_server:call/3 rather than supervisor:which_children/1 so that
+// a busy supervisor cannot block the walk indefinitely.
+const TREE_SOURCE: &str = r#"
+Walk = fun W(Pid, Name, Type) ->
+           Children = case Type of
+                          supervisor ->
+                              try gen_server:call(Pid, which_children, Timeout) of
+                                  Cs -> [W(C, Id, T) || {Id, C, T, _} <- Cs, is_pid(C)]
+                              catch _:_ -> []
+                              end;
+                          _ -> []
+                      end,
+           {Name, Pid, Type, Children}
+       end,
+Root = fun(App) ->
+           case application_controller:get_master(App) of
+               undefined -> undefined;
+               Master ->
+                   case catch application_master:get_child(Master) of
+                       {Sup, _Mod} when is_pid(Sup) -> Walk(Sup, App, supervisor);
+                       _ -> undefined
+                   end
+           end
+       end,
+[T || {App, _, _} <- application:which_applications(), T <- [Root(App)], T =/= undefined].
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessKind {
+    Supervisor,
+    Worker,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessNode {
+    /// Child id, or the application name for a top-level supervisor.
+    pub name: String,
+    pub pid: String,
+    pub kind: ProcessKind,
+    pub tables: Vec<EtsTableInfo>,
+    pub subtree_table_count: usize,
+    pub subtree_memory_bytes: u64,
+    pub children: Vec<ProcessNode>,
+}
+
+impl ProcessNode {
+    fn prune_empty(&mut self) {
+        self.children.retain(|c| c.subtree_table_count > 0);
+        for child in &mut self.children {
+            child.prune_empty();
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SupervisionTree {
+    pub applications: Vec<ProcessNode>,
+    /// Tables whose owner is not part of any supervision tree.
+    pub unattributed: Vec<EtsTableInfo>,
+}
+
+impl SupervisionTree {
+    pub fn prune_empty(&mut self) {
+        self.applications.retain(|a| a.subtree_table_count > 0);
+        for application in &mut self.applications {
+            application.prune_empty();
+        }
+    }
+}
+
+fn build_node(
+    term: OwnedTerm,
+    tables_by_owner: &mut BTreeMap<OwnedTerm, Vec<EtsTableInfo>>,
+) -> Result<ProcessNode> {
+    let [name, pid, kind, children]: [OwnedTerm; 4] =
+        term.try_into_tuple()?.try_into().map_err(|_| {
+            Error::UnexpectedResponse("Expected a {Name, Pid, Type, Children} tuple".to_string())
+        })?;
+
+    let children = children
+        .try_into_list()?
+        .into_iter()
+        .map(|child| build_node(child, tables_by_owner))
+        .collect::<Result<Vec<_>>>()?;
+    let mut tables = tables_by_owner.remove(&pid).unwrap_or_default();
+    tables.sort_by_key(|t| std::cmp::Reverse(t.memory_bytes));
+
+    Ok(ProcessNode {
+        name: name.to_string(),
+        pid: pid.to_string(),
+        kind: if kind.is_atom_with_name("supervisor") {
+            ProcessKind::Supervisor
+        } else {
+            ProcessKind::Worker
+        },
+        subtree_table_count: tables.len()
+            + children
+                .iter()
+                .map(|c| c.subtree_table_count)
+                .sum::<usize>(),
+        subtree_memory_bytes: tables.iter().map(|t| t.memory_bytes).sum::<u64>()
+            + children.iter().map(|c| c.subtree_memory_bytes).sum::<u64>(),
+        tables,
+        children,
+    })
+}
+
+pub async fn supervision_tree(remote_node: &str, cookie: &str) -> Result<SupervisionTree> {
+    let node = create_connected_node(remote_node, cookie).await?;
+
+    let mut tables_by_owner: BTreeMap<OwnedTerm, Vec<EtsTableInfo>> = BTreeMap::new();
+    for table in fetch_remote_tables(&node, remote_node).await? {
+        tables_by_owner
+            .entry(table.owner_pid)
+            .or_default()
+            .push(table.info);
+    }
+
+    let roots = eval(
+        &node,
+        remote_node,
+        TREE_SOURCE,
+        vec![("Timeout", erl_int!(WHICH_CHILDREN_TIMEOUT_MS))],
+    )
+    .await?;
+
+    let mut applications = roots
+        .try_into_list()?
+        .into_iter()
+        .map(|root| build_node(root, &mut tables_by_owner))
+        .collect::<Result<Vec<_>>>()?;
+    applications.sort_by_key(|a| std::cmp::Reverse(a.subtree_memory_bytes));
+
+    let mut unattributed: Vec<EtsTableInfo> = tables_by_owner.into_values().flatten().collect();
+    unattributed.sort_by_key(|t| std::cmp::Reverse(t.memory_bytes));
+
+    Ok(SupervisionTree {
+        applications,
+        unattributed,
+    })
+}
@@ -0,0 +1,141 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::{Error, Result};
+use crate::ets::{dump_table, lookup_table};
+use erltf::OwnedTerm;
+use serde::de::{self, DeserializeOwned, Visitor};
+use std::cell::Cell;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ObjectLayout {
+    /// Tuple elements fill the fields of a struct in declaration order.
+    /// Tuple structs and Rust tuples are filled element by element.
+    #[default]
+    Positional,
+    /// Like [`ObjectLayout::Positional`] for records: the first element
+    /// must be this atom and is not mapped onto a field.
+    Record(String),
+}
+
+pub fn from_object<T: DeserializeOwned>(object: &OwnedTerm, layout: &ObjectLayout) -> Result<T> {
+    let elements = match (layout, object) {
+        (ObjectLayout::Positional, OwnedTerm::Tuple(elements)) => elements.as_slice(),
+        (ObjectLayout::Record(tag), OwnedTerm::Tuple(elements)) => match elements.split_first() {
+            Some((first, rest)) if first.is_atom_with_name(tag) => rest,
+            _ => return Err(mismatch(&format!("a '{}' record", tag), object)),
+        },
+        (ObjectLayout::Positional, _) => return Ok(erltf_serde::from_term(object)?),
+        (ObjectLayout::Record(tag), _) => {
+            return Err(mismatch(&format!("a '{}' record", tag), object));
+        }
+    };
+
+    match struct_fields::<T>() {
+        Some(fields) => {
+            if elements.len() > fields.len() {
+                return Err(mismatch(
+                    &format!("at most {} elements", fields.len()),
+                    object,
+                ));
+            }
+            let named = fields
+                .iter()
+                .zip(elements)
+                .map(|(field, element)| (OwnedTerm::atom(field), element.clone()))
+                .collect::<BTreeMap<_, _>>();
+            Ok(erltf_serde::from_term(&OwnedTerm::Map(named))?)
+        }
+        None => Ok(erltf_serde::from_term(&OwnedTerm::Tuple(
+            elements.to_vec(),
+        ))?),
+    }
+}
+
+/// Nested proplists are converted into maps as well.
+pub fn from_proplist<T: DeserializeOwned>(term: &OwnedTerm) -> Result<T> {
+    let map = term.to_map_recursive()?;
+    Ok(erltf_serde::from_term(&map)?)
+}
+
+fn mismatch(expected: &str, found: &OwnedTerm) -> Error {
+    Error::Deserialization(erltf_serde::Error::TypeMismatch {
+        expected: expected.to_string(),
+        found: found.to_string(),
+    })
+}
+
+/// The serialized field names of `T` if it is a struct with named fields.
+fn struct_fields<T: DeserializeOwned>() -> Option<&'static [&'static str]> {
+    let fields = Cell::new(None);
+    let _ = T::deserialize(FieldNames(&fields));
+    fields.get()
+}
+
+/// Records the field list serde passes to `deserialize_struct` and fails
+/// every call, so nothing is actually deserialized.
+struct FieldNames<'a>(&'a Cell<Option<&'static [&'static str]>>);
+
+impl<'de> de::Deserializer<'de> for FieldNames<'_> {
+    type Error = de::value::Error;
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error> {
+        self.0.set(Some(fields));
+        Err(de::Error::custom("field names recorded"))
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(
+        self,
+        _visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error> {
+        Err(de::Error::custom("not a struct"))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+/// Each object is deserialized separately, so that one malformed object
+/// does not fail the others.
+pub async fn dump_table_as<T: DeserializeOwned>(
+    remote_node: &str,
+    cookie: &str,
+    table_name: &str,
+    layout: &ObjectLayout,
+) -> Result<Vec<Result<T>>> {
+    let objects = dump_table(remote_node, cookie, table_name).await?;
+
+    Ok(objects.iter().map(|o| from_object(o, layout)).collect())
+}
+
+pub async fn lookup_as<T: DeserializeOwned>(
+    remote_node: &str,
+    cookie: &str,
+    table_name: &str,
+    key: &OwnedTerm,
+    layout: &ObjectLayout,
+) -> Result<Vec<Result<T>>> {
+    let objects = lookup_table(remote_node, cookie, table_name, key).await?;
+
+    Ok(objects.iter().map(|o| from_object(o, layout)).collect())
+}
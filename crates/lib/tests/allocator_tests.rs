@@ -0,0 +1,72 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ets_lib::{AllocatorInstance, CarrierUsage, EtsAllocReport, fragmentation_ratio};
+
+fn usage(blocks_bytes: u64, carriers_bytes: u64, carriers: u64) -> CarrierUsage {
+    CarrierUsage {
+        blocks_bytes,
+        carriers_bytes,
+        carriers,
+    }
+}
+
+#[test]
+fn test_fragmentation_ratio() {
+    assert_eq!(fragmentation_ratio(750, 1000), 0.25);
+    assert_eq!(fragmentation_ratio(1000, 1000), 0.0);
+    assert_eq!(fragmentation_ratio(0, 0), 0.0);
+}
+
+#[test]
+fn test_instance_combines_carrier_kinds() {
+    let instance = AllocatorInstance::new(
+        1,
+        &usage(600, 1000, 2),
+        &usage(300, 400, 1),
+        &usage(100, 600, 3),
+    );
+
+    assert_eq!(instance.blocks_bytes, 1000);
+    assert_eq!(instance.carriers_bytes, 2000);
+    assert_eq!(instance.mbcs_carriers, 5);
+    assert_eq!(instance.sbcs_carriers, 1);
+    assert_eq!(instance.fragmentation, 0.5);
+}
+
+#[test]
+fn test_report_totals_and_gaps() {
+    let empty = CarrierUsage::default();
+    let instances = vec![
+        AllocatorInstance::new(0, &usage(1000, 2000, 1), &empty, &empty),
+        AllocatorInstance::new(1, &usage(3000, 6000, 2), &empty, &empty),
+    ];
+
+    let report = EtsAllocReport::new(instances, 4500, 4000, 12);
+    assert!(report.enabled);
+    assert_eq!(report.blocks_bytes, 4000);
+    assert_eq!(report.carriers_bytes, 8000);
+    assert_eq!(report.fragmentation, 0.5);
+    assert_eq!(report.unattributed_bytes, 500);
+    assert_eq!(report.allocator_overhead_bytes, 4000);
+}
+
+#[test]
+fn test_report_without_instances_is_disabled() {
+    let report = EtsAllocReport::new(vec![], 4000, 4200, 3);
+
+    assert!(!report.enabled);
+    assert_eq!(report.carriers_bytes, 0);
+    assert_eq!(report.unattributed_bytes, -200);
+}
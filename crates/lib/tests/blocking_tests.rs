@@ -0,0 +1,49 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ets_lib::blocking::Client;
+use ets_lib::{Error, ObjectLayout};
+
+const UNREACHABLE_NODE: &str = "ets_lib_blocking_test@localhost";
+
+#[test]
+fn test_client_keeps_its_node() {
+    let client = Client::new(UNREACHABLE_NODE, "cookie").unwrap();
+
+    assert_eq!(client.node(), UNREACHABLE_NODE);
+}
+
+#[test]
+fn test_client_errors_match_the_async_api() {
+    let client = Client::new(UNREACHABLE_NODE, "cookie").unwrap();
+    let blocking_error = client.list_tables().unwrap_err();
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let async_error = runtime
+        .block_on(ets_lib::list_tables(UNREACHABLE_NODE, "cookie"))
+        .unwrap_err();
+
+    assert_eq!(blocking_error.to_string(), async_error.to_string());
+}
+
+#[test]
+fn test_client_can_be_reused_after_an_error() {
+    let client = Client::new(UNREACHABLE_NODE, "cookie").unwrap();
+
+    assert!(client.dump_table("my_table").is_err());
+    assert!(matches!(
+        client.dump_table_as::<(String, i64)>("my_table", &ObjectLayout::Positional),
+        Err(Error::Node(_))
+    ));
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ets_lib::cluster::NodeFailure;
+use ets_lib::{NodeChecksum, TableChecksums};
+
+fn checksums(checksums: Vec<NodeChecksum>, failures: Vec<NodeFailure>) -> TableChecksums {
+    TableChecksums {
+        table: "my_table".to_string(),
+        checksums,
+        failures,
+    }
+}
+
+#[test]
+fn test_checksum_combines_sum_and_xor_as_hex() {
+    let checksum = NodeChecksum::new("a@localhost", 3, 0xdead_beef, 0x1);
+
+    assert_eq!(checksum.checksum, "deadbeef00000001");
+    assert_eq!(checksum.objects, 3);
+}
+
+#[test]
+fn test_checksums_match_when_equal_on_every_node() {
+    let result = checksums(
+        vec![
+            NodeChecksum::new("a@localhost", 10, 42, 7),
+            NodeChecksum::new("b@localhost", 10, 42, 7),
+        ],
+        vec![],
+    );
+
+    assert!(result.all_match());
+}
+
+#[test]
+fn test_checksums_differ_when_any_node_disagrees() {
+    let result = checksums(
+        vec![
+            NodeChecksum::new("a@localhost", 10, 42, 7),
+            NodeChecksum::new("b@localhost", 10, 42, 7),
+            NodeChecksum::new("c@localhost", 10, 43, 7),
+        ],
+        vec![],
+    );
+
+    assert!(!result.all_match());
+}
+
+#[test]
+fn test_checksums_differ_when_object_counts_differ() {
+    let result = checksums(
+        vec![
+            NodeChecksum::new("a@localhost", 10, 42, 7),
+            NodeChecksum::new("b@localhost", 11, 42, 7),
+        ],
+        vec![],
+    );
+
+    assert!(!result.all_match());
+}
+
+#[test]
+fn test_checksums_do_not_match_when_a_node_failed() {
+    let result = checksums(
+        vec![NodeChecksum::new("a@localhost", 10, 42, 7)],
+        vec![NodeFailure {
+            node: "b@localhost".to_string(),
+            reason: "Table not found: my_table on b@localhost".to_string(),
+        }],
+    );
+
+    assert!(!result.all_match());
+}
+
+#[test]
+fn test_single_checksum_matches_itself() {
+    let result = checksums(vec![NodeChecksum::new("a@localhost", 0, 0, 0)], vec![]);
+
+    assert!(result.all_match());
+}
@@ -0,0 +1,154 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ets_lib::compare::{build_inventory, compare_keyed};
+use ets_lib::{EtsTableInfo, Protection, TableType};
+use std::collections::BTreeMap;
+
+fn hashes(pairs: &[(&'static str, i64)]) -> BTreeMap<&'static str, i64> {
+    pairs.iter().copied().collect()
+}
+
+#[test]
+fn test_compare_keyed_identical_maps() {
+    let left = hashes(&[("a", 1), ("b", 2)]);
+    let right = hashes(&[("a", 1), ("b", 2)]);
+
+    let result = compare_keyed(&left, &right);
+
+    assert!(result.only_left.is_empty());
+    assert!(result.only_right.is_empty());
+    assert!(result.differing.is_empty());
+}
+
+#[test]
+fn test_compare_keyed_reports_one_sided_keys() {
+    let left = hashes(&[("a", 1), ("b", 2)]);
+    let right = hashes(&[("b", 2), ("c", 3)]);
+
+    let result = compare_keyed(&left, &right);
+
+    assert_eq!(result.only_left, vec!["a"]);
+    assert_eq!(result.only_right, vec!["c"]);
+    assert!(result.differing.is_empty());
+}
+
+#[test]
+fn test_compare_keyed_reports_differing_values() {
+    let left = hashes(&[("a", 1), ("b", 2)]);
+    let right = hashes(&[("a", 1), ("b", 3)]);
+
+    let result = compare_keyed(&left, &right);
+
+    assert_eq!(result.differing, vec!["b"]);
+}
+
+#[test]
+fn test_compare_keyed_empty_sides() {
+    let left = hashes(&[]);
+    let right = hashes(&[("a", 1)]);
+
+    let result = compare_keyed(&left, &right);
+
+    assert!(result.only_left.is_empty());
+    assert_eq!(result.only_right, vec!["a"]);
+}
+
+fn make_table(name: &str, size: u64) -> EtsTableInfo {
+    EtsTableInfo {
+        name: name.to_string(),
+        table_type: TableType::Set,
+        size,
+        memory_bytes: size * 100,
+        owner: "<0.0.0>".to_string(),
+        protection: Protection::Public,
+        keypos: 1,
+        application: None,
+        off_heap_bytes: None,
+    }
+}
+
+#[test]
+fn test_build_inventory_lines_up_tables_by_name() {
+    let inventory = build_inventory(vec![
+        (
+            "a@h".to_string(),
+            vec![make_table("t1", 5), make_table("t2", 5)],
+        ),
+        ("b@h".to_string(), vec![make_table("t1", 5)]),
+    ]);
+
+    assert_eq!(inventory.nodes, vec!["a@h", "b@h"]);
+    assert_eq!(inventory.rows.len(), 2);
+    assert!(!inventory.rows[0].is_missing_on_some());
+    assert_eq!(inventory.rows[1].name, "t2");
+    assert!(inventory.rows[1].is_missing_on_some());
+    assert!(inventory.rows[1].per_node[1].is_none());
+}
+
+#[test]
+fn test_build_inventory_sums_tables_sharing_a_name() {
+    let inventory = build_inventory(vec![(
+        "a@h".to_string(),
+        vec![make_table("t", 5), make_table("t", 7)],
+    )]);
+
+    let stats = inventory.rows[0].per_node[0].unwrap();
+    assert_eq!(stats.tables, 2);
+    assert_eq!(stats.size, 12);
+    assert_eq!(stats.memory_bytes, 1200);
+}
+
+#[test]
+fn test_build_inventory_flags_size_outliers() {
+    let inventory = build_inventory(vec![
+        ("a@h".to_string(), vec![make_table("t", 1000)]),
+        ("b@h".to_string(), vec![make_table("t", 1010)]),
+        ("c@h".to_string(), vec![make_table("t", 5000)]),
+    ]);
+
+    assert_eq!(inventory.rows[0].size_outliers, vec![2]);
+}
+
+#[test]
+fn test_build_inventory_does_not_flag_outliers_between_two_nodes() {
+    let inventory = build_inventory(vec![
+        ("a@h".to_string(), vec![make_table("t", 1000)]),
+        ("b@h".to_string(), vec![make_table("t", 5000)]),
+    ]);
+
+    assert!(inventory.rows[0].size_outliers.is_empty());
+}
+
+#[test]
+fn test_build_inventory_needs_three_reporting_nodes_for_outliers() {
+    let inventory = build_inventory(vec![
+        ("a@h".to_string(), vec![make_table("t", 1000)]),
+        ("b@h".to_string(), vec![make_table("t", 5000)]),
+        ("c@h".to_string(), vec![]),
+    ]);
+
+    assert!(inventory.rows[0].size_outliers.is_empty());
+}
+
+#[test]
+fn test_build_inventory_ignores_small_absolute_differences() {
+    let inventory = build_inventory(vec![
+        ("a@h".to_string(), vec![make_table("t", 1)]),
+        ("b@h".to_string(), vec![make_table("t", 1)]),
+        ("c@h".to_string(), vec![make_table("t", 4)]),
+    ]);
+
+    assert!(inventory.rows[0].size_outliers.is_empty());
+}
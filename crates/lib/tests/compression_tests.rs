@@ -0,0 +1,112 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ets_lib::{
+    CompressionEstimate, CompressionSample, CompressionStatus, EtsTableInfo, Protection,
+    TableCompressionEstimate, TableType,
+};
+
+fn make_table(name: &str, size: u64, memory_bytes: u64) -> EtsTableInfo {
+    EtsTableInfo {
+        name: name.to_string(),
+        table_type: TableType::Set,
+        size,
+        memory_bytes,
+        owner: "<0.90.0>".to_string(),
+        protection: Protection::Public,
+        keypos: 1,
+        application: None,
+        off_heap_bytes: None,
+    }
+}
+
+#[test]
+fn test_savings_are_projected_from_the_sample() {
+    // 100 sampled objects of 400 bytes each that encode to 100 bytes.
+    let sample = CompressionSample {
+        objects: 100,
+        flat_bytes: 40_000,
+        external_bytes: 10_000,
+    };
+    let estimate =
+        TableCompressionEstimate::from_sample(&make_table("docs", 1_000, 500_000), sample);
+
+    assert_eq!(estimate.status, CompressionStatus::Estimated);
+    assert_eq!(estimate.ratio, Some(0.25));
+    assert_eq!(estimate.estimated_savings_bytes, 300_000);
+    assert_eq!(estimate.projected_memory_bytes, 200_000);
+}
+
+#[test]
+fn test_extrapolated_data_is_capped_at_table_memory() {
+    let sample = CompressionSample {
+        objects: 10,
+        flat_bytes: 10_000,
+        external_bytes: 5_000,
+    };
+    let estimate =
+        TableCompressionEstimate::from_sample(&make_table("big", 1_000, 100_000), sample);
+
+    assert_eq!(estimate.estimated_savings_bytes, 50_000);
+}
+
+#[test]
+fn test_objects_that_grow_when_encoded_save_nothing() {
+    let sample = CompressionSample {
+        objects: 10,
+        flat_bytes: 240,
+        external_bytes: 300,
+    };
+    let estimate = TableCompressionEstimate::from_sample(&make_table("ints", 10, 1_000), sample);
+
+    assert!(estimate.ratio.unwrap() > 1.0);
+    assert_eq!(estimate.estimated_savings_bytes, 0);
+    assert_eq!(estimate.projected_memory_bytes, 1_000);
+}
+
+#[test]
+fn test_empty_sample_has_no_ratio() {
+    let estimate = TableCompressionEstimate::from_sample(
+        &make_table("empty", 0, 1_000),
+        CompressionSample::default(),
+    );
+
+    assert_eq!(estimate.status, CompressionStatus::Empty);
+    assert!(estimate.ratio.is_none());
+}
+
+#[test]
+fn test_node_wide_estimate_sums_and_sorts_by_savings() {
+    let small = TableCompressionEstimate::from_sample(
+        &make_table("small", 100, 10_000),
+        CompressionSample {
+            objects: 100,
+            flat_bytes: 8_000,
+            external_bytes: 4_000,
+        },
+    );
+    let large = TableCompressionEstimate::from_sample(
+        &make_table("large", 100, 100_000),
+        CompressionSample {
+            objects: 100,
+            flat_bytes: 80_000,
+            external_bytes: 20_000,
+        },
+    );
+
+    let estimate = CompressionEstimate::from_tables(vec![small, large]);
+    assert_eq!(estimate.tables[0].name, "large");
+    assert_eq!(estimate.total_memory_bytes, 110_000);
+    assert_eq!(estimate.estimated_savings_bytes, 64_000);
+}
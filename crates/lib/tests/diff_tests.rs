@@ -0,0 +1,221 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use erltf::{OwnedTerm, erl_atom, erl_int, erl_tuple};
+use ets_lib::diff::{diff_contents, object_key};
+use ets_lib::{EtsTableInfo, Protection, Snapshot, TableType, diff_snapshots};
+use std::collections::BTreeMap;
+
+fn make_table(name: &str, size: u64, memory_bytes: u64) -> EtsTableInfo {
+    EtsTableInfo {
+        name: name.to_string(),
+        table_type: TableType::Set,
+        size,
+        memory_bytes,
+        owner: "<0.0.0>".to_string(),
+        protection: Protection::Public,
+        keypos: 1,
+        application: None,
+        off_heap_bytes: None,
+    }
+}
+
+fn make_snapshot(tables: Vec<EtsTableInfo>, contents: Vec<(&str, Vec<OwnedTerm>)>) -> Snapshot {
+    Snapshot {
+        node: "rabbit@localhost".to_string(),
+        taken_at: 1_700_000_000,
+        tables,
+        contents: contents
+            .into_iter()
+            .map(|(name, objects)| (name.to_string(), objects))
+            .collect::<BTreeMap<_, _>>(),
+    }
+}
+
+#[test]
+fn test_object_key_uses_keypos() {
+    let object = erl_tuple!(erl_atom!("user"), erl_int!(42), erl_atom!("active"));
+    assert_eq!(object_key(&object, 1), Some(&erl_atom!("user")));
+    assert_eq!(object_key(&object, 2), Some(&erl_int!(42)));
+    assert_eq!(object_key(&object, 4), None);
+    assert_eq!(object_key(&object, 0), None);
+}
+
+#[test]
+fn test_diff_reports_added_and_removed_tables() {
+    let old = make_snapshot(
+        vec![make_table("a", 1, 100), make_table("b", 1, 100)],
+        vec![],
+    );
+    let new = make_snapshot(
+        vec![make_table("b", 1, 100), make_table("c", 1, 100)],
+        vec![],
+    );
+
+    let diff = diff_snapshots(&old, &new);
+
+    assert_eq!(diff.added.len(), 1);
+    assert_eq!(diff.added[0].name, "c");
+    assert_eq!(diff.removed.len(), 1);
+    assert_eq!(diff.removed[0].name, "a");
+    assert!(diff.changed.is_empty());
+}
+
+#[test]
+fn test_diff_reports_size_and_memory_deltas() {
+    let old = make_snapshot(vec![make_table("a", 10, 2048)], vec![]);
+    let new = make_snapshot(vec![make_table("a", 7, 4096)], vec![]);
+
+    let diff = diff_snapshots(&old, &new);
+
+    assert_eq!(diff.changed.len(), 1);
+    assert_eq!(diff.changed[0].size_delta, -3);
+    assert_eq!(diff.changed[0].memory_delta, 2048);
+}
+
+#[test]
+fn test_diff_counts_unnamed_tables_sharing_a_name() {
+    let old = make_snapshot(
+        vec![
+            make_table("session_cache", 1, 100),
+            make_table("session_cache", 2, 100),
+            make_table("stable", 1, 100),
+        ],
+        vec![],
+    );
+    let new = make_snapshot(
+        vec![
+            make_table("session_cache", 1, 100),
+            make_table("session_cache", 2, 100),
+            make_table("session_cache", 4, 300),
+            make_table("stable", 1, 100),
+            make_table("stable", 1, 100),
+        ],
+        vec![],
+    );
+
+    let diff = diff_snapshots(&old, &new);
+
+    assert!(diff.added.is_empty());
+    assert!(diff.removed.is_empty());
+    assert_eq!(diff.changed.len(), 2);
+
+    let cache = &diff.changed[0];
+    assert_eq!(cache.name, "session_cache");
+    assert_eq!((cache.old_count, cache.new_count), (2, 3));
+    assert_eq!(cache.count_delta(), 1);
+    assert_eq!(cache.size_delta, 4);
+    assert_eq!(cache.memory_delta, 300);
+
+    let stable = &diff.changed[1];
+    assert_eq!(stable.name, "stable");
+    assert_eq!(stable.count_delta(), 1);
+    assert_eq!(stable.size_delta, 1);
+}
+
+#[test]
+fn test_diff_reports_every_removed_table_sharing_a_name() {
+    let old = make_snapshot(
+        vec![make_table("worker", 1, 100), make_table("worker", 1, 100)],
+        vec![],
+    );
+    let new = make_snapshot(vec![], vec![]);
+
+    let diff = diff_snapshots(&old, &new);
+
+    assert_eq!(diff.removed.len(), 2);
+    assert!(diff.changed.is_empty());
+}
+
+#[test]
+fn test_diff_contents_matches_objects_by_key() {
+    let old = vec![
+        erl_tuple!(erl_atom!("a"), erl_int!(1)),
+        erl_tuple!(erl_atom!("b"), erl_int!(2)),
+        erl_tuple!(erl_atom!("c"), erl_int!(3)),
+    ];
+    let new = vec![
+        erl_tuple!(erl_atom!("a"), erl_int!(1)),
+        erl_tuple!(erl_atom!("b"), erl_int!(20)),
+        erl_tuple!(erl_atom!("d"), erl_int!(4)),
+    ];
+
+    let diff = diff_contents("t", 1, &old, &new);
+
+    assert_eq!(diff.added, vec![erl_tuple!(erl_atom!("d"), erl_int!(4))]);
+    assert_eq!(diff.removed, vec![erl_tuple!(erl_atom!("c"), erl_int!(3))]);
+    assert_eq!(diff.changed.len(), 1);
+    assert_eq!(diff.changed[0].key, erl_atom!("b"));
+    assert_eq!(
+        diff.changed[0].new,
+        vec![erl_tuple!(erl_atom!("b"), erl_int!(20))]
+    );
+}
+
+#[test]
+fn test_diff_contents_ignores_bag_ordering() {
+    let old = vec![
+        erl_tuple!(erl_atom!("k"), erl_int!(1)),
+        erl_tuple!(erl_atom!("k"), erl_int!(2)),
+    ];
+    let new = vec![
+        erl_tuple!(erl_atom!("k"), erl_int!(2)),
+        erl_tuple!(erl_atom!("k"), erl_int!(1)),
+    ];
+
+    assert!(diff_contents("t", 1, &old, &new).is_empty());
+}
+
+#[test]
+fn test_diff_snapshots_compares_captured_contents() {
+    let old = make_snapshot(
+        vec![make_table("t", 1, 100)],
+        vec![("t", vec![erl_tuple!(erl_atom!("k"), erl_int!(1))])],
+    );
+    let new = make_snapshot(
+        vec![make_table("t", 1, 100)],
+        vec![("t", vec![erl_tuple!(erl_atom!("k"), erl_int!(2))])],
+    );
+
+    let diff = diff_snapshots(&old, &new);
+
+    assert!(diff.changed.is_empty());
+    assert_eq!(diff.contents.len(), 1);
+    assert_eq!(diff.contents[0].table_name, "t");
+    assert_eq!(diff.contents[0].changed.len(), 1);
+}
+
+#[test]
+fn test_snapshot_round_trips_through_bytes() {
+    let snapshot = make_snapshot(
+        vec![make_table("t", 1, 100)],
+        vec![("t", vec![erl_tuple!(erl_atom!("k"), erl_int!(1))])],
+    );
+
+    let bytes = snapshot.to_bytes().unwrap();
+    let decoded = Snapshot::from_bytes(&bytes).unwrap();
+
+    assert_eq!(decoded.node, snapshot.node);
+    assert_eq!(decoded.taken_at, snapshot.taken_at);
+    assert_eq!(decoded.tables.len(), 1);
+    assert_eq!(decoded.tables[0].name, "t");
+    assert_eq!(decoded.tables[0].keypos, 1);
+    assert_eq!(decoded.contents, snapshot.contents);
+}
+
+#[test]
+fn test_snapshot_rejects_foreign_terms() {
+    let bytes = erltf::encode(&erl_tuple!(erl_atom!("something_else"), erl_int!(1))).unwrap();
+    assert!(Snapshot::from_bytes(&bytes).is_err());
+}
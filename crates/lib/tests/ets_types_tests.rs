@@ -14,7 +14,7 @@
 
 use erltf::{OwnedTerm, erl_atom, erl_int, erl_map};
 use erltf_serde::from_term;
-use ets_lib::{EtsTableInfo, Protection, TableType};
+use ets_lib::{EtsTableInfo, Protection, TableType, extrapolate_off_heap};
 
 #[test]
 fn test_table_type_deserialization_from_atoms() {
@@ -104,6 +104,9 @@ fn test_ets_table_info_serialization() {
         memory_bytes: 8192,
         owner: "<0.99.0>".to_string(),
         protection: Protection::Protected,
+        keypos: 1,
+        application: None,
+        off_heap_bytes: None,
     };
 
     let json = serde_json::to_string(&info).unwrap();
@@ -115,6 +118,40 @@ fn test_ets_table_info_serialization() {
     assert_eq!(deserialized.memory_bytes, info.memory_bytes);
     assert_eq!(deserialized.owner, info.owner);
     assert_eq!(deserialized.protection, info.protection);
+    assert_eq!(deserialized.keypos, info.keypos);
+}
+
+#[test]
+fn test_off_heap_bytes_are_only_serialized_when_estimated() {
+    let mut info = EtsTableInfo {
+        name: "msg_store".to_string(),
+        table_type: TableType::Set,
+        size: 10,
+        memory_bytes: 4096,
+        owner: "<0.99.0>".to_string(),
+        protection: Protection::Public,
+        keypos: 1,
+        application: None,
+        off_heap_bytes: None,
+    };
+
+    let json = serde_json::to_value(&info).unwrap();
+    assert!(json.get("off_heap_bytes").is_none());
+
+    info.off_heap_bytes = Some(1_000_000);
+    let json = serde_json::to_value(&info).unwrap();
+    assert_eq!(json["off_heap_bytes"], 1_000_000);
+}
+
+#[test]
+fn test_extrapolate_off_heap() {
+    // A full scan is reported as is.
+    assert_eq!(extrapolate_off_heap(10, 5000, 10), 5000);
+    // A sample of 100 out of 1000 objects is scaled up.
+    assert_eq!(extrapolate_off_heap(100, 5000, 1000), 50_000);
+    // Objects added after the listing can make the sample larger than the size.
+    assert_eq!(extrapolate_off_heap(12, 600, 10), 600);
+    assert_eq!(extrapolate_off_heap(0, 0, 10), 0);
 }
 
 #[test]
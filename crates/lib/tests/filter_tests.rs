@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use ets_lib::{EtsTableInfo, Protection, TableType, filter_tables};
+use ets_lib::{
+    ClusterTableInfo, DetsTableInfo, EtsTableInfo, Protection, TableType, filter_cluster_tables,
+    filter_dets_tables, filter_tables,
+};
 use regex::Regex;
 
 fn make_table(name: &str) -> EtsTableInfo {
@@ -23,6 +26,9 @@ fn make_table(name: &str) -> EtsTableInfo {
         memory_bytes: 0,
         owner: "<0.0.0>".to_string(),
         protection: Protection::Public,
+        keypos: 1,
+        application: None,
+        off_heap_bytes: None,
     }
 }
 
@@ -124,3 +130,42 @@ fn test_filter_with_case_insensitive_pattern() {
 
     assert_eq!(result.len(), 2);
 }
+
+#[test]
+fn test_filter_cluster_tables_matches_table_names() {
+    let tables = vec![
+        ClusterTableInfo {
+            node: "a@localhost".to_string(),
+            info: make_table("rabbit_queue"),
+        },
+        ClusterTableInfo {
+            node: "b@localhost".to_string(),
+            info: make_table("ra_log"),
+        },
+    ];
+
+    let pattern = Regex::new("^rabbit_").unwrap();
+    let result = filter_cluster_tables(tables, Some(&pattern));
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].node, "a@localhost");
+}
+
+#[test]
+fn test_filter_dets_tables_matches_table_names() {
+    let make_dets_table = |name: &str| DetsTableInfo {
+        name: name.to_string(),
+        file: format!("/var/lib/app/{}.dets", name),
+        table_type: TableType::Set,
+        size: 0,
+        file_size: 5432,
+        keypos: 1,
+    };
+    let tables = vec![make_dets_table("sessions"), make_dets_table("users")];
+
+    let pattern = Regex::new("^user").unwrap();
+    let result = filter_dets_tables(tables, Some(&pattern));
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].name, "users");
+}
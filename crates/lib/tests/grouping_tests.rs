@@ -0,0 +1,157 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ets_lib::{
+    Error, EtsTableInfo, GroupBy, NO_APPLICATION, NO_MATCH, OTHER_GROUP, Protection, TableType,
+    group_tables, top_groups,
+};
+use regex::Regex;
+
+fn make_table(name: &str, application: Option<&str>, memory_bytes: u64) -> EtsTableInfo {
+    EtsTableInfo {
+        name: name.to_string(),
+        table_type: TableType::Set,
+        size: 10,
+        memory_bytes,
+        owner: "<0.0.0>".to_string(),
+        protection: Protection::Public,
+        keypos: 1,
+        application: application.map(String::from),
+        off_heap_bytes: None,
+    }
+}
+
+#[test]
+fn test_group_by_application_aggregates_tables() {
+    let tables = vec![
+        make_table("rabbit_queue", Some("rabbit"), 3000),
+        make_table("rabbit_exchange", Some("rabbit"), 1000),
+        make_table("ra_log", Some("ra"), 1000),
+    ];
+
+    let groups = group_tables(&tables, &GroupBy::Application);
+
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].name, "rabbit");
+    assert_eq!(groups[0].table_count, 2);
+    assert_eq!(groups[0].object_count, 20);
+    assert_eq!(groups[0].memory_bytes, 4000);
+    assert!((groups[0].memory_percentage - 80.0).abs() < f64::EPSILON);
+    assert_eq!(groups[1].name, "ra");
+}
+
+#[test]
+fn test_group_by_application_collects_unresolved_owners() {
+    let tables = vec![make_table("orphan", None, 100)];
+
+    let groups = group_tables(&tables, &GroupBy::Application);
+
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].name, NO_APPLICATION);
+}
+
+#[test]
+fn test_group_tables_with_no_memory_has_zero_percentages() {
+    let tables = vec![make_table("empty", Some("app"), 0)];
+
+    let groups = group_tables(&tables, &GroupBy::Application);
+
+    assert_eq!(groups[0].memory_percentage, 0.0);
+}
+
+#[test]
+fn test_group_tables_empty_input() {
+    assert!(group_tables(&[], &GroupBy::Application).is_empty());
+}
+
+#[test]
+fn test_group_by_type() {
+    let mut bag = make_table("bag_table", None, 100);
+    bag.table_type = TableType::Bag;
+    let tables = vec![make_table("a", None, 100), make_table("b", None, 100), bag];
+
+    let groups = group_tables(&tables, &GroupBy::Type);
+
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].name, "set");
+    assert_eq!(groups[0].table_count, 2);
+    assert_eq!(groups[1].name, "bag");
+}
+
+#[test]
+fn test_group_by_regex_capture_uses_first_group() {
+    let tables = vec![
+        make_table("ra_log_ets", None, 100),
+        make_table("ra_log_meta", None, 100),
+        make_table("ra_directory", None, 50),
+        make_table("rabbit_queue", None, 10),
+    ];
+    let group_by = GroupBy::RegexCapture(Regex::new("^(ra_[a-z]+)").unwrap());
+
+    let groups = group_tables(&tables, &group_by);
+    let names: Vec<&str> = groups.iter().map(|g| g.name.as_str()).collect();
+
+    assert_eq!(names, vec!["ra_log", "ra_directory", NO_MATCH]);
+}
+
+#[test]
+fn test_group_by_regex_without_groups_uses_whole_match() {
+    let tables = vec![make_table("ra_log_ets", None, 100)];
+    let group_by = GroupBy::RegexCapture(Regex::new("^ra_").unwrap());
+
+    assert_eq!(group_tables(&tables, &group_by)[0].name, "ra_");
+}
+
+#[test]
+fn test_group_by_from_name() {
+    assert!(matches!(
+        GroupBy::from_name("owner", None),
+        Ok(GroupBy::Owner)
+    ));
+    assert!(GroupBy::from_name("regex-capture", None).is_err());
+    assert!(GroupBy::from_name("regex-capture", Some("(unclosed")).is_err());
+    assert!(GroupBy::from_name("color", None).is_err());
+    assert!(matches!(
+        GroupBy::from_name("owner", Some("^(ra_[a-z]+)")),
+        Err(Error::InvalidArgument(_))
+    ));
+}
+
+#[test]
+fn test_top_groups_folds_the_rest_into_other() {
+    let tables = vec![
+        make_table("a", Some("a"), 500),
+        make_table("b", Some("b"), 300),
+        make_table("c", Some("c"), 150),
+        make_table("d", Some("d"), 50),
+    ];
+
+    let groups = top_groups(group_tables(&tables, &GroupBy::Application), 2);
+
+    assert_eq!(groups.len(), 3);
+    assert_eq!(groups[2].name, OTHER_GROUP);
+    assert_eq!(groups[2].table_count, 2);
+    assert_eq!(groups[2].memory_bytes, 200);
+    assert!((groups[2].memory_percentage - 20.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_top_groups_keeps_everything_when_under_the_limit() {
+    let tables = vec![make_table("a", Some("a"), 500)];
+
+    let groups = top_groups(group_tables(&tables, &GroupBy::Application), 3);
+
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].name, "a");
+}
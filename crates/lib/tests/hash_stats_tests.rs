@@ -0,0 +1,81 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use erltf::{OwnedTerm, erl_atom, erl_int};
+use ets_lib::{Error, HashStats};
+
+fn stats_term(std_dev: f64, expected_std_dev: f64) -> OwnedTerm {
+    OwnedTerm::Tuple(vec![
+        erl_int!(256),
+        erl_int!(0),
+        erl_int!(9),
+        OwnedTerm::Float(1.5),
+        OwnedTerm::Float(std_dev),
+        OwnedTerm::Float(expected_std_dev),
+        erl_int!(3),
+    ])
+}
+
+#[test]
+fn test_hash_stats_parses_stats_tuple() {
+    let stats = HashStats::from_term(&stats_term(1.2, 1.1))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(stats.buckets, 256);
+    assert_eq!(stats.min_chain_len, 0);
+    assert_eq!(stats.max_chain_len, 9);
+    assert_eq!(stats.avg_chain_len, 1.5);
+    assert_eq!(stats.std_dev, 1.2);
+    assert_eq!(stats.expected_std_dev, 1.1);
+}
+
+#[test]
+fn test_hash_stats_is_none_for_non_hash_tables() {
+    assert!(HashStats::from_term(&erl_atom!("false")).unwrap().is_none());
+}
+
+#[test]
+fn test_hash_stats_rejects_short_tuples() {
+    let term = OwnedTerm::Tuple(vec![erl_int!(256), erl_int!(0)]);
+
+    assert!(matches!(
+        HashStats::from_term(&term),
+        Err(Error::UnexpectedResponse(_))
+    ));
+}
+
+#[test]
+fn test_hash_stats_detects_skew() {
+    let even = HashStats::from_term(&stats_term(1.2, 1.1))
+        .unwrap()
+        .unwrap();
+    let skewed = HashStats::from_term(&stats_term(6.0, 1.0))
+        .unwrap()
+        .unwrap();
+
+    assert!(!even.is_skewed(ets_lib::DEFAULT_SKEW_FACTOR));
+    assert!(skewed.is_skewed(ets_lib::DEFAULT_SKEW_FACTOR));
+    assert_eq!(skewed.deviation_ratio(), Some(6.0));
+}
+
+#[test]
+fn test_hash_stats_has_no_ratio_for_empty_tables() {
+    let stats = HashStats::from_term(&stats_term(0.0, 0.0))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(stats.deviation_ratio(), None);
+    assert!(!stats.is_skewed(ets_lib::DEFAULT_SKEW_FACTOR));
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ets_lib::{EtsTableInfo, Protection, TableSample, TableType, analyze_samples};
+
+fn make_table(name: &str, owner: &str, size: u64) -> EtsTableInfo {
+    EtsTableInfo {
+        name: name.to_string(),
+        table_type: TableType::Set,
+        size,
+        memory_bytes: size * 100,
+        owner: owner.to_string(),
+        protection: Protection::Protected,
+        keypos: 1,
+        application: None,
+        off_heap_bytes: None,
+    }
+}
+
+/// `leaky` creates two more `conn_state` tables per sample, `cache` keeps
+/// growing a single table and `stable` never changes.
+fn make_samples() -> Vec<TableSample> {
+    (0..4)
+        .map(|i| {
+            let mut tables = vec![
+                make_table("stable", "<0.10.0>", 50),
+                make_table("cache", "<0.20.0>", 100 * (i + 1)),
+            ];
+            for _ in 0..(2 * i + 1) {
+                tables.push(make_table("conn_state", "<0.30.0>", 1));
+            }
+            TableSample {
+                elapsed_secs: i as f64 * 10.0,
+                tables,
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_owner_with_growing_table_count_is_flagged() {
+    let report = analyze_samples(&make_samples(), 1000);
+
+    let leaky = &report.owners[0];
+    assert_eq!(leaky.key, "<0.30.0>");
+    assert_eq!(leaky.table_counts, [1, 3, 5, 7]);
+    assert!(leaky.count_growing);
+    assert!((leaky.tables_per_minute - 12.0).abs() < 1e-9);
+
+    // 9 tables in the last sample, 991 to go at 12 tables per minute.
+    let eta = leaky.seconds_to_ets_limit.unwrap();
+    assert!((eta - 991.0 / 12.0 * 60.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_owner_with_growing_table_size_is_flagged() {
+    let report = analyze_samples(&make_samples(), 1000);
+
+    let cache = report.owners.iter().find(|o| o.key == "<0.20.0>").unwrap();
+    assert!(!cache.count_growing);
+    assert!(cache.size_growing);
+    assert!(cache.seconds_to_ets_limit.is_none());
+    assert!((cache.objects_per_minute - 600.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_stable_owner_is_not_flagged() {
+    let report = analyze_samples(&make_samples(), 1000);
+
+    let growing: Vec<&str> = report.growing_owners().map(|o| o.key.as_str()).collect();
+    assert_eq!(growing.len(), 2);
+    assert!(!growing.contains(&"<0.10.0>"));
+    assert_eq!(report.owners.last().unwrap().key, "<0.10.0>");
+}
+
+#[test]
+fn test_counts_are_reported_per_name() {
+    let report = analyze_samples(&make_samples(), 1000);
+
+    assert_eq!(report.table_counts, [3, 5, 7, 9]);
+    let conn_state = report.names.iter().find(|n| n.key == "conn_state").unwrap();
+    assert_eq!(conn_state.table_counts, [1, 3, 5, 7]);
+    assert!(conn_state.count_growing);
+}
+
+#[test]
+fn test_fluctuating_counts_are_not_monotonic_growth() {
+    let samples: Vec<TableSample> = [1usize, 3, 2, 4]
+        .iter()
+        .enumerate()
+        .map(|(i, n)| TableSample {
+            elapsed_secs: i as f64,
+            tables: (0..*n).map(|_| make_table("tmp", "<0.40.0>", 1)).collect(),
+        })
+        .collect();
+
+    let report = analyze_samples(&samples, 1000);
+    assert!(!report.owners[0].count_growing);
+    assert_eq!(report.growing_owners().count(), 0);
+}
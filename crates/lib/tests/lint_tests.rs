@@ -0,0 +1,158 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ets_lib::{
+    EtsTableInfo, LintFinding, LintThresholds, OwnerProcessInfo, Protection, Severity,
+    TableLintInput, TableType, lint_tables,
+};
+
+fn make_input(
+    name: &str,
+    table_type: TableType,
+    protection: Protection,
+    size: u64,
+) -> TableLintInput {
+    TableLintInput {
+        info: EtsTableInfo {
+            name: name.to_string(),
+            table_type,
+            size,
+            memory_bytes: size * 100,
+            owner: "<0.90.0>".to_string(),
+            protection,
+            keypos: 1,
+            application: None,
+            off_heap_bytes: None,
+        },
+        write_concurrency: false,
+        read_concurrency: false,
+        has_heir: false,
+        owner: Some(OwnerProcessInfo {
+            message_queue_len: 0,
+            registered_name: Some("my_server".to_string()),
+        }),
+    }
+}
+
+fn lint(tables: &[TableLintInput]) -> Vec<LintFinding> {
+    lint_tables(tables, 100, 8192, &LintThresholds::default())
+}
+
+fn rules(findings: &[LintFinding]) -> Vec<&'static str> {
+    findings.iter().map(|f| f.rule).collect()
+}
+
+#[test]
+fn test_well_configured_tables_have_no_findings() {
+    let tables = vec![
+        make_input("small", TableType::Set, Protection::Public, 10),
+        make_input(
+            "big_protected",
+            TableType::Set,
+            Protection::Protected,
+            1_000_000,
+        ),
+    ];
+
+    assert!(lint(&tables).is_empty());
+}
+
+#[test]
+fn test_large_public_set_without_write_concurrency() {
+    let mut tables = vec![make_input(
+        "sessions",
+        TableType::Set,
+        Protection::Public,
+        200_000,
+    )];
+    assert_eq!(rules(&lint(&tables)), ["set-without-write-concurrency"]);
+
+    tables[0].write_concurrency = true;
+    assert!(lint(&tables).is_empty());
+}
+
+#[test]
+fn test_read_concurrency_on_write_heavy_table() {
+    let mut input = make_input("counters", TableType::Set, Protection::Public, 10);
+    input.write_concurrency = true;
+    input.read_concurrency = true;
+
+    let findings = lint(&[input]);
+    assert_eq!(rules(&findings), ["read-concurrency-on-write-heavy-table"]);
+    assert_eq!(findings[0].severity, Severity::Info);
+}
+
+#[test]
+fn test_owner_message_queue_severity_scales() {
+    let mut input = make_input("registry", TableType::Set, Protection::Protected, 10);
+    input.owner.as_mut().unwrap().message_queue_len = 20_000;
+    assert_eq!(
+        lint(std::slice::from_ref(&input))[0].severity,
+        Severity::Warning
+    );
+
+    input.owner.as_mut().unwrap().message_queue_len = 200_000;
+    let findings = lint(&[input]);
+    assert_eq!(rules(&findings), ["owner-message-queue"]);
+    assert_eq!(findings[0].severity, Severity::Critical);
+}
+
+#[test]
+fn test_public_table_without_heir_owned_by_unregistered_process() {
+    let mut input = make_input("cache", TableType::Set, Protection::Public, 10);
+    input.owner.as_mut().unwrap().registered_name = None;
+    assert_eq!(
+        rules(&lint(std::slice::from_ref(&input))),
+        ["public-table-without-heir"]
+    );
+
+    input.has_heir = true;
+    assert!(lint(&[input]).is_empty());
+}
+
+#[test]
+fn test_ets_limit_is_a_node_wide_finding() {
+    let thresholds = LintThresholds::default();
+
+    let findings = lint_tables(&[], 7000, 8192, &thresholds);
+    assert_eq!(rules(&findings), ["ets-limit"]);
+    assert_eq!(findings[0].severity, Severity::Warning);
+    assert!(findings[0].table.is_none());
+
+    let findings = lint_tables(&[], 8000, 8192, &thresholds);
+    assert_eq!(findings[0].severity, Severity::Critical);
+
+    assert!(lint_tables(&[], 100, 8192, &thresholds).is_empty());
+}
+
+#[test]
+fn test_findings_are_sorted_by_severity() {
+    let mut queue = make_input("a_queue", TableType::Set, Protection::Protected, 10);
+    queue.owner.as_mut().unwrap().message_queue_len = 500_000;
+    let mut rc = make_input("b_counters", TableType::Set, Protection::Public, 10);
+    rc.write_concurrency = true;
+    rc.read_concurrency = true;
+
+    let findings = lint(&[rc, queue]);
+    let severities: Vec<Severity> = findings.iter().map(|f| f.severity).collect();
+    assert_eq!(severities, [Severity::Critical, Severity::Info]);
+}
+
+#[test]
+fn test_severity_names_round_trip() {
+    for name in Severity::NAMES {
+        assert_eq!(Severity::from_name(name).unwrap().to_string(), name);
+    }
+    assert!(Severity::from_name("fatal").is_err());
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use erltf::{OwnedTerm, erl_atom, erl_int, erl_tuple};
+use ets_lib::MnesiaTableInfo;
+
+fn user_table() -> MnesiaTableInfo {
+    MnesiaTableInfo {
+        name: "users".to_string(),
+        storage_type: "disc_copies".to_string(),
+        ram_copies: vec![],
+        disc_copies: vec!["rabbit@node1".to_string()],
+        disc_only_copies: vec![],
+        record_name: "user".to_string(),
+        attributes: vec!["name".to_string(), "age".to_string()],
+        size: 1,
+        memory_bytes: 1024,
+    }
+}
+
+#[test]
+fn test_render_record_uses_attribute_names() {
+    let record = erl_tuple!(
+        erl_atom!("user"),
+        OwnedTerm::binary(b"guest".to_vec()),
+        erl_int!(42)
+    );
+
+    assert_eq!(
+        user_table().render_record(&record),
+        "#user{name = <<\"guest\">>, age = 42}"
+    );
+}
+
+#[test]
+fn test_render_record_falls_back_on_arity_mismatch() {
+    let record = erl_tuple!(erl_atom!("user"), erl_int!(1));
+
+    assert_eq!(user_table().render_record(&record), "{user, 1}");
+}
+
+#[test]
+fn test_render_record_falls_back_on_other_record_names() {
+    let record = erl_tuple!(erl_atom!("group"), erl_int!(1), erl_int!(2));
+
+    assert_eq!(user_table().render_record(&record), "{group, 1, 2}");
+}
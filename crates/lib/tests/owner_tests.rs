@@ -0,0 +1,51 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ets_lib::{Error, MAX_OWNER_STATE_TIMEOUT_MS, OwnerStateKind, get_owner_state};
+
+#[test]
+fn test_owner_state_kind_round_trips_names() {
+    for name in OwnerStateKind::NAMES {
+        assert_eq!(OwnerStateKind::from_name(name).unwrap().name(), name);
+    }
+}
+
+#[test]
+fn test_owner_state_kind_defaults_to_state() {
+    assert_eq!(OwnerStateKind::default(), OwnerStateKind::State);
+}
+
+#[test]
+fn test_owner_state_kind_rejects_unknown_names() {
+    assert!(matches!(
+        OwnerStateKind::from_name("heap"),
+        Err(Error::InvalidArgument(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_owner_state_rejects_out_of_range_timeouts() {
+    for timeout_ms in [0, MAX_OWNER_STATE_TIMEOUT_MS + 1] {
+        let result = get_owner_state(
+            "test@localhost",
+            "test",
+            "my_table",
+            OwnerStateKind::State,
+            timeout_ms,
+        )
+        .await;
+
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+}
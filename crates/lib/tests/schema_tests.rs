@@ -0,0 +1,299 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use erltf::{OwnedTerm, erl_atom, erl_int};
+use ets_lib::{TableSchema, TermKind, TermShape};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+fn binary(value: &str) -> OwnedTerm {
+    OwnedTerm::Binary(value.as_bytes().to_vec())
+}
+
+fn user(id: i64, name: &str, status: &str, email: Option<&str>) -> OwnedTerm {
+    let mut profile = BTreeMap::from([(erl_atom!("name"), binary(name))]);
+    if let Some(email) = email {
+        profile.insert(erl_atom!("email"), binary(email));
+    }
+    OwnedTerm::Tuple(vec![
+        erl_atom!("user"),
+        erl_int!(id),
+        OwnedTerm::Map(profile),
+        erl_atom!(status),
+        if id % 2 == 0 {
+            erl_atom!("undefined")
+        } else {
+            OwnedTerm::Float(id as f64)
+        },
+    ])
+}
+
+fn users() -> Vec<OwnedTerm> {
+    vec![
+        user(1, "alice", "active", Some("alice@example.com")),
+        user(2, "bob", "suspended", None),
+        user(3, "carol", "active", Some("carol@example.com")),
+        user(4, "dave", "active", None),
+    ]
+}
+
+#[test]
+fn test_schema_counts_kinds_per_position() {
+    let schema = TableSchema::infer("users", 10, &users());
+
+    assert_eq!(schema.sampled_objects, 4);
+    assert_eq!(schema.total_objects, 10);
+    assert_eq!(schema.arity(), Some(5));
+
+    let id = &schema.object.tuple_elements[1];
+    assert_eq!(id.count(TermKind::Integer), 4);
+    assert_eq!(id.summary(), "integer");
+}
+
+#[test]
+fn test_schema_tracks_undefined_frequency() {
+    let schema = TableSchema::infer("users", 4, &users());
+    let score = &schema.object.tuple_elements[4];
+
+    assert!(score.is_nullable());
+    assert_eq!(score.undefined_ratio(), 0.5);
+    assert_eq!(score.summary(), "float 50%, undefined 50%");
+}
+
+#[test]
+fn test_schema_detects_enum_like_atoms() {
+    let schema = TableSchema::infer("users", 4, &users());
+
+    assert_eq!(
+        schema.object.tuple_elements[3].enum_values(),
+        Some(vec!["active", "suspended"])
+    );
+    assert_eq!(
+        schema.object.tuple_elements[0].enum_values(),
+        Some(vec!["user"])
+    );
+    assert!(
+        schema.object.tuple_elements[0]
+            .summary()
+            .ends_with("always 'user'")
+    );
+}
+
+#[test]
+fn test_schema_does_not_treat_unique_atoms_as_enums() {
+    let objects: Vec<OwnedTerm> = ["a", "b", "c"]
+        .iter()
+        .map(|name| OwnedTerm::Tuple(vec![erl_atom!(*name)]))
+        .collect();
+    let schema = TableSchema::infer("names", 3, &objects);
+
+    assert_eq!(schema.object.tuple_elements[0].enum_values(), None);
+}
+
+#[test]
+fn test_schema_drops_atom_values_past_the_enum_limit() {
+    let mut shape = TermShape::default();
+    for i in 0..=ets_lib::schema::MAX_ENUM_VALUES {
+        shape.observe(&erl_atom!(format!("value_{}", i).as_str()));
+    }
+
+    assert!(shape.atom_values_truncated);
+    assert!(shape.atom_values.is_empty());
+    assert_eq!(shape.enum_values(), None);
+}
+
+#[test]
+fn test_schema_collects_nested_map_keys() {
+    let schema = TableSchema::infer("users", 4, &users());
+    let profile = &schema.object.tuple_elements[2];
+
+    assert_eq!(
+        profile.map_keys.keys().collect::<Vec<_>>(),
+        vec!["email", "name"]
+    );
+    assert_eq!(profile.required_map_keys(), vec!["name"]);
+}
+
+#[test]
+fn test_schema_records_every_observed_arity() {
+    let objects = vec![
+        OwnedTerm::Tuple(vec![erl_int!(1), erl_int!(2)]),
+        OwnedTerm::Tuple(vec![erl_int!(1), erl_int!(2), erl_int!(3)]),
+        OwnedTerm::Tuple(vec![erl_int!(1), erl_int!(2), erl_int!(3)]),
+    ];
+    let schema = TableSchema::infer("mixed", 3, &objects);
+
+    assert_eq!(
+        schema.object.tuple_arities,
+        BTreeMap::from([(2, 1), (3, 2)])
+    );
+    assert_eq!(schema.arity(), Some(3));
+    assert_eq!(schema.object.tuple_elements[2].observed, 2);
+}
+
+#[test]
+fn test_schema_merges_list_elements() {
+    let objects = vec![
+        OwnedTerm::Tuple(vec![
+            erl_int!(1),
+            OwnedTerm::List(vec![erl_int!(1), erl_int!(2)]),
+        ]),
+        OwnedTerm::Tuple(vec![erl_int!(2), OwnedTerm::Nil]),
+    ];
+    let schema = TableSchema::infer("lists", 2, &objects);
+    let tags = &schema.object.tuple_elements[1];
+
+    assert_eq!(tags.count(TermKind::List), 2);
+    assert_eq!(
+        tags.list_elements
+            .as_ref()
+            .unwrap()
+            .count(TermKind::Integer),
+        2
+    );
+}
+
+#[test]
+fn test_json_schema_describes_tuples_as_arrays() {
+    let schema = TableSchema::infer("users", 4, &users()).to_json_schema();
+
+    assert_eq!(schema["title"], "users");
+    assert_eq!(schema["type"], "array");
+    assert_eq!(schema["minItems"], 5);
+    assert_eq!(schema["prefixItems"][1]["type"], "integer");
+    assert_eq!(
+        schema["prefixItems"][3]["enum"],
+        serde_json::json!(["active", "suspended"])
+    );
+    assert_eq!(
+        schema["prefixItems"][2]["required"],
+        serde_json::json!(["name"])
+    );
+    assert_eq!(
+        schema["prefixItems"][4]["anyOf"],
+        serde_json::json!([{ "type": "number" }, { "type": "null" }])
+    );
+}
+
+#[test]
+fn test_rust_definitions_cover_every_position() {
+    let rust = TableSchema::infer("users", 4, &users()).to_rust();
+
+    assert!(rust.starts_with("use serde::Deserialize;"));
+    assert!(rust.contains("pub struct Users(\n"));
+    assert!(rust.contains("    pub String,\n"));
+    assert!(rust.contains("    pub i64,\n"));
+    assert!(rust.contains("    pub UsersField3,\n"));
+    assert!(rust.contains("    pub UsersField4,\n"));
+    assert!(rust.contains("    pub Option<f64>,\n"));
+    assert!(rust.contains(
+        "pub struct UsersField3 {\n    pub email: Option<String>,\n    pub name: String,\n}"
+    ));
+    assert!(rust.contains("    #[serde(rename = \"suspended\")]\n    Suspended,"));
+}
+
+#[test]
+fn test_rust_definitions_rename_fields_that_are_not_identifiers() {
+    let objects = vec![OwnedTerm::Tuple(vec![
+        erl_int!(1),
+        OwnedTerm::Map(BTreeMap::from([
+            (erl_atom!("type"), erl_int!(1)),
+            (erl_atom!("createdAt"), erl_int!(2)),
+        ])),
+    ])];
+    let rust = TableSchema::infer("events", 1, &objects).to_rust();
+
+    assert!(rust.contains("    pub r#type: i64,"));
+    assert!(rust.contains("    #[serde(rename = \"createdAt\")]\n    pub created_at: i64,"));
+}
+
+#[test]
+fn test_rust_definitions_suffix_keywords_that_cannot_be_raw() {
+    let objects = vec![OwnedTerm::Tuple(vec![
+        erl_int!(1),
+        OwnedTerm::Map(BTreeMap::from([
+            (erl_atom!("self"), erl_int!(1)),
+            (erl_atom!("crate"), erl_int!(2)),
+            (erl_atom!("super"), erl_int!(3)),
+            (erl_atom!("box"), erl_int!(4)),
+            (erl_atom!("virtual"), erl_int!(5)),
+        ])),
+    ])];
+    let rust = TableSchema::infer("modules", 1, &objects).to_rust();
+
+    assert!(rust.contains("    #[serde(rename = \"self\")]\n    pub self_: i64,"));
+    assert!(rust.contains("    #[serde(rename = \"crate\")]\n    pub crate_: i64,"));
+    assert!(rust.contains("    #[serde(rename = \"super\")]\n    pub super_: i64,"));
+    assert!(rust.contains("    pub r#box: i64,"));
+    assert!(rust.contains("    pub r#virtual: i64,"));
+}
+
+#[test]
+fn test_rust_definitions_name_tables_that_are_not_identifiers() {
+    let objects = vec![OwnedTerm::Tuple(vec![erl_int!(1)])];
+
+    let rust = TableSchema::infer("1st_cache", 1, &objects).to_rust();
+    assert!(rust.contains("pub struct Table1stCache(\n"));
+
+    let rust = TableSchema::infer("$tab", 1, &objects).to_rust();
+    assert!(rust.contains("pub struct Tab(\n"));
+
+    let rust = TableSchema::infer("self", 1, &objects).to_rust();
+    assert!(rust.contains("pub struct Self_(\n"));
+}
+
+#[test]
+fn test_rust_definitions_ignore_terms_that_cannot_be_deserialized() {
+    let objects = vec![
+        OwnedTerm::Tuple(vec![erl_int!(1), erl_int!(1)]),
+        OwnedTerm::Tuple(vec![erl_int!(2), binary("two")]),
+    ];
+    let rust = TableSchema::infer("mixed", 2, &objects).to_rust();
+
+    assert!(rust.contains("    pub serde::de::IgnoredAny,"));
+}
+
+// Mirrors the definitions generated for the users table above.
+#[derive(Debug, Deserialize)]
+struct Users(String, i64, UsersField3, UsersField4, Option<f64>);
+
+#[derive(Debug, Deserialize)]
+struct UsersField3 {
+    email: Option<String>,
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+enum UsersField4 {
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "suspended")]
+    Suspended,
+}
+
+#[test]
+fn test_generated_definitions_deserialize_sampled_objects() {
+    let objects = users();
+    let alice: Users = erltf_serde::from_term(&objects[0]).unwrap();
+    let bob: Users = erltf_serde::from_term(&objects[1]).unwrap();
+
+    assert_eq!(alice.0, "user");
+    assert_eq!(alice.2.name, "alice");
+    assert_eq!(alice.2.email.as_deref(), Some("alice@example.com"));
+    assert_eq!(alice.4, Some(1.0));
+    assert_eq!(bob.1, 2);
+    assert_eq!(bob.2.email, None);
+    assert_eq!(bob.3, UsersField4::Suspended);
+    assert_eq!(bob.4, None);
+}
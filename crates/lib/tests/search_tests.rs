@@ -0,0 +1,72 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use erltf::{OwnedTerm, erl_atom, erl_tuple};
+use ets_lib::search::{match_entry, render_entry};
+use regex::Regex;
+
+fn user_entry() -> OwnedTerm {
+    erl_tuple!(erl_atom!("user"), OwnedTerm::binary(b"guest".to_vec()))
+}
+
+#[test]
+fn test_render_entry_spells_out_printable_binaries() {
+    assert_eq!(render_entry(&user_entry()), "{user, <<\"guest\">>}");
+}
+
+#[test]
+fn test_render_entry_keeps_opaque_binaries_summarized() {
+    let entry = OwnedTerm::binary(vec![0, 1, 2]);
+    assert_eq!(render_entry(&entry), "<<3 bytes>>");
+}
+
+#[test]
+fn test_match_entry_returns_none_without_match() {
+    let re = Regex::new("admin").unwrap();
+    assert!(match_entry(&re, "users", 1, &user_entry()).is_none());
+}
+
+#[test]
+fn test_match_entry_records_ranges() {
+    let re = Regex::new("guest").unwrap();
+    let m = match_entry(&re, "users", 3, &user_entry()).unwrap();
+
+    assert_eq!(m.table_name, "users");
+    assert_eq!(m.index, 3);
+    assert_eq!(m.ranges.len(), 1);
+    let (start, end) = m.ranges[0];
+    assert_eq!(&m.entry[start..end], "guest");
+}
+
+#[test]
+fn test_match_entry_ignores_empty_matches() {
+    let re = Regex::new("x*").unwrap();
+    assert!(match_entry(&re, "users", 1, &user_entry()).is_none());
+}
+
+#[test]
+fn test_segments_cover_the_whole_entry() {
+    let re = Regex::new("u").unwrap();
+    let m = match_entry(&re, "users", 1, &user_entry()).unwrap();
+    let segments = m.segments();
+
+    let joined: String = segments.iter().map(|(text, _)| *text).collect();
+    assert_eq!(joined, m.entry);
+    assert!(
+        segments
+            .iter()
+            .any(|(text, matched)| *matched && *text == "u")
+    );
+    assert!(segments.iter().any(|(_, matched)| !matched));
+}
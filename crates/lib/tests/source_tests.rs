@@ -0,0 +1,158 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use erltf::{erl_atom, erl_int, erl_tuple};
+use ets_lib::{Error, EtsSource, EtsTableInfo, Protection, Snapshot, TableType};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+fn make_table(name: &str, size: u64, keypos: u64) -> EtsTableInfo {
+    EtsTableInfo {
+        name: name.to_string(),
+        table_type: TableType::Bag,
+        size,
+        memory_bytes: 1024,
+        owner: "<0.0.0>".to_string(),
+        protection: Protection::Public,
+        keypos,
+        application: None,
+        off_heap_bytes: None,
+    }
+}
+
+fn make_source() -> Arc<dyn EtsSource> {
+    let sessions = vec![
+        erl_tuple!(erl_atom!("session"), erl_int!(1), erl_atom!("alice")),
+        erl_tuple!(erl_atom!("session"), erl_int!(2), erl_atom!("bob")),
+        erl_tuple!(erl_atom!("session"), erl_int!(1), erl_atom!("carol")),
+    ];
+
+    Arc::new(Snapshot {
+        node: "rabbit@localhost".to_string(),
+        taken_at: 1_700_000_000,
+        tables: vec![
+            make_table("sessions", 3, 2),
+            make_table("private_tab", 5, 1),
+        ],
+        contents: BTreeMap::from([("sessions".to_string(), sessions)]),
+    })
+}
+
+#[tokio::test]
+async fn test_snapshot_source_lists_tables() {
+    let tables = make_source().list_tables().await.unwrap();
+
+    assert_eq!(tables.len(), 2);
+    assert_eq!(tables[0].name, "sessions");
+}
+
+#[tokio::test]
+async fn test_snapshot_source_returns_table_info() {
+    let source = make_source();
+
+    assert_eq!(source.table_info("sessions").await.unwrap().keypos, 2);
+    assert!(matches!(
+        source.table_info("missing").await,
+        Err(Error::TableNotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_snapshot_source_dumps_captured_contents() {
+    let source = make_source();
+
+    assert_eq!(source.dump_table("sessions").await.unwrap().len(), 3);
+    assert!(matches!(
+        source.dump_table("private_tab").await,
+        Err(Error::InvalidSnapshot(_))
+    ));
+    assert!(matches!(
+        source.dump_table("missing").await,
+        Err(Error::TableNotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_snapshot_source_pages_through_contents() {
+    let source = make_source();
+
+    let first = source.page_table("sessions", None, 2).await.unwrap();
+    assert_eq!(first.entries.len(), 2);
+    let next = first.next.expect("a second page");
+
+    let second = source.page_table("sessions", Some(next), 2).await.unwrap();
+    assert_eq!(second.entries.len(), 1);
+    assert!(second.next.is_none());
+}
+
+#[tokio::test]
+async fn test_snapshot_source_rejects_foreign_cursors() {
+    let result = make_source()
+        .page_table("sessions", Some(erl_atom!("alice")), 2)
+        .await;
+
+    assert!(matches!(result, Err(Error::InvalidArgument(_))));
+}
+
+#[tokio::test]
+async fn test_snapshot_source_looks_up_by_key_position() {
+    let source = make_source();
+
+    let objects = source.lookup_table("sessions", &erl_int!(1)).await.unwrap();
+    assert_eq!(objects.len(), 2);
+    assert_eq!(
+        objects[1].as_tuple().map(|t| t[2].clone()),
+        Some(erl_atom!("carol"))
+    );
+
+    assert!(
+        source
+            .lookup_table("sessions", &erl_int!(3))
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    assert!(matches!(
+        source.lookup_table("missing", &erl_int!(1)).await,
+        Err(Error::TableNotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_snapshot_source_returns_details_without_stats() {
+    let source = make_source();
+
+    let details = source.table_details("sessions", false).await.unwrap();
+    assert_eq!(details.info.name, "sessions");
+    assert!(details.hash_stats.is_none());
+
+    assert!(matches!(
+        source.table_details("sessions", true).await,
+        Err(Error::Unsupported(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_snapshot_source_rejects_live_only_queries() {
+    let source = make_source();
+
+    assert!(matches!(
+        source.list_tables_with_off_heap(10).await,
+        Err(Error::Unsupported(_))
+    ));
+    assert!(matches!(
+        source.list_cluster_tables().await,
+        Err(Error::Unsupported(_))
+    ));
+}
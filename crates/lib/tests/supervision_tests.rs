@@ -0,0 +1,85 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ets_lib::{EtsTableInfo, ProcessKind, ProcessNode, Protection, SupervisionTree, TableType};
+
+fn make_table(name: &str, memory_bytes: u64) -> EtsTableInfo {
+    EtsTableInfo {
+        name: name.to_string(),
+        table_type: TableType::Set,
+        size: 10,
+        memory_bytes,
+        owner: "<0.90.0>".to_string(),
+        protection: Protection::Public,
+        keypos: 1,
+        application: None,
+        off_heap_bytes: None,
+    }
+}
+
+fn make_node(name: &str, tables: Vec<EtsTableInfo>, children: Vec<ProcessNode>) -> ProcessNode {
+    ProcessNode {
+        name: name.to_string(),
+        pid: "<0.90.0>".to_string(),
+        kind: if children.is_empty() {
+            ProcessKind::Worker
+        } else {
+            ProcessKind::Supervisor
+        },
+        subtree_table_count: tables.len()
+            + children
+                .iter()
+                .map(|c| c.subtree_table_count)
+                .sum::<usize>(),
+        subtree_memory_bytes: tables.iter().map(|t| t.memory_bytes).sum::<u64>()
+            + children.iter().map(|c| c.subtree_memory_bytes).sum::<u64>(),
+        tables,
+        children,
+    }
+}
+
+#[test]
+fn test_prune_empty_keeps_paths_to_table_owners() {
+    let registry = make_node("registry", vec![make_table("my_registry", 2048)], vec![]);
+    let idle = make_node("idle_worker", vec![], vec![]);
+    let inner = make_node("inner_sup", vec![], vec![registry, idle]);
+    let app = make_node(
+        "my_app",
+        vec![],
+        vec![inner, make_node("other", vec![], vec![])],
+    );
+
+    let mut tree = SupervisionTree {
+        applications: vec![app, make_node("empty_app", vec![], vec![])],
+        unattributed: vec![],
+    };
+    tree.prune_empty();
+
+    assert_eq!(tree.applications.len(), 1);
+    let app = &tree.applications[0];
+    assert_eq!(app.subtree_memory_bytes, 2048);
+    assert_eq!(app.children.len(), 1);
+    assert_eq!(app.children[0].name, "inner_sup");
+    assert_eq!(app.children[0].children.len(), 1);
+    assert_eq!(app.children[0].children[0].name, "registry");
+}
+
+#[test]
+fn test_supervision_tree_serializes_process_kind_in_snake_case() {
+    let node = make_node("my_app", vec![], vec![make_node("w", vec![], vec![])]);
+    let json = serde_json::to_value(&node).unwrap();
+
+    assert_eq!(json["kind"], "supervisor");
+    assert_eq!(json["children"][0]["kind"], "worker");
+}
@@ -0,0 +1,166 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use erltf::{OwnedTerm, erl_atom, erl_int};
+use ets_lib::{Error, ObjectLayout, from_object, from_proplist};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+fn binary(value: &str) -> OwnedTerm {
+    OwnedTerm::Binary(value.as_bytes().to_vec())
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Session {
+    id: i64,
+    user: String,
+    expires_at: Option<i64>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct SessionTuple(i64, String);
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Settings {
+    name: String,
+    limits: Limits,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Limits {
+    max_connections: i64,
+}
+
+#[test]
+fn test_tuple_elements_fill_named_fields_in_order() {
+    let object = OwnedTerm::Tuple(vec![erl_int!(7), binary("alice"), erl_int!(1700)]);
+    let session: Session = from_object(&object, &ObjectLayout::Positional).unwrap();
+
+    assert_eq!(
+        session,
+        Session {
+            id: 7,
+            user: "alice".to_string(),
+            expires_at: Some(1700),
+        }
+    );
+}
+
+#[test]
+fn test_undefined_and_missing_trailing_elements_become_none() {
+    let undefined = OwnedTerm::Tuple(vec![erl_int!(7), binary("alice"), erl_atom!("undefined")]);
+    let short = OwnedTerm::Tuple(vec![erl_int!(8), binary("bob")]);
+
+    let first: Session = from_object(&undefined, &ObjectLayout::Positional).unwrap();
+    let second: Session = from_object(&short, &ObjectLayout::Positional).unwrap();
+
+    assert_eq!(first.expires_at, None);
+    assert_eq!(second.expires_at, None);
+}
+
+#[test]
+fn test_tuples_with_more_elements_than_fields_are_rejected() {
+    let object = OwnedTerm::Tuple(vec![
+        erl_int!(7),
+        binary("alice"),
+        erl_int!(1700),
+        erl_int!(1),
+    ]);
+
+    assert!(matches!(
+        from_object::<Session>(&object, &ObjectLayout::Positional),
+        Err(Error::Deserialization(_))
+    ));
+}
+
+#[test]
+fn test_tuple_structs_are_filled_element_by_element() {
+    let object = OwnedTerm::Tuple(vec![erl_int!(7), binary("alice")]);
+    let session: SessionTuple = from_object(&object, &ObjectLayout::Positional).unwrap();
+
+    assert_eq!(session, SessionTuple(7, "alice".to_string()));
+}
+
+#[test]
+fn test_records_skip_a_matching_tag() {
+    let object = OwnedTerm::Tuple(vec![
+        erl_atom!("session"),
+        erl_int!(7),
+        binary("alice"),
+        erl_atom!("undefined"),
+    ]);
+    let layout = ObjectLayout::Record("session".to_string());
+    let session: Session = from_object(&object, &layout).unwrap();
+
+    assert_eq!(session.id, 7);
+    assert_eq!(session.user, "alice");
+}
+
+#[test]
+fn test_records_with_another_tag_are_rejected() {
+    let object = OwnedTerm::Tuple(vec![erl_atom!("user"), erl_int!(7), binary("alice")]);
+    let layout = ObjectLayout::Record("session".to_string());
+
+    assert!(matches!(
+        from_object::<Session>(&object, &layout),
+        Err(Error::Deserialization(_))
+    ));
+}
+
+#[test]
+fn test_maps_deserialize_into_structs() {
+    let object = OwnedTerm::Map(BTreeMap::from([
+        (erl_atom!("id"), erl_int!(7)),
+        (erl_atom!("user"), binary("alice")),
+    ]));
+    let session: Session = from_object(&object, &ObjectLayout::Positional).unwrap();
+
+    assert_eq!(session.user, "alice");
+    assert_eq!(session.expires_at, None);
+}
+
+#[test]
+fn test_malformed_objects_fail_individually() {
+    let objects = [
+        OwnedTerm::Tuple(vec![erl_int!(7), binary("alice")]),
+        OwnedTerm::Tuple(vec![binary("oops"), binary("bob")]),
+        OwnedTerm::Tuple(vec![erl_int!(9), binary("carol")]),
+    ];
+    let sessions: Vec<Result<Session, Error>> = objects
+        .iter()
+        .map(|o| from_object(o, &ObjectLayout::Positional))
+        .collect();
+
+    assert!(sessions[0].is_ok());
+    assert!(sessions[1].is_err());
+    assert!(sessions[2].is_ok());
+}
+
+#[test]
+fn test_nested_proplists_deserialize_into_structs() {
+    let proplist = OwnedTerm::List(vec![
+        OwnedTerm::Tuple(vec![erl_atom!("name"), binary("default")]),
+        OwnedTerm::Tuple(vec![
+            erl_atom!("limits"),
+            OwnedTerm::List(vec![OwnedTerm::Tuple(vec![
+                erl_atom!("max_connections"),
+                erl_int!(100),
+            ])]),
+        ]),
+    ]);
+    let settings: Settings = from_proplist(&proplist).unwrap();
+
+    assert_eq!(settings.name, "default");
+    assert_eq!(settings.limits.max_connections, 100);
+}
@@ -8,6 +8,8 @@ export interface EtsTableInfo {
   memory_bytes: number
   owner: string
   protection: Protection
+  keypos: number
+  off_heap_bytes?: number
 }
 
 export interface TablesListResponse {
@@ -15,6 +17,65 @@ export interface TablesListResponse {
   total: number
 }
 
+export interface ClusterTableInfo extends EtsTableInfo {
+  node: string
+}
+
+export interface NodeFailure {
+  node: string
+  reason: string
+}
+
+export interface ClusterTablesResponse {
+  tables: ClusterTableInfo[]
+  failures: NodeFailure[]
+}
+
+export interface DetsTableInfo {
+  name: string
+  file: string
+  table_type: TableType
+  size: number
+  file_size: number
+  keypos: number
+}
+
+export interface DetsTablesListResponse {
+  tables: DetsTableInfo[]
+  total: number
+}
+
+export interface MnesiaTableInfo {
+  name: string
+  storage_type: string
+  ram_copies: string[]
+  disc_copies: string[]
+  disc_only_copies: string[]
+  record_name: string
+  attributes: string[]
+  size: number
+  memory_bytes: number
+}
+
+export interface MnesiaTablesListResponse {
+  tables: MnesiaTableInfo[]
+  total: number
+}
+
+export interface TableGroup {
+  name: string
+  table_count: number
+  object_count: number
+  memory_bytes: number
+  memory_percentage: number
+}
+
+export interface MemoryBreakdownResponse {
+  groups: TableGroup[]
+  table_count: number
+  total_memory_bytes: number
+}
+
 export interface TableEntry {
   index: number
   entry: string
@@ -24,14 +85,147 @@ export interface TableContentsResponse {
   table_name: string
   entries: TableEntry[]
   total: number
+  next_cursor?: string
+}
+
+export interface EntrySegment {
+  text: string
+  matched: boolean
+}
+
+export interface SearchMatch {
+  table_name: string
+  index: number
+  segments: EntrySegment[]
+}
+
+export interface StoppedTable {
+  table_name: string
+  entries_searched: number
+  error: string | null
+}
+
+export interface SearchResponse {
+  matches: SearchMatch[]
+  total: number
+  tables_searched: number
+  skipped_private: string[]
+  stopped_early: StoppedTable[]
+  truncated: boolean
+}
+
+export interface PersistentTermInfo {
+  key: string
+  memory_bytes: number
+}
+
+export interface PersistentTermsResponse {
+  terms: PersistentTermInfo[]
+  count: number
+  total_memory_bytes: number
+}
+
+export interface PersistentTermResponse {
+  key: string
+  value: string
+}
+
+export interface HashStats {
+  buckets: number
+  min_chain_len: number
+  max_chain_len: number
+  avg_chain_len: number
+  std_dev: number
+  expected_std_dev: number
+}
+
+export interface TableDetails {
+  info: EtsTableInfo
+  hash_stats?: HashStats
+  skewed: boolean
+}
+
+export type OwnerStateKind = 'state' | 'status'
+
+export interface OwnerStateResponse {
+  table: string
+  owner: string
+  kind: OwnerStateKind
+  value: string
+}
+
+export type ProcessKind = 'supervisor' | 'worker'
+
+export interface ProcessNode {
+  name: string
+  pid: string
+  kind: ProcessKind
+  tables: EtsTableInfo[]
+  subtree_table_count: number
+  subtree_memory_bytes: number
+  children: ProcessNode[]
+}
+
+export interface SupervisionTreeResponse {
+  applications: ProcessNode[]
+  unattributed: EtsTableInfo[]
+}
+
+export interface AllocatorInstance {
+  instance: number
+  blocks_bytes: number
+  carriers_bytes: number
+  mbcs_carriers: number
+  sbcs_carriers: number
+  fragmentation: number
+}
+
+export interface EtsAllocReport {
+  enabled: boolean
+  instances: AllocatorInstance[]
+  blocks_bytes: number
+  carriers_bytes: number
+  fragmentation: number
+  erlang_memory_ets_bytes: number
+  tables_memory_bytes: number
+  table_count: number
+  unattributed_bytes: number
+  allocator_overhead_bytes: number
 }
 
 export interface ApiError {
   error: string
 }
 
-export async function fetchTablesList(): Promise<TablesListResponse> {
-  const response = await fetch('/api/v1/tables/list')
+export async function fetchTablesList(offHeap: boolean = false): Promise<TablesListResponse> {
+  const response = await fetch(`/api/v1/tables/list?${new URLSearchParams({ off_heap: String(offHeap) })}`)
+  if (!response.ok) {
+    const error: ApiError = await response.json()
+    throw new Error(error.error)
+  }
+  return response.json()
+}
+
+export async function fetchClusterTablesList(): Promise<ClusterTablesResponse> {
+  const response = await fetch('/api/v1/cluster/tables/list')
+  if (!response.ok) {
+    const error: ApiError = await response.json()
+    throw new Error(error.error)
+  }
+  return response.json()
+}
+
+export interface MemoryBreakdownQuery {
+  groupBy: string
+  groupRegex: string | null
+  top: string | null
+}
+
+export async function fetchMemoryBreakdown(query: MemoryBreakdownQuery): Promise<MemoryBreakdownResponse> {
+  const params = new URLSearchParams({ group_by: query.groupBy })
+  if (query.groupBy === 'regex-capture' && query.groupRegex) params.set('group_regex', query.groupRegex)
+  if (query.top) params.set('top', query.top)
+  const response = await fetch(`/api/v1/tables/memory_breakdown?${params}`)
   if (!response.ok) {
     const error: ApiError = await response.json()
     throw new Error(error.error)
@@ -48,6 +242,107 @@ export async function fetchTableContents(name: string): Promise<TableContentsRes
   return response.json()
 }
 
+export async function fetchTableInfo(name: string, stats: boolean): Promise<TableDetails> {
+  const response = await fetch(`/api/v1/tables/${encodeURIComponent(name)}/info?${new URLSearchParams({ stats: String(stats) })}`)
+  if (!response.ok) {
+    const error: ApiError = await response.json()
+    throw new Error(error.error)
+  }
+  return response.json()
+}
+
+export async function fetchOwnerState(name: string, kind: OwnerStateKind): Promise<OwnerStateResponse> {
+  const response = await fetch(`/api/v1/tables/${encodeURIComponent(name)}/owner_state?${new URLSearchParams({ kind })}`)
+  if (!response.ok) {
+    const error: ApiError = await response.json()
+    throw new Error(error.error)
+  }
+  return response.json()
+}
+
+export async function fetchDetsTablesList(): Promise<DetsTablesListResponse> {
+  const response = await fetch('/api/v1/dets/list')
+  if (!response.ok) {
+    const error: ApiError = await response.json()
+    throw new Error(error.error)
+  }
+  return response.json()
+}
+
+export async function fetchDetsTableContents(name: string): Promise<TableContentsResponse> {
+  const response = await fetch(`/api/v1/dets/${encodeURIComponent(name)}/contents`)
+  if (!response.ok) {
+    const error: ApiError = await response.json()
+    throw new Error(error.error)
+  }
+  return response.json()
+}
+
+export async function fetchMnesiaTablesList(): Promise<MnesiaTablesListResponse> {
+  const response = await fetch('/api/v1/mnesia/list')
+  if (!response.ok) {
+    const error: ApiError = await response.json()
+    throw new Error(error.error)
+  }
+  return response.json()
+}
+
+export async function fetchMnesiaTableContents(name: string): Promise<TableContentsResponse> {
+  const response = await fetch(`/api/v1/mnesia/${encodeURIComponent(name)}/contents`)
+  if (!response.ok) {
+    const error: ApiError = await response.json()
+    throw new Error(error.error)
+  }
+  return response.json()
+}
+
+export async function fetchSearchResults(regex: string, pattern: string | null): Promise<SearchResponse> {
+  const params = new URLSearchParams({ regex })
+  if (pattern) params.set('pattern', pattern)
+  const response = await fetch(`/api/v1/search?${params}`)
+  if (!response.ok) {
+    const error: ApiError = await response.json()
+    throw new Error(error.error)
+  }
+  return response.json()
+}
+
+export async function fetchPersistentTerms(): Promise<PersistentTermsResponse> {
+  const response = await fetch('/api/v1/persistent_terms/list')
+  if (!response.ok) {
+    const error: ApiError = await response.json()
+    throw new Error(error.error)
+  }
+  return response.json()
+}
+
+export async function fetchPersistentTerm(key: string): Promise<PersistentTermResponse> {
+  const response = await fetch(`/api/v1/persistent_terms/get?${new URLSearchParams({ key })}`)
+  if (!response.ok) {
+    const error: ApiError = await response.json()
+    throw new Error(error.error)
+  }
+  return response.json()
+}
+
+export async function fetchSupervisionTree(all: boolean): Promise<SupervisionTreeResponse> {
+  const response = await fetch(`/api/v1/tables/tree?${new URLSearchParams({ all: String(all) })}`)
+  if (!response.ok) {
+    const error: ApiError = await response.json()
+    throw new Error(error.error)
+  }
+  return response.json()
+}
+
+export async function fetchEtsAllocReport(): Promise<EtsAllocReport> {
+  const response = await fetch('/api/v1/node/ets_alloc')
+  if (!response.ok) {
+    const error: ApiError = await response.json()
+    throw new Error(error.error)
+  }
+  return response.json()
+}
+
 export function formatBytes(bytes: number): string {
   if (bytes < 1024) return `${bytes} B`
   if (bytes < 1024 * 1024) return `${(bytes / 1024).toFixed(1)} KiB`
@@ -0,0 +1,72 @@
+import { fetchClusterTablesList, formatBytes, ClusterTableInfo, NodeFailure } from './api'
+import { escapeHtml } from './utils'
+
+function createClusterTableRow(table: ClusterTableInfo): string {
+  return `
+    <tr>
+      <td>${escapeHtml(table.node)}</td>
+      <td>${escapeHtml(table.name)}</td>
+      <td>${escapeHtml(table.table_type)}</td>
+      <td class="text-end">${table.size.toLocaleString()}</td>
+      <td class="text-end">${formatBytes(table.memory_bytes)}</td>
+      <td>${escapeHtml(table.owner)}</td>
+      <td>${escapeHtml(table.protection)}</td>
+    </tr>
+  `
+}
+
+function createFailureAlert(failure: NodeFailure): string {
+  return `
+    <div class="alert alert-warning" role="alert">
+      <strong>${escapeHtml(failure.node)}</strong> did not respond: ${escapeHtml(failure.reason)}
+    </div>
+  `
+}
+
+export async function renderClusterTablesList(container: HTMLElement): Promise<void> {
+  container.innerHTML = `
+    <div class="loading">
+      <div class="spinner-border text-primary" role="status">
+        <span class="visually-hidden">Loading...</span>
+      </div>
+    </div>
+  `
+
+  try {
+    const response = await fetchClusterTablesList()
+    const nodeCount = new Set(response.tables.map((t) => t.node)).size
+
+    container.innerHTML = `
+      <div class="d-flex justify-content-between align-items-center mb-3">
+        <h2>Cluster ETS Tables</h2>
+        <span class="badge bg-secondary">${response.tables.length} tables on ${nodeCount} nodes</span>
+      </div>
+      ${response.failures.map(createFailureAlert).join('')}
+      <div class="table-responsive">
+        <table class="table table-striped table-hover">
+          <thead class="table-dark">
+            <tr>
+              <th>Node</th>
+              <th>Name</th>
+              <th>Type</th>
+              <th class="text-end">Objects</th>
+              <th class="text-end">Memory</th>
+              <th>Owner</th>
+              <th>Protection</th>
+            </tr>
+          </thead>
+          <tbody>
+            ${response.tables.map(createClusterTableRow).join('')}
+          </tbody>
+        </table>
+      </div>
+    `
+  } catch (error) {
+    const message = error instanceof Error ? error.message : 'Unknown error'
+    container.innerHTML = `
+      <div class="alert alert-danger" role="alert">
+        <strong>Error:</strong> ${escapeHtml(message)}
+      </div>
+    `
+  }
+}
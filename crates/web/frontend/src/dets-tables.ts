@@ -0,0 +1,65 @@
+import { fetchDetsTableContents, fetchDetsTablesList, formatBytes, DetsTableInfo } from './api'
+import { TableSource } from './table-contents'
+import { escapeHtml } from './utils'
+
+export const DETS_TABLES: TableSource = {
+  label: 'DETS',
+  listPath: '/dets',
+  fetchContents: fetchDetsTableContents,
+}
+
+function createDetsTableRow(table: DetsTableInfo): string {
+  return `
+    <tr>
+      <td><a href="/dets/${encodeURIComponent(table.name)}" class="table-link">${escapeHtml(table.name)}</a></td>
+      <td>${escapeHtml(table.table_type)}</td>
+      <td class="text-end">${table.size.toLocaleString()}</td>
+      <td class="text-end">${formatBytes(table.file_size)}</td>
+      <td class="entry-text">${escapeHtml(table.file)}</td>
+    </tr>
+  `
+}
+
+export async function renderDetsTablesList(container: HTMLElement): Promise<void> {
+  container.innerHTML = `
+    <div class="loading">
+      <div class="spinner-border text-primary" role="status">
+        <span class="visually-hidden">Loading...</span>
+      </div>
+    </div>
+  `
+
+  try {
+    const response = await fetchDetsTablesList()
+
+    container.innerHTML = `
+      <div class="d-flex justify-content-between align-items-center mb-3">
+        <h2>DETS Tables</h2>
+        <span class="badge bg-secondary">${response.total} tables</span>
+      </div>
+      <div class="table-responsive">
+        <table class="table table-striped table-hover">
+          <thead class="table-dark">
+            <tr>
+              <th>Name</th>
+              <th>Type</th>
+              <th class="text-end">Objects</th>
+              <th class="text-end">File Size</th>
+              <th>File</th>
+            </tr>
+          </thead>
+          <tbody>
+            ${response.tables.map(createDetsTableRow).join('')}
+          </tbody>
+        </table>
+      </div>
+    `
+  } catch (error) {
+    const message = error instanceof Error ? error.message : 'Unknown error'
+    container.innerHTML = `
+      <div class="alert alert-danger" role="alert">
+        <strong>Error:</strong> ${escapeHtml(message)}
+      </div>
+    `
+  }
+}
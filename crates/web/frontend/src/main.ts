@@ -1,9 +1,19 @@
 import { renderTablesList } from './tables-list'
 import { renderTableContents } from './table-contents'
+import { DETS_TABLES, renderDetsTablesList } from './dets-tables'
+import { MNESIA_TABLES, renderMnesiaTablesList } from './mnesia-tables'
+import { renderClusterTablesList } from './cluster-tables'
+import { renderMemoryBreakdown } from './memory-breakdown'
+import { renderSearchResults } from './search'
+import { renderPersistentTerm, renderPersistentTermsList } from './persistent-terms'
+import { renderSupervisionTree } from './supervision-tree'
+import { renderNodeOverview } from './node-overview'
 
-function getTableNameFromPath(): string | null {
-  const match = window.location.pathname.match(/^\/tables\/(.+)$/)
-  return match ? decodeURIComponent(match[1]) : null
+function getTableNameFromPath(prefix: string = '/tables/'): string | null {
+  const path = window.location.pathname
+  return path.startsWith(prefix) && path.length > prefix.length
+    ? decodeURIComponent(path.slice(prefix.length))
+    : null
 }
 
 async function main(): Promise<void> {
@@ -11,11 +21,46 @@ async function main(): Promise<void> {
   if (!app) return
 
   const tableName = getTableNameFromPath()
+  const detsTableName = getTableNameFromPath('/dets/')
+  const mnesiaTableName = getTableNameFromPath('/mnesia/')
 
-  if (tableName) {
+  if (window.location.pathname === '/cluster') {
+    await renderClusterTablesList(app)
+  } else if (window.location.pathname === '/memory') {
+    const params = new URLSearchParams(window.location.search)
+    await renderMemoryBreakdown(app, {
+      groupBy: params.get('group_by') ?? 'application',
+      groupRegex: params.get('group_regex'),
+      top: params.get('top'),
+    })
+  } else if (window.location.pathname === '/node') {
+    await renderNodeOverview(app)
+  } else if (window.location.pathname === '/tree') {
+    const all = new URLSearchParams(window.location.search).get('all') === 'true'
+    await renderSupervisionTree(app, all)
+  } else if (window.location.pathname === '/dets') {
+    await renderDetsTablesList(app)
+  } else if (detsTableName) {
+    await renderTableContents(app, detsTableName, DETS_TABLES)
+  } else if (window.location.pathname === '/mnesia') {
+    await renderMnesiaTablesList(app)
+  } else if (mnesiaTableName) {
+    await renderTableContents(app, mnesiaTableName, MNESIA_TABLES)
+  } else if (window.location.pathname === '/persistent_terms') {
+    const key = new URLSearchParams(window.location.search).get('key')
+    if (key) {
+      await renderPersistentTerm(app, key)
+    } else {
+      await renderPersistentTermsList(app)
+    }
+  } else if (window.location.pathname === '/search') {
+    const params = new URLSearchParams(window.location.search)
+    await renderSearchResults(app, params.get('regex') ?? '', params.get('pattern'))
+  } else if (tableName) {
     await renderTableContents(app, tableName)
   } else {
-    await renderTablesList(app)
+    const offHeap = new URLSearchParams(window.location.search).get('off_heap') === 'true'
+    await renderTablesList(app, offHeap)
   }
 }
 
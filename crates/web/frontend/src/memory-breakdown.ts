@@ -0,0 +1,97 @@
+import { fetchMemoryBreakdown, formatBytes, MemoryBreakdownQuery, TableGroup } from './api'
+import { escapeAttribute, escapeHtml } from './utils'
+
+const GROUPINGS: [string, string][] = [
+  ['application', 'Application'],
+  ['owner', 'Owner'],
+  ['type', 'Type'],
+  ['protection', 'Protection'],
+  ['regex-capture', 'Regex capture'],
+]
+
+function createGroupRow(group: TableGroup): string {
+  return `
+    <tr>
+      <td>${escapeHtml(group.name)}</td>
+      <td class="text-end">${group.table_count.toLocaleString()}</td>
+      <td class="text-end">${group.object_count.toLocaleString()}</td>
+      <td class="text-end">${formatBytes(group.memory_bytes)}</td>
+      <td class="text-end">${group.memory_percentage.toFixed(1)}%</td>
+    </tr>
+  `
+}
+
+function createGroupingForm(query: MemoryBreakdownQuery): string {
+  const options = GROUPINGS.map(
+    ([value, label]) => `<option value="${value}"${value === query.groupBy ? ' selected' : ''}>${label}</option>`
+  ).join('')
+  return `
+    <form class="row g-2 align-items-center mb-3" method="get" action="/memory">
+      <div class="col-auto">
+        <label for="group-by" class="col-form-label">Group by</label>
+      </div>
+      <div class="col-auto">
+        <select id="group-by" name="group_by" class="form-select form-select-sm">
+          ${options}
+        </select>
+      </div>
+      <div class="col-auto">
+        <input type="text" name="group_regex" class="form-control form-control-sm" placeholder="^(ra_[a-z]+)"
+          value="${escapeAttribute(query.groupRegex ?? '')}" aria-label="Group regex">
+      </div>
+      <div class="col-auto">
+        <input type="number" min="1" name="top" class="form-control form-control-sm" placeholder="Top N"
+          value="${escapeAttribute(query.top ?? '')}" aria-label="Top N groups">
+      </div>
+      <div class="col-auto">
+        <button type="submit" class="btn btn-primary btn-sm">Apply</button>
+      </div>
+    </form>
+  `
+}
+
+export async function renderMemoryBreakdown(container: HTMLElement, query: MemoryBreakdownQuery): Promise<void> {
+  container.innerHTML = `
+    <div class="loading">
+      <div class="spinner-border text-primary" role="status">
+        <span class="visually-hidden">Loading...</span>
+      </div>
+    </div>
+  `
+
+  try {
+    const response = await fetchMemoryBreakdown(query)
+
+    container.innerHTML = `
+      <div class="d-flex justify-content-between align-items-center mb-3">
+        <h2>Memory Breakdown</h2>
+        <span class="badge bg-secondary">${response.table_count} tables, ${formatBytes(response.total_memory_bytes)}</span>
+      </div>
+      ${createGroupingForm(query)}
+      <div class="table-responsive">
+        <table class="table table-striped table-hover">
+          <thead class="table-dark">
+            <tr>
+              <th>Group</th>
+              <th class="text-end">Tables</th>
+              <th class="text-end">Objects</th>
+              <th class="text-end">Memory</th>
+              <th class="text-end">% of Total</th>
+            </tr>
+          </thead>
+          <tbody>
+            ${response.groups.map(createGroupRow).join('')}
+          </tbody>
+        </table>
+      </div>
+    `
+  } catch (error) {
+    const message = error instanceof Error ? error.message : 'Unknown error'
+    container.innerHTML = `
+      ${createGroupingForm(query)}
+      <div class="alert alert-danger" role="alert">
+        <strong>Error:</strong> ${escapeHtml(message)}
+      </div>
+    `
+  }
+}
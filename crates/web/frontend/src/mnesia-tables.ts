@@ -0,0 +1,79 @@
+import { fetchMnesiaTableContents, fetchMnesiaTablesList, formatBytes, MnesiaTableInfo } from './api'
+import { TableSource } from './table-contents'
+import { escapeHtml } from './utils'
+
+export const MNESIA_TABLES: TableSource = {
+  label: 'Mnesia',
+  listPath: '/mnesia',
+  fetchContents: fetchMnesiaTableContents,
+}
+
+function replicaSummary(table: MnesiaTableInfo): string {
+  const kinds: [string, string[]][] = [
+    ['ram', table.ram_copies],
+    ['disc', table.disc_copies],
+    ['disc_only', table.disc_only_copies],
+  ]
+  return kinds
+    .filter(([, nodes]) => nodes.length > 0)
+    .map(([kind, nodes]) => `${kind}: ${escapeHtml(nodes.join(', '))}`)
+    .join('<br>')
+}
+
+function createMnesiaTableRow(table: MnesiaTableInfo): string {
+  return `
+    <tr>
+      <td><a href="/mnesia/${encodeURIComponent(table.name)}" class="table-link">${escapeHtml(table.name)}</a></td>
+      <td>${escapeHtml(table.storage_type)}</td>
+      <td class="entry-text">#${escapeHtml(table.record_name)}{${escapeHtml(table.attributes.join(', '))}}</td>
+      <td class="text-end">${table.size.toLocaleString()}</td>
+      <td class="text-end">${formatBytes(table.memory_bytes)}</td>
+      <td>${replicaSummary(table)}</td>
+    </tr>
+  `
+}
+
+export async function renderMnesiaTablesList(container: HTMLElement): Promise<void> {
+  container.innerHTML = `
+    <div class="loading">
+      <div class="spinner-border text-primary" role="status">
+        <span class="visually-hidden">Loading...</span>
+      </div>
+    </div>
+  `
+
+  try {
+    const response = await fetchMnesiaTablesList()
+
+    container.innerHTML = `
+      <div class="d-flex justify-content-between align-items-center mb-3">
+        <h2>Mnesia Tables</h2>
+        <span class="badge bg-secondary">${response.total} tables</span>
+      </div>
+      <div class="table-responsive">
+        <table class="table table-striped table-hover">
+          <thead class="table-dark">
+            <tr>
+              <th>Name</th>
+              <th>Storage</th>
+              <th>Record</th>
+              <th class="text-end">Objects</th>
+              <th class="text-end">Memory</th>
+              <th>Replicas</th>
+            </tr>
+          </thead>
+          <tbody>
+            ${response.tables.map(createMnesiaTableRow).join('')}
+          </tbody>
+        </table>
+      </div>
+    `
+  } catch (error) {
+    const message = error instanceof Error ? error.message : 'Unknown error'
+    container.innerHTML = `
+      <div class="alert alert-danger" role="alert">
+        <strong>Error:</strong> ${escapeHtml(message)}
+      </div>
+    `
+  }
+}
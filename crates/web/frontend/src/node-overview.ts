@@ -0,0 +1,96 @@
+import { AllocatorInstance, EtsAllocReport, fetchEtsAllocReport, formatBytes } from './api'
+import { escapeHtml } from './utils'
+
+const LOADING_HTML = `
+  <div class="loading">
+    <div class="spinner-border text-primary" role="status">
+      <span class="visually-hidden">Loading...</span>
+    </div>
+  </div>
+`
+
+function formatPercentage(ratio: number): string {
+  return `${(ratio * 100).toFixed(1)}%`
+}
+
+function formatSignedBytes(bytes: number): string {
+  return bytes < 0 ? `-${formatBytes(-bytes)}` : `+${formatBytes(bytes)}`
+}
+
+function createInstanceRow(instance: AllocatorInstance): string {
+  return `
+    <tr>
+      <td class="text-end">${instance.instance}</td>
+      <td class="text-end">${instance.mbcs_carriers.toLocaleString()}</td>
+      <td class="text-end">${instance.sbcs_carriers.toLocaleString()}</td>
+      <td class="text-end">${formatBytes(instance.blocks_bytes)}</td>
+      <td class="text-end">${formatBytes(instance.carriers_bytes)}</td>
+      <td class="text-end">${formatPercentage(instance.fragmentation)}</td>
+    </tr>
+  `
+}
+
+function renderEtsAllocPanel(report: EtsAllocReport): string {
+  const instances = report.enabled
+    ? `
+      <div class="table-responsive">
+        <table class="table table-sm table-striped mb-0">
+          <thead>
+            <tr>
+              <th class="text-end">Instance</th>
+              <th class="text-end">MBC Carriers</th>
+              <th class="text-end">SBC Carriers</th>
+              <th class="text-end">Blocks</th>
+              <th class="text-end">Carriers Size</th>
+              <th class="text-end">Fragmentation</th>
+            </tr>
+          </thead>
+          <tbody>
+            ${report.instances.map(createInstanceRow).join('')}
+          </tbody>
+        </table>
+      </div>
+    `
+    : `<div class="alert alert-info mb-0">ets_alloc is disabled on this node, ETS memory comes from sys_alloc.</div>`
+
+  return `
+    <div class="card mb-3">
+      <div class="card-header">ets_alloc</div>
+      <div class="card-body">
+        <dl class="row mb-3">
+          <dt class="col-sm-5">Allocator blocks / carriers</dt>
+          <dd class="col-sm-7">${formatBytes(report.blocks_bytes)} / ${formatBytes(report.carriers_bytes)} (${formatPercentage(report.fragmentation)} fragmentation)</dd>
+          <dt class="col-sm-5"><code>erlang:memory(ets)</code></dt>
+          <dd class="col-sm-7">${formatBytes(report.erlang_memory_ets_bytes)}</dd>
+          <dt class="col-sm-5">Reported by ${report.table_count} tables</dt>
+          <dd class="col-sm-7">${formatBytes(report.tables_memory_bytes)}</dd>
+          <dt class="col-sm-5">Not attributed to any table</dt>
+          <dd class="col-sm-7">${formatSignedBytes(report.unattributed_bytes)}</dd>
+          <dt class="col-sm-5">Held by the allocator beyond table memory</dt>
+          <dd class="col-sm-7">${formatSignedBytes(report.allocator_overhead_bytes)}</dd>
+        </dl>
+        ${instances}
+      </div>
+    </div>
+  `
+}
+
+export async function renderNodeOverview(container: HTMLElement): Promise<void> {
+  container.innerHTML = LOADING_HTML
+
+  try {
+    const report = await fetchEtsAllocReport()
+
+    container.innerHTML = `
+      <h2 class="mb-3">Node Overview</h2>
+      ${renderEtsAllocPanel(report)}
+    `
+  } catch (error) {
+    const message = error instanceof Error ? error.message : 'Unknown error'
+    container.innerHTML = `
+      <div class="alert alert-danger" role="alert">
+        <strong>Error:</strong> ${escapeHtml(message)}
+      </div>
+    `
+  }
+}
@@ -0,0 +1,80 @@
+import { fetchPersistentTerm, fetchPersistentTerms, formatBytes, PersistentTermInfo } from './api'
+import { escapeHtml } from './utils'
+
+const LOADING_HTML = `
+  <div class="loading">
+    <div class="spinner-border text-primary" role="status">
+      <span class="visually-hidden">Loading...</span>
+    </div>
+  </div>
+`
+
+function renderError(container: HTMLElement, error: unknown): void {
+  const message = error instanceof Error ? error.message : 'Unknown error'
+  container.innerHTML = `
+    <div class="alert alert-danger" role="alert">
+      <strong>Error:</strong> ${escapeHtml(message)}
+    </div>
+  `
+}
+
+function createTermRow(term: PersistentTermInfo): string {
+  const href = `/persistent_terms?${new URLSearchParams({ key: term.key })}`
+  return `
+    <tr>
+      <td class="entry-text"><a href="${escapeHtml(href)}" class="table-link">${escapeHtml(term.key)}</a></td>
+      <td class="text-end">${formatBytes(term.memory_bytes)}</td>
+    </tr>
+  `
+}
+
+export async function renderPersistentTermsList(container: HTMLElement): Promise<void> {
+  container.innerHTML = LOADING_HTML
+
+  try {
+    const response = await fetchPersistentTerms()
+
+    container.innerHTML = `
+      <div class="d-flex justify-content-between align-items-center mb-3">
+        <h2>Persistent Terms</h2>
+        <span class="badge bg-secondary">${response.count} terms, ${formatBytes(response.total_memory_bytes)}</span>
+      </div>
+      <div class="table-responsive">
+        <table class="table table-striped table-hover">
+          <thead class="table-dark">
+            <tr>
+              <th>Key</th>
+              <th class="text-end">Memory</th>
+            </tr>
+          </thead>
+          <tbody>
+            ${response.terms.map(createTermRow).join('')}
+          </tbody>
+        </table>
+      </div>
+    `
+  } catch (error) {
+    renderError(container, error)
+  }
+}
+
+export async function renderPersistentTerm(container: HTMLElement, key: string): Promise<void> {
+  container.innerHTML = LOADING_HTML
+
+  try {
+    const response = await fetchPersistentTerm(key)
+
+    container.innerHTML = `
+      <nav aria-label="breadcrumb" class="mb-3">
+        <ol class="breadcrumb">
+          <li class="breadcrumb-item"><a href="/persistent_terms">Persistent Terms</a></li>
+          <li class="breadcrumb-item active" aria-current="page">${escapeHtml(response.key)}</li>
+        </ol>
+      </nav>
+      <h2 class="mb-3">Key: <code>${escapeHtml(response.key)}</code></h2>
+      <pre class="entry-text border rounded p-3 bg-light">${escapeHtml(response.value)}</pre>
+    `
+  } catch (error) {
+    renderError(container, error)
+  }
+}
@@ -0,0 +1,95 @@
+import { fetchSearchResults, SearchMatch } from './api'
+import { escapeHtml } from './utils'
+
+function renderSegments(match: SearchMatch): string {
+  return match.segments
+    .map((s) => (s.matched ? `<mark>${escapeHtml(s.text)}</mark>` : escapeHtml(s.text)))
+    .join('')
+}
+
+function createMatchRow(match: SearchMatch): string {
+  return `
+    <tr>
+      <td><a href="/tables/${encodeURIComponent(match.table_name)}" class="table-link">${escapeHtml(match.table_name)}</a></td>
+      <td class="text-end" style="width: 60px;">${match.index}</td>
+      <td class="entry-text">${renderSegments(match)}</td>
+    </tr>
+  `
+}
+
+export async function renderSearchResults(
+  container: HTMLElement,
+  regex: string,
+  pattern: string | null
+): Promise<void> {
+  if (!regex) {
+    container.innerHTML = `
+      <div class="alert alert-info" role="alert">
+        Enter a regex in the search box to search the entries of all tables.
+      </div>
+    `
+    return
+  }
+
+  container.innerHTML = `
+    <div class="loading">
+      <div class="spinner-border text-primary" role="status">
+        <span class="visually-hidden">Loading...</span>
+      </div>
+    </div>
+  `
+
+  try {
+    const response = await fetchSearchResults(regex, pattern)
+    const notes = [
+      response.truncated ? `Stopped after ${response.total} matches.` : '',
+      response.skipped_private.length > 0
+        ? `Skipped ${response.skipped_private.length} private tables: ${response.skipped_private.join(', ')}`
+        : '',
+      response.stopped_early.length > 0
+        ? `Only partially searched ${response.stopped_early.length} tables: ${response.stopped_early
+            .map((t) =>
+              t.error === null
+                ? `${t.table_name} (deleted after ${t.entries_searched} entries)`
+                : `${t.table_name} (failed after ${t.entries_searched} entries: ${t.error})`,
+            )
+            .join(', ')}`
+        : '',
+    ].filter((n) => n)
+
+    container.innerHTML = `
+      <div class="d-flex justify-content-between align-items-center mb-3">
+        <h2>Search: <code>${escapeHtml(regex)}</code></h2>
+        <span class="badge bg-secondary">${response.total} matches in ${response.tables_searched} tables</span>
+      </div>
+      ${notes.map((n) => `<div class="alert alert-warning" role="alert">${escapeHtml(n)}</div>`).join('')}
+      ${
+        response.total === 0
+          ? '<div class="alert alert-info" role="alert">No matching entries found.</div>'
+          : `
+      <div class="table-responsive">
+        <table class="table table-striped table-hover">
+          <thead class="table-dark">
+            <tr>
+              <th>Table</th>
+              <th class="text-end" style="width: 60px;">#</th>
+              <th>Entry</th>
+            </tr>
+          </thead>
+          <tbody>
+            ${response.matches.map(createMatchRow).join('')}
+          </tbody>
+        </table>
+      </div>
+      `
+      }
+    `
+  } catch (error) {
+    const message = error instanceof Error ? error.message : 'Unknown error'
+    container.innerHTML = `
+      <div class="alert alert-danger" role="alert">
+        <strong>Error:</strong> ${escapeHtml(message)}
+      </div>
+    `
+  }
+}
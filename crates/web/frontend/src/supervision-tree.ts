@@ -0,0 +1,77 @@
+import { EtsTableInfo, fetchSupervisionTree, formatBytes, ProcessNode } from './api'
+import { escapeHtml } from './utils'
+
+const LOADING_HTML = `
+  <div class="loading">
+    <div class="spinner-border text-primary" role="status">
+      <span class="visually-hidden">Loading...</span>
+    </div>
+  </div>
+`
+
+function renderError(container: HTMLElement, error: unknown): void {
+  const message = error instanceof Error ? error.message : 'Unknown error'
+  container.innerHTML = `
+    <div class="alert alert-danger" role="alert">
+      <strong>Error:</strong> ${escapeHtml(message)}
+    </div>
+  `
+}
+
+function createTableItem(table: EtsTableInfo): string {
+  return `
+    <li>
+      <a href="/tables/${encodeURIComponent(table.name)}" class="table-link"><code>${escapeHtml(table.name)}</code></a>
+      <span class="text-muted">${table.size.toLocaleString()} objects, ${formatBytes(table.memory_bytes)}</span>
+    </li>
+  `
+}
+
+function createProcessItem(node: ProcessNode): string {
+  const badge = node.kind === 'supervisor' ? 'bg-primary' : 'bg-secondary'
+  return `
+    <li>
+      <details open>
+        <summary>
+          <strong>${escapeHtml(node.name)}</strong>
+          <span class="badge ${badge}">${node.kind}</span>
+          <span class="text-muted">${escapeHtml(node.pid)}</span>
+          &mdash; ${node.subtree_table_count} tables, ${formatBytes(node.subtree_memory_bytes)}
+        </summary>
+        <ul>
+          ${node.tables.map(createTableItem).join('')}
+          ${node.children.map(createProcessItem).join('')}
+        </ul>
+      </details>
+    </li>
+  `
+}
+
+export async function renderSupervisionTree(container: HTMLElement, all: boolean): Promise<void> {
+  container.innerHTML = LOADING_HTML
+
+  try {
+    const response = await fetchSupervisionTree(all)
+    const toggleHref = all ? '/tree' : '/tree?all=true'
+    const toggleLabel = all ? 'Hide processes without tables' : 'Show all processes'
+    const unattributed = response.unattributed.length === 0 ? '' : `
+      <h4 class="mt-4">Outside supervision trees</h4>
+      <ul>
+        ${response.unattributed.map(createTableItem).join('')}
+      </ul>
+    `
+
+    container.innerHTML = `
+      <div class="d-flex justify-content-between align-items-center mb-3">
+        <h2>Supervision Tree</h2>
+        <a href="${toggleHref}" class="btn btn-outline-secondary btn-sm">${toggleLabel}</a>
+      </div>
+      <ul class="process-tree list-unstyled">
+        ${response.applications.map(createProcessItem).join('')}
+      </ul>
+      ${unattributed}
+    `
+  } catch (error) {
+    renderError(container, error)
+  }
+}
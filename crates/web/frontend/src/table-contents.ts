@@ -1,6 +1,139 @@
-import { fetchTableContents, TableEntry } from './api'
+import {
+  fetchOwnerState,
+  fetchTableContents,
+  fetchTableInfo,
+  formatBytes,
+  OwnerStateKind,
+  TableContentsResponse,
+  TableDetails,
+  TableEntry,
+} from './api'
 import { escapeHtml } from './utils'
 
+export interface TableSource {
+  label: string
+  listPath: string
+  fetchContents: (name: string) => Promise<TableContentsResponse>
+  inspectOwner?: boolean
+  showInfo?: boolean
+}
+
+export const ETS_TABLES: TableSource = {
+  label: 'Tables',
+  listPath: '/',
+  fetchContents: fetchTableContents,
+  inspectOwner: true,
+  showInfo: true,
+}
+
+const TABLE_INFO_PANEL_HTML = `
+  <div class="card mb-3">
+    <div class="card-header d-flex justify-content-between align-items-center">
+      <span>Table info</span>
+      <button type="button" class="btn btn-outline-secondary btn-sm" id="load-table-stats">Load bucket statistics</button>
+    </div>
+    <div class="card-body" id="table-info"></div>
+  </div>
+`
+
+function renderTableDetails(details: TableDetails, withStats: boolean): string {
+  const info = details.info
+  const rows: [string, string][] = [
+    ['Type', escapeHtml(info.table_type)],
+    ['Protection', escapeHtml(info.protection)],
+    ['Owner', `<code>${escapeHtml(info.owner)}</code>`],
+    ['Key position', String(info.keypos)],
+    ['Objects', info.size.toLocaleString()],
+    ['Memory', formatBytes(info.memory_bytes)],
+  ]
+
+  const stats = details.hash_stats
+  if (stats) {
+    rows.push(
+      ['Buckets', stats.buckets.toLocaleString()],
+      ['Chain length', `min ${stats.min_chain_len}, max ${stats.max_chain_len}, average ${stats.avg_chain_len.toFixed(2)}`],
+      ['Standard deviation', `${stats.std_dev.toFixed(2)} (expected ${stats.expected_std_dev.toFixed(2)})`],
+    )
+  }
+
+  const warning = details.skewed
+    ? `<div class="alert alert-warning mt-3 mb-0">Chain lengths deviate far more than expected: many keys hash to the same buckets.</div>`
+    : ''
+  const notHashed = withStats && !stats
+    ? `<p class="text-muted mt-3 mb-0">Bucket statistics are only available for hash-based tables.</p>`
+    : ''
+
+  return `
+    <dl class="row mb-0">
+      ${rows.map(([label, value]) => `<dt class="col-sm-3">${label}</dt><dd class="col-sm-9">${value}</dd>`).join('')}
+    </dl>
+    ${warning}
+    ${notHashed}
+  `
+}
+
+async function showTableInfo(panel: HTMLElement, tableName: string, withStats: boolean): Promise<void> {
+  panel.innerHTML = `<div class="spinner-border spinner-border-sm text-primary" role="status"></div>`
+
+  try {
+    panel.innerHTML = renderTableDetails(await fetchTableInfo(tableName, withStats), withStats)
+  } catch (error) {
+    const message = error instanceof Error ? error.message : 'Unknown error'
+    panel.innerHTML = `<div class="alert alert-warning mb-0">${escapeHtml(message)}</div>`
+  }
+}
+
+function bindTableInfoPanel(container: HTMLElement, tableName: string): void {
+  const panel = container.querySelector<HTMLElement>('#table-info')
+  if (!panel) return
+
+  showTableInfo(panel, tableName, false)
+  container.querySelector('#load-table-stats')?.addEventListener('click', () => {
+    showTableInfo(panel, tableName, true)
+  })
+}
+
+const OWNER_STATE_PANEL_HTML = `
+  <div class="card mb-3">
+    <div class="card-header d-flex justify-content-between align-items-center">
+      <span>Owner process</span>
+      <div class="btn-group btn-group-sm">
+        <button type="button" class="btn btn-outline-secondary" data-owner-kind="state">Fetch state</button>
+        <button type="button" class="btn btn-outline-secondary" data-owner-kind="status">Fetch status</button>
+      </div>
+    </div>
+    <div class="card-body" id="owner-state">
+      <p class="text-muted mb-0">Fetching the state sends a system message to the owner process.</p>
+    </div>
+  </div>
+`
+
+async function showOwnerState(panel: HTMLElement, tableName: string, kind: OwnerStateKind): Promise<void> {
+  panel.innerHTML = `<div class="spinner-border spinner-border-sm text-primary" role="status"></div>`
+
+  try {
+    const response = await fetchOwnerState(tableName, kind)
+    panel.innerHTML = `
+      <p class="mb-2">Owner: <code>${escapeHtml(response.owner)}</code></p>
+      <pre class="entry-text border rounded p-3 bg-light mb-0">${escapeHtml(response.value)}</pre>
+    `
+  } catch (error) {
+    const message = error instanceof Error ? error.message : 'Unknown error'
+    panel.innerHTML = `<div class="alert alert-warning mb-0">${escapeHtml(message)}</div>`
+  }
+}
+
+function bindOwnerStatePanel(container: HTMLElement, tableName: string): void {
+  const panel = container.querySelector<HTMLElement>('#owner-state')
+  if (!panel) return
+
+  container.querySelectorAll<HTMLButtonElement>('[data-owner-kind]').forEach((button) => {
+    button.addEventListener('click', () => {
+      showOwnerState(panel, tableName, button.dataset.ownerKind as OwnerStateKind)
+    })
+  })
+}
+
 function createEntryRow(entry: TableEntry): string {
   return `
     <tr>
@@ -10,7 +143,11 @@ function createEntryRow(entry: TableEntry): string {
   `
 }
 
-export async function renderTableContents(container: HTMLElement, tableName: string): Promise<void> {
+export async function renderTableContents(
+  container: HTMLElement,
+  tableName: string,
+  source: TableSource = ETS_TABLES
+): Promise<void> {
   container.innerHTML = `
     <div class="loading">
       <div class="spinner-border text-primary" role="status">
@@ -20,7 +157,7 @@ export async function renderTableContents(container: HTMLElement, tableName: str
   `
 
   try {
-    const response = await fetchTableContents(tableName)
+    const response = await source.fetchContents(tableName)
 
     const entriesHtml = response.entries.length > 0
       ? `
@@ -43,7 +180,7 @@ export async function renderTableContents(container: HTMLElement, tableName: str
     container.innerHTML = `
       <nav aria-label="breadcrumb" class="mb-3">
         <ol class="breadcrumb">
-          <li class="breadcrumb-item"><a href="/">Tables</a></li>
+          <li class="breadcrumb-item"><a href="${source.listPath}">${source.label}</a></li>
           <li class="breadcrumb-item active" aria-current="page">${escapeHtml(tableName)}</li>
         </ol>
       </nav>
@@ -51,14 +188,22 @@ export async function renderTableContents(container: HTMLElement, tableName: str
         <h2>Table: ${escapeHtml(tableName)}</h2>
         <span class="badge bg-secondary">${response.total} entries</span>
       </div>
+      ${source.showInfo ? TABLE_INFO_PANEL_HTML : ''}
+      ${source.inspectOwner ? OWNER_STATE_PANEL_HTML : ''}
       ${entriesHtml}
     `
+    if (source.showInfo) {
+      bindTableInfoPanel(container, tableName)
+    }
+    if (source.inspectOwner) {
+      bindOwnerStatePanel(container, tableName)
+    }
   } catch (error) {
     const message = error instanceof Error ? error.message : 'Unknown error'
     container.innerHTML = `
       <nav aria-label="breadcrumb" class="mb-3">
         <ol class="breadcrumb">
-          <li class="breadcrumb-item"><a href="/">Tables</a></li>
+          <li class="breadcrumb-item"><a href="${source.listPath}">${source.label}</a></li>
           <li class="breadcrumb-item active" aria-current="page">${escapeHtml(tableName)}</li>
         </ol>
       </nav>
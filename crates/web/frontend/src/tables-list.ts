@@ -1,20 +1,24 @@
 import { fetchTablesList, formatBytes, EtsTableInfo } from './api'
 import { escapeHtml } from './utils'
 
-function createTableRow(table: EtsTableInfo): string {
+function createTableRow(table: EtsTableInfo, offHeap: boolean): string {
+  const offHeapCell = offHeap
+    ? `<td class="text-end">${table.off_heap_bytes === undefined ? '-' : formatBytes(table.off_heap_bytes)}</td>`
+    : ''
   return `
     <tr>
       <td><a href="/tables/${encodeURIComponent(table.name)}" class="table-link">${escapeHtml(table.name)}</a></td>
       <td>${escapeHtml(table.table_type)}</td>
       <td class="text-end">${table.size.toLocaleString()}</td>
       <td class="text-end">${formatBytes(table.memory_bytes)}</td>
+      ${offHeapCell}
       <td>${escapeHtml(table.owner)}</td>
       <td>${escapeHtml(table.protection)}</td>
     </tr>
   `
 }
 
-export async function renderTablesList(container: HTMLElement): Promise<void> {
+export async function renderTablesList(container: HTMLElement, offHeap: boolean = false): Promise<void> {
   container.innerHTML = `
     <div class="loading">
       <div class="spinner-border text-primary" role="status">
@@ -24,12 +28,17 @@ export async function renderTablesList(container: HTMLElement): Promise<void> {
   `
 
   try {
-    const response = await fetchTablesList()
+    const response = await fetchTablesList(offHeap)
+    const toggleHref = offHeap ? '/' : '/?off_heap=true'
+    const toggleLabel = offHeap ? 'Hide off-heap binaries' : 'Estimate off-heap binaries'
 
     container.innerHTML = `
       <div class="d-flex justify-content-between align-items-center mb-3">
         <h2>ETS Tables</h2>
-        <span class="badge bg-secondary">${response.total} tables</span>
+        <div>
+          <a href="${toggleHref}" class="btn btn-outline-secondary btn-sm me-2">${toggleLabel}</a>
+          <span class="badge bg-secondary">${response.total} tables</span>
+        </div>
       </div>
       <div class="table-responsive">
         <table class="table table-striped table-hover">
@@ -39,12 +48,13 @@ export async function renderTablesList(container: HTMLElement): Promise<void> {
               <th>Type</th>
               <th class="text-end">Objects</th>
               <th class="text-end">Memory</th>
+              ${offHeap ? '<th class="text-end" title="Estimated refc binaries referenced by the objects">Off-heap</th>' : ''}
               <th>Owner</th>
               <th>Protection</th>
             </tr>
           </thead>
           <tbody>
-            ${response.tables.map(createTableRow).join('')}
+            ${response.tables.map((table) => createTableRow(table, offHeap)).join('')}
           </tbody>
         </table>
       </div>
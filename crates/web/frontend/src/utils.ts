@@ -3,3 +3,7 @@ export function escapeHtml(text: string): string {
   div.textContent = text
   return div.innerHTML
 }
+
+export function escapeAttribute(text: string): string {
+  return escapeHtml(text).replace(/"/g, '&quot;')
+}
@@ -0,0 +1,47 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::api::tables::TableContentsResponse;
+use crate::errors::ServerResult;
+use crate::server::AppState;
+use axum::Json;
+use axum::extract::{Path, State};
+use ets_lib::DetsTableInfo;
+use ets_lib::dets::DEFAULT_CHUNK_SIZE;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct DetsTablesListResponse {
+    pub tables: Vec<DetsTableInfo>,
+    pub total: usize,
+}
+
+pub async fn list_dets_tables(
+    State(state): State<AppState>,
+) -> ServerResult<Json<DetsTablesListResponse>> {
+    let tables = ets_lib::list_dets_tables(&state.node, &state.cookie).await?;
+    let total = tables.len();
+
+    Ok(Json(DetsTablesListResponse { tables, total }))
+}
+
+pub async fn get_dets_table_contents(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> ServerResult<Json<TableContentsResponse>> {
+    let entries =
+        ets_lib::dump_dets_table(&state.node, &state.cookie, &name, DEFAULT_CHUNK_SIZE).await?;
+
+    Ok(Json(TableContentsResponse::new(name, entries)))
+}
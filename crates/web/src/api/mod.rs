@@ -12,4 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod dets;
+pub mod mnesia;
+pub mod node;
+pub mod persistent_terms;
+pub mod search;
+pub mod supervision;
 pub mod tables;
@@ -0,0 +1,27 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::ServerResult;
+use crate::server::AppState;
+use axum::Json;
+use axum::extract::State;
+use ets_lib::EtsAllocReport;
+
+pub async fn get_ets_alloc_report(
+    State(state): State<AppState>,
+) -> ServerResult<Json<EtsAllocReport>> {
+    let report = ets_lib::ets_alloc_report(&state.node, &state.cookie).await?;
+
+    Ok(Json(report))
+}
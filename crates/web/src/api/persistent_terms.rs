@@ -0,0 +1,52 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::ServerResult;
+use crate::server::AppState;
+use axum::Json;
+use axum::extract::{Query, State};
+use ets_lib::PersistentTerms;
+use ets_lib::search::render_entry;
+use serde::{Deserialize, Serialize};
+
+pub async fn list_persistent_terms(
+    State(state): State<AppState>,
+) -> ServerResult<Json<PersistentTerms>> {
+    let terms = ets_lib::list_persistent_terms(&state.node, &state.cookie, None).await?;
+
+    Ok(Json(terms))
+}
+
+#[derive(Deserialize)]
+pub struct PersistentTermParams {
+    pub key: String,
+}
+
+#[derive(Serialize)]
+pub struct PersistentTermResponse {
+    pub key: String,
+    pub value: String,
+}
+
+pub async fn get_persistent_term(
+    State(state): State<AppState>,
+    Query(params): Query<PersistentTermParams>,
+) -> ServerResult<Json<PersistentTermResponse>> {
+    let value = ets_lib::get_persistent_term(&state.node, &state.cookie, &params.key).await?;
+
+    Ok(Json(PersistentTermResponse {
+        key: params.key,
+        value: render_entry(&value),
+    }))
+}
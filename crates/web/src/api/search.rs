@@ -0,0 +1,102 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::ServerResult;
+use crate::server::AppState;
+use axum::Json;
+use axum::extract::{Query, State};
+use ets_lib::{Error, GrepMatch, StoppedTable};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Caps the number of matches a single request can return.
+pub const DEFAULT_MAX_MATCHES: usize = 1000;
+
+#[derive(Deserialize)]
+pub struct SearchParams {
+    pub regex: String,
+    pub pattern: Option<String>,
+    pub max_matches: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub struct EntrySegment {
+    pub text: String,
+    pub matched: bool,
+}
+
+#[derive(Serialize)]
+pub struct SearchMatch {
+    pub table_name: String,
+    pub index: usize,
+    pub segments: Vec<EntrySegment>,
+}
+
+impl From<&GrepMatch> for SearchMatch {
+    fn from(m: &GrepMatch) -> Self {
+        Self {
+            table_name: m.table_name.clone(),
+            index: m.index,
+            segments: m
+                .segments()
+                .into_iter()
+                .map(|(text, matched)| EntrySegment {
+                    text: text.to_string(),
+                    matched,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SearchResponse {
+    pub matches: Vec<SearchMatch>,
+    pub total: usize,
+    pub tables_searched: usize,
+    pub skipped_private: Vec<String>,
+    pub stopped_early: Vec<StoppedTable>,
+    pub truncated: bool,
+}
+
+fn compile_regex(source: &str) -> Result<Regex, Error> {
+    Regex::new(source).map_err(|e| Error::InvalidPattern(e.to_string()))
+}
+
+pub async fn search_tables(
+    State(state): State<AppState>,
+    Query(params): Query<SearchParams>,
+) -> ServerResult<Json<SearchResponse>> {
+    let regex = compile_regex(&params.regex)?;
+    let pattern = params.pattern.as_deref().map(compile_regex).transpose()?;
+    let max_matches = params.max_matches.unwrap_or(DEFAULT_MAX_MATCHES);
+
+    let result = ets_lib::grep_tables(
+        &state.node,
+        &state.cookie,
+        &regex,
+        pattern.as_ref(),
+        Some(max_matches),
+    )
+    .await?;
+
+    Ok(Json(SearchResponse {
+        matches: result.matches.iter().map(SearchMatch::from).collect(),
+        total: result.matches.len(),
+        tables_searched: result.tables_searched,
+        skipped_private: result.skipped_private,
+        stopped_early: result.stopped_early,
+        truncated: result.truncated,
+    }))
+}
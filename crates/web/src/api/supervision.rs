@@ -0,0 +1,38 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::ServerResult;
+use crate::server::AppState;
+use axum::Json;
+use axum::extract::{Query, State};
+use ets_lib::SupervisionTree;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct SupervisionTreeParams {
+    #[serde(default)]
+    pub all: bool,
+}
+
+pub async fn get_supervision_tree(
+    State(state): State<AppState>,
+    Query(params): Query<SupervisionTreeParams>,
+) -> ServerResult<Json<SupervisionTree>> {
+    let mut tree = ets_lib::supervision_tree(&state.node, &state.cookie).await?;
+    if !params.all {
+        tree.prune_empty();
+    }
+
+    Ok(Json(tree))
+}
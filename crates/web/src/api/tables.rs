@@ -12,12 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::errors::ServerResult;
+use crate::errors::{ServerError, ServerResult};
 use crate::server::AppState;
 use axum::Json;
-use axum::extract::{Path, State};
-use ets_lib::EtsTableInfo;
-use serde::Serialize;
+use axum::extract::{Path, Query, State};
+use erltf::OwnedTerm;
+use ets_lib::ets::DEFAULT_OFF_HEAP_SAMPLE_SIZE;
+use ets_lib::owner::{DEFAULT_OWNER_STATE_TIMEOUT_MS, MAX_OWNER_STATE_TIMEOUT_MS, OwnerStateKind};
+use ets_lib::search::render_entry;
+use ets_lib::{ClusterTables, EtsTableInfo, GroupBy, TableDetails, TableGroup};
+use serde::{Deserialize, Serialize};
 
 #[derive(Serialize)]
 pub struct TablesListResponse {
@@ -25,13 +29,76 @@ pub struct TablesListResponse {
     pub total: usize,
 }
 
-pub async fn list_tables(State(state): State<AppState>) -> ServerResult<Json<TablesListResponse>> {
-    let tables = ets_lib::list_tables(&state.node, &state.cookie).await?;
+#[derive(Deserialize)]
+pub struct TablesListParams {
+    /// Estimate the off-heap binaries referenced by each table.
+    #[serde(default)]
+    pub off_heap: bool,
+}
+
+pub async fn list_tables(
+    State(state): State<AppState>,
+    Query(params): Query<TablesListParams>,
+) -> ServerResult<Json<TablesListResponse>> {
+    let tables = if params.off_heap {
+        state
+            .source
+            .list_tables_with_off_heap(DEFAULT_OFF_HEAP_SAMPLE_SIZE)
+            .await?
+    } else {
+        state.source.list_tables().await?
+    };
     let total = tables.len();
 
     Ok(Json(TablesListResponse { tables, total }))
 }
 
+pub async fn list_cluster_tables(
+    State(state): State<AppState>,
+) -> ServerResult<Json<ClusterTables>> {
+    let cluster = state.source.list_cluster_tables().await?;
+
+    Ok(Json(cluster))
+}
+
+#[derive(Deserialize)]
+pub struct MemoryBreakdownParams {
+    pub group_by: String,
+    pub group_regex: Option<String>,
+    pub top: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub struct MemoryBreakdownResponse {
+    pub groups: Vec<TableGroup>,
+    pub table_count: usize,
+    pub total_memory_bytes: u64,
+}
+
+pub async fn get_memory_breakdown(
+    State(state): State<AppState>,
+    Query(params): Query<MemoryBreakdownParams>,
+) -> ServerResult<Json<MemoryBreakdownResponse>> {
+    let group_regex = params.group_regex.as_deref().filter(|re| !re.is_empty());
+    let group_by = GroupBy::from_name(&params.group_by, group_regex)?;
+    let tables = if group_by.needs_applications() {
+        state.source.list_tables_with_applications().await?
+    } else {
+        state.source.list_tables().await?
+    };
+
+    let mut groups = ets_lib::group_tables(&tables, &group_by);
+    if let Some(top) = params.top {
+        groups = ets_lib::top_groups(groups, top);
+    }
+
+    Ok(Json(MemoryBreakdownResponse {
+        groups,
+        table_count: tables.len(),
+        total_memory_bytes: tables.iter().map(|t| t.memory_bytes).sum(),
+    }))
+}
+
 #[derive(Serialize)]
 pub struct TableEntry {
     pub index: usize,
@@ -43,27 +110,155 @@ pub struct TableContentsResponse {
     pub table_name: String,
     pub entries: Vec<TableEntry>,
     pub total: usize,
+    /// Pass as `cursor` to get the following page, absent on the last one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+impl TableContentsResponse {
+    pub fn new(table_name: String, entries: Vec<OwnedTerm>) -> Self {
+        Self::from_rendered(table_name, entries.iter().map(|e| e.to_string()).collect())
+    }
+
+    pub fn from_rendered(table_name: String, entries: Vec<String>) -> Self {
+        let total = entries.len();
+        let entries = entries
+            .into_iter()
+            .enumerate()
+            .map(|(i, entry)| TableEntry {
+                index: i + 1,
+                entry,
+            })
+            .collect();
+
+        Self {
+            table_name,
+            entries,
+            total,
+            next_cursor: None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct TableContentsParams {
+    /// Return one page of at most this many entries instead of the whole table.
+    pub limit: Option<usize>,
+    /// The `next_cursor` of the previous page.
+    pub cursor: Option<String>,
+}
+
+/// Page cursors are terms, handed to clients as hex-encoded external term format.
+fn encode_cursor(cursor: &OwnedTerm) -> ets_lib::Result<String> {
+    Ok(erltf::encode(cursor)?
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
+fn decode_cursor(cursor: &str) -> ets_lib::Result<OwnedTerm> {
+    let invalid = || ets_lib::Error::InvalidArgument(format!("invalid page cursor: {}", cursor));
+
+    let bytes = (0..cursor.len())
+        .step_by(2)
+        .map(|i| {
+            cursor
+                .get(i..i + 2)
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+        })
+        .collect::<Option<Vec<u8>>>()
+        .ok_or_else(invalid)?;
+
+    erltf::decode(&bytes).map_err(|_| invalid())
 }
 
 pub async fn get_table_contents(
     State(state): State<AppState>,
     Path(name): Path<String>,
+    Query(params): Query<TableContentsParams>,
 ) -> ServerResult<Json<TableContentsResponse>> {
-    let entries = ets_lib::dump_table(&state.node, &state.cookie, &name).await?;
-    let total = entries.len();
-
-    let entries: Vec<TableEntry> = entries
-        .into_iter()
-        .enumerate()
-        .map(|(i, entry)| TableEntry {
-            index: i + 1,
-            entry: entry.to_string(),
-        })
-        .collect();
+    let Some(limit) = params.limit else {
+        if params.cursor.is_some() {
+            return Err(
+                ets_lib::Error::InvalidArgument("cursor requires limit".to_string()).into(),
+            );
+        }
+        let entries = state.source.dump_table(&name).await?;
+        return Ok(Json(TableContentsResponse::new(name, entries)));
+    };
+
+    if limit == 0 {
+        return Err(ets_lib::Error::InvalidArgument("limit must be positive".to_string()).into());
+    }
+    let cursor = params.cursor.as_deref().map(decode_cursor).transpose()?;
+    let page = state.source.page_table(&name, cursor, limit).await?;
+    let next_cursor = page.next.as_ref().map(encode_cursor).transpose()?;
 
     Ok(Json(TableContentsResponse {
-        table_name: name,
-        entries,
-        total,
+        next_cursor,
+        ..TableContentsResponse::new(name, page.entries)
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct OwnerStateParams {
+    pub kind: Option<String>,
+    /// At most [`MAX_OWNER_STATE_TIMEOUT_MS`].
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(Serialize)]
+pub struct OwnerStateResponse {
+    pub table: String,
+    pub owner: String,
+    pub kind: &'static str,
+    pub value: String,
+}
+
+pub async fn get_owner_state(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    Query(params): Query<OwnerStateParams>,
+) -> ServerResult<Json<OwnerStateResponse>> {
+    if !state.owner_state_enabled {
+        return Err(ServerError::OwnerStateDisabled);
+    }
+
+    let kind = match params.kind.as_deref() {
+        Some(kind) => OwnerStateKind::from_name(kind)?,
+        None => OwnerStateKind::default(),
+    };
+    let timeout_ms = params.timeout_ms.unwrap_or(DEFAULT_OWNER_STATE_TIMEOUT_MS);
+    if !(1..=MAX_OWNER_STATE_TIMEOUT_MS).contains(&timeout_ms) {
+        return Err(ets_lib::Error::InvalidArgument(format!(
+            "timeout_ms must be between 1 and {}",
+            MAX_OWNER_STATE_TIMEOUT_MS
+        ))
+        .into());
+    }
+    let owner_state = state.source.owner_state(&name, kind, timeout_ms).await?;
+
+    Ok(Json(OwnerStateResponse {
+        table: owner_state.table_name,
+        owner: owner_state.owner,
+        kind: owner_state.kind.name(),
+        value: render_entry(&owner_state.value),
     }))
 }
+
+#[derive(Deserialize)]
+pub struct TableInfoParams {
+    /// Include bucket statistics of hash-based tables.
+    #[serde(default)]
+    pub stats: bool,
+}
+
+pub async fn get_table_info(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    Query(params): Query<TableInfoParams>,
+) -> ServerResult<Json<TableDetails>> {
+    let details = state.source.table_details(&name, params.stats).await?;
+
+    Ok(Json(details))
+}
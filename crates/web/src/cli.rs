@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use clap::{Arg, Command};
+use clap::{Arg, ArgAction, Command};
 
 pub fn clap_parser() -> Command {
     Command::new("ets-web")
@@ -50,4 +50,10 @@ pub fn clap_parser() -> Command {
                 .default_value("15692")
                 .help("Port to listen on"),
         )
+        .arg(
+            Arg::new("enable_owner_state")
+                .long("enable-owner-state")
+                .action(ArgAction::SetTrue)
+                .help("Allow the UI to fetch table owner state with sys:get_state/2 (messages the owner)"),
+        )
 }
@@ -24,6 +24,9 @@ use thiserror::Error;
 pub enum ServerError {
     #[error("ETS error: {0}")]
     Ets(#[from] ets_lib::Error),
+
+    #[error("Owner state inspection is disabled, restart the server with --enable-owner-state")]
+    OwnerStateDisabled,
 }
 
 impl IntoResponse for ServerError {
@@ -32,6 +35,22 @@ impl IntoResponse for ServerError {
             ServerError::Ets(ets_lib::Error::TableNotFound(name)) => {
                 (StatusCode::NOT_FOUND, format!("Table not found: {}", name))
             }
+            ServerError::Ets(ets_lib::Error::PersistentTermNotFound(key)) => (
+                StatusCode::NOT_FOUND,
+                format!("Persistent term not found: {}", key),
+            ),
+            ServerError::Ets(
+                ets_lib::Error::InvalidPattern(msg)
+                | ets_lib::Error::InvalidArgument(msg)
+                | ets_lib::Error::InvalidTerm(msg),
+            ) => (StatusCode::BAD_REQUEST, msg.clone()),
+            ServerError::Ets(ets_lib::Error::Unsupported(msg)) => {
+                (StatusCode::NOT_IMPLEMENTED, msg.clone())
+            }
+            ServerError::Ets(ets_lib::Error::OwnerState(msg)) => {
+                (StatusCode::BAD_GATEWAY, msg.clone())
+            }
+            ServerError::OwnerStateDisabled => (StatusCode::FORBIDDEN, self.to_string()),
             ServerError::Ets(ets_lib::Error::CookieNotFound(msg)) => {
                 (StatusCode::UNAUTHORIZED, msg.clone())
             }
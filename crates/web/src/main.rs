@@ -61,7 +61,8 @@ async fn main() {
         .expect("port has default value")
         .clone();
 
-    let state = AppState::new(node.clone(), cookie);
+    let state = AppState::new(node.clone(), cookie)
+        .with_owner_state(matches.get_flag("enable_owner_state"));
     let router = create_router(state);
 
     let addr = format!("{}:{}", host, port);
@@ -12,11 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::api::tables;
+use crate::api::{dets, mnesia, node, persistent_terms, search, supervision, tables};
 use axum::Router;
 use axum::http::header;
 use axum::response::{Html, IntoResponse};
 use axum::routing::get;
+use ets_lib::{EtsSource, LiveNode};
 use std::sync::Arc;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::services::ServeDir;
@@ -26,21 +27,62 @@ use tower_http::trace::TraceLayer;
 pub struct AppState {
     pub node: Arc<str>,
     pub cookie: Arc<str>,
+    /// Serves table listings and contents, the node itself unless replaced.
+    pub source: Arc<dyn EtsSource>,
+    /// Whether the owner state endpoint may message table owner processes.
+    pub owner_state_enabled: bool,
 }
 
 impl AppState {
     pub fn new(node: String, cookie: String) -> Self {
         Self {
+            source: Arc::new(LiveNode::new(&node, &cookie)),
             node: Arc::from(node),
             cookie: Arc::from(cookie),
+            owner_state_enabled: false,
         }
     }
+
+    pub fn with_source(mut self, source: Arc<dyn EtsSource>) -> Self {
+        self.source = source;
+        self
+    }
+
+    pub fn with_owner_state(mut self, enabled: bool) -> Self {
+        self.owner_state_enabled = enabled;
+        self
+    }
 }
 
 pub fn create_router(state: AppState) -> Router {
     let api_routes = Router::new()
         .route("/tables/list", get(tables::list_tables))
+        .route(
+            "/tables/memory_breakdown",
+            get(tables::get_memory_breakdown),
+        )
+        .route("/tables/tree", get(supervision::get_supervision_tree))
+        .route("/cluster/tables/list", get(tables::list_cluster_tables))
         .route("/tables/{name}/contents", get(tables::get_table_contents))
+        .route("/tables/{name}/info", get(tables::get_table_info))
+        .route("/tables/{name}/owner_state", get(tables::get_owner_state))
+        .route("/dets/list", get(dets::list_dets_tables))
+        .route("/dets/{name}/contents", get(dets::get_dets_table_contents))
+        .route("/mnesia/list", get(mnesia::list_mnesia_tables))
+        .route(
+            "/mnesia/{name}/contents",
+            get(mnesia::get_mnesia_table_contents),
+        )
+        .route("/node/ets_alloc", get(node::get_ets_alloc_report))
+        .route("/search", get(search::search_tables))
+        .route(
+            "/persistent_terms/list",
+            get(persistent_terms::list_persistent_terms),
+        )
+        .route(
+            "/persistent_terms/get",
+            get(persistent_terms::get_persistent_term),
+        )
         .with_state(state.clone());
 
     let cors = CorsLayer::new()
@@ -53,6 +95,16 @@ pub fn create_router(state: AppState) -> Router {
     Router::new()
         .route("/", get(spa_handler))
         .route("/tables/{name}", get(spa_handler))
+        .route("/memory", get(spa_handler))
+        .route("/cluster", get(spa_handler))
+        .route("/dets", get(spa_handler))
+        .route("/dets/{name}", get(spa_handler))
+        .route("/mnesia", get(spa_handler))
+        .route("/mnesia/{name}", get(spa_handler))
+        .route("/search", get(spa_handler))
+        .route("/persistent_terms", get(spa_handler))
+        .route("/tree", get(spa_handler))
+        .route("/node", get(spa_handler))
         .nest("/api/v1", api_routes)
         .nest_service("/assets", ServeDir::new(assets_dir))
         .layer(cors)
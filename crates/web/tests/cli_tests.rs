@@ -55,3 +55,8 @@ fn test_help_shows_cookie_option() {
         .stdout(output_includes("--erlang-cookie"))
         .stdout(output_includes("ERLANG_COOKIE"));
 }
+
+#[test]
+fn test_help_shows_enable_owner_state_option() {
+    run_succeeds(["--help"]).stdout(output_includes("--enable-owner-state"));
+}
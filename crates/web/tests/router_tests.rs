@@ -12,15 +12,59 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use axum::body::Body;
+use axum::body::{Body, to_bytes};
 use axum::http::{Request, StatusCode};
+use erltf::{erl_atom, erl_int, erl_tuple};
+use ets_fake_node::{FakeNode, Fixture, FixtureTable};
+use ets_lib::{EtsTableInfo, Protection, Snapshot, TableType};
 use ets_web::{AppState, create_router};
+use std::collections::BTreeMap;
+use std::sync::Arc;
 use tower::ServiceExt;
 
 fn create_test_state() -> AppState {
     AppState::new("test@localhost".to_string(), "test_cookie".to_string())
 }
 
+fn create_snapshot_state() -> AppState {
+    let table = EtsTableInfo {
+        name: "sessions".to_string(),
+        table_type: TableType::Set,
+        size: 2,
+        memory_bytes: 1024,
+        owner: "<0.0.0>".to_string(),
+        protection: Protection::Public,
+        keypos: 1,
+        application: None,
+        off_heap_bytes: None,
+    };
+    let snapshot = Snapshot {
+        node: "rabbit@localhost".to_string(),
+        taken_at: 1_700_000_000,
+        tables: vec![table],
+        contents: BTreeMap::from([(
+            "sessions".to_string(),
+            vec![
+                erl_tuple!(erl_int!(1), erl_atom!("alice")),
+                erl_tuple!(erl_int!(2), erl_atom!("bob")),
+            ],
+        )]),
+    };
+
+    create_test_state().with_source(Arc::new(snapshot))
+}
+
+async fn get_body(state: AppState, uri: &str) -> (StatusCode, String) {
+    let response = create_router(state)
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
 #[tokio::test]
 async fn test_index_returns_html() {
     let router = create_router(create_test_state());
@@ -95,3 +139,410 @@ async fn test_unknown_route_returns_404() {
 
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
+
+#[tokio::test]
+async fn test_cluster_page_returns_html() {
+    let router = create_router(create_test_state());
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/cluster")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_search_page_returns_html() {
+    let router = create_router(create_test_state());
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/search?regex=guest")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_search_api_rejects_invalid_regex() {
+    let router = create_router(create_test_state());
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/search?regex=%28unclosed")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_search_api_requires_regex() {
+    let router = create_router(create_test_state());
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/search")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_persistent_terms_page_returns_html() {
+    let router = create_router(create_test_state());
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/persistent_terms")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_persistent_term_api_requires_key() {
+    let router = create_router(create_test_state());
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/persistent_terms/get")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_dets_pages_return_html() {
+    for uri in ["/dets", "/dets/my_dets_table"] {
+        let router = create_router(create_test_state());
+
+        let response = router
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK, "{}", uri);
+    }
+}
+
+#[tokio::test]
+async fn test_mnesia_pages_return_html() {
+    for uri in ["/mnesia", "/mnesia/rabbit_user"] {
+        let router = create_router(create_test_state());
+
+        let response = router
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK, "{}", uri);
+    }
+}
+
+#[tokio::test]
+async fn test_memory_breakdown_api_rejects_unknown_grouping() {
+    let router = create_router(create_test_state());
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/tables/memory_breakdown?group_by=color")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_memory_breakdown_api_rejects_invalid_group_regex() {
+    let router = create_router(create_test_state());
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/tables/memory_breakdown?group_by=regex-capture&group_regex=%28unclosed")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_supervision_tree_page_returns_html() {
+    let router = create_router(create_test_state());
+
+    let response = router
+        .oneshot(Request::builder().uri("/tree").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_supervision_tree_api_rejects_invalid_all_flag() {
+    let router = create_router(create_test_state());
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/tables/tree?all=maybe")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_owner_state_api_is_disabled_by_default() {
+    let router = create_router(create_test_state());
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/tables/my_table/owner_state")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_owner_state_api_rejects_unknown_kind() {
+    let router = create_router(create_test_state().with_owner_state(true));
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/tables/my_table/owner_state?kind=heap")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_owner_state_api_rejects_out_of_range_timeouts() {
+    for timeout_ms in ["0", "600000"] {
+        let uri = format!(
+            "/api/v1/tables/my_table/owner_state?timeout_ms={}",
+            timeout_ms
+        );
+        let (status, body) = get_body(create_test_state().with_owner_state(true), &uri).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}", timeout_ms);
+        assert!(body.contains("timeout_ms"));
+    }
+}
+
+#[tokio::test]
+async fn test_table_info_api_rejects_invalid_stats_flag() {
+    let router = create_router(create_test_state());
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/tables/my_table/info?stats=maybe")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_tables_list_api_rejects_invalid_off_heap_flag() {
+    let router = create_router(create_test_state());
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/tables/list?off_heap=maybe")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_node_overview_page_returns_html() {
+    let router = create_router(create_test_state());
+
+    let response = router
+        .oneshot(Request::builder().uri("/node").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_tables_list_api_serves_snapshot_source() {
+    let (status, body) = get_body(create_snapshot_state(), "/api/v1/tables/list").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains("\"sessions\""));
+}
+
+#[tokio::test]
+async fn test_table_contents_api_serves_snapshot_source() {
+    let (status, body) =
+        get_body(create_snapshot_state(), "/api/v1/tables/sessions/contents").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains("alice"));
+}
+
+#[tokio::test]
+async fn test_table_contents_api_reports_missing_snapshot_table() {
+    let (status, _) = get_body(create_snapshot_state(), "/api/v1/tables/missing/contents").await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_table_contents_api_pages_snapshot_source() {
+    let state = create_snapshot_state();
+
+    let (status, body) = get_body(state.clone(), "/api/v1/tables/sessions/contents?limit=1").await;
+    assert_eq!(status, StatusCode::OK);
+    let first: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(first["entries"].as_array().unwrap().len(), 1);
+    let cursor = first["next_cursor"].as_str().expect("a second page");
+
+    let uri = format!("/api/v1/tables/sessions/contents?limit=1&cursor={}", cursor);
+    let (status, body) = get_body(state, &uri).await;
+    assert_eq!(status, StatusCode::OK);
+    let second: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert!(body.contains("bob"));
+    assert!(second.get("next_cursor").is_none());
+}
+
+#[tokio::test]
+async fn test_table_contents_api_rejects_bad_cursors() {
+    let (status, _) = get_body(
+        create_snapshot_state(),
+        "/api/v1/tables/sessions/contents?limit=1&cursor=zz",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_table_info_api_serves_snapshot_source() {
+    let (status, body) = get_body(create_snapshot_state(), "/api/v1/tables/sessions/info").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains("\"sessions\""));
+}
+
+#[tokio::test]
+async fn test_snapshot_source_rejects_live_only_queries() {
+    for uri in [
+        "/api/v1/tables/list?off_heap=true",
+        "/api/v1/tables/sessions/info?stats=true",
+    ] {
+        let (status, _) = get_body(create_snapshot_state(), uri).await;
+        assert_eq!(status, StatusCode::NOT_IMPLEMENTED, "{}", uri);
+    }
+}
+
+fn start_fake_node() -> FakeNode {
+    let sessions = FixtureTable::new("sessions", TableType::Set)
+        .with_objects(vec![erl_tuple!(erl_int!(1), erl_atom!("alice"))]);
+
+    FakeNode::start(Fixture::new().with_table(sessions)).unwrap()
+}
+
+fn create_fake_node_state(node: &FakeNode) -> AppState {
+    AppState::new(node.name().to_string(), node.cookie().to_string())
+}
+
+#[tokio::test]
+async fn test_tables_list_api_serves_fake_node() {
+    let node = start_fake_node();
+
+    let (status, body) = get_body(create_fake_node_state(&node), "/api/v1/tables/list").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains("\"sessions\""));
+}
+
+#[tokio::test]
+async fn test_table_contents_api_serves_fake_node() {
+    let node = start_fake_node();
+
+    let (status, body) = get_body(
+        create_fake_node_state(&node),
+        "/api/v1/tables/sessions/contents",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains("alice"));
+}
+
+#[tokio::test]
+async fn test_table_contents_api_reports_missing_fake_node_table() {
+    let node = start_fake_node();
+
+    let (status, _) = get_body(
+        create_fake_node_state(&node),
+        "/api/v1/tables/missing/contents",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}